@@ -223,6 +223,41 @@ fn bench_montgomery_ops<M: Measurement>(group: &mut BenchmarkGroup<'_, M>) {
             },
         );
     }
+
+    #[cfg(feature = "alloc")]
+    for i in [1, 10, 100, 1000] {
+        group.bench_function(
+            format!("multi_exponentiate_vartime for {i} bases, U256^U256"),
+            |b| {
+                b.iter_batched(
+                    || {
+                        let bases_and_exponents: Vec<(MontyForm<{ U256::LIMBS }>, U256)> = (1..=i)
+                            .map(|_| {
+                                let x = U256::random_mod_vartime(
+                                    &mut rng,
+                                    params.modulus().as_nz_ref(),
+                                );
+                                let x_m = MontyForm::new(&x, params);
+                                let p = U256::random_mod_vartime(
+                                    &mut rng,
+                                    params.modulus().as_nz_ref(),
+                                ) | (U256::ONE << (U256::BITS - 1));
+                                (x_m, p)
+                            })
+                            .collect();
+
+                        bases_and_exponents
+                    },
+                    |bases_and_exponents| {
+                        black_box(MontyForm::<{ U256::LIMBS }>::multi_exponentiate_vartime(
+                            bases_and_exponents.as_slice(),
+                        ))
+                    },
+                    BatchSize::SmallInput,
+                )
+            },
+        );
+    }
 }
 
 fn bench_montgomery(c: &mut Criterion) {
@@ -0,0 +1,112 @@
+use chacha20::ChaCha8Rng;
+use criterion::{
+    BatchSize, BenchmarkGroup, Criterion, criterion_group, criterion_main, measurement::Measurement,
+};
+use crypto_bigint::{
+    Random, U256, const_monty_params, const_special_form_params,
+    modular::{ConstMontyForm, ConstMontyParams, SpecialFormElement, SpecialFormParams},
+};
+use rand_core::SeedableRng;
+use std::hint::black_box;
+
+// 2^255 - 19, both as a Montgomery modulus and in its special form
+const_monty_params!(
+    Curve25519Modulus,
+    U256,
+    "7fffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffed"
+);
+const_special_form_params!(Curve25519SpecialModulus, U256, 255, 19);
+
+// secp256k1 base field modulus 2^256 - 2^32 - 977, both as a Montgomery modulus and in its
+// special form
+const_monty_params!(
+    Secp256k1Modulus,
+    U256,
+    "fffffffffffffffffffffffffffffffffffffffffffffffffffffffefffffc2f"
+);
+#[cfg(target_pointer_width = "64")]
+const_special_form_params!(Secp256k1SpecialModulus, U256, 256, 0x1000003d1);
+
+fn bench_modulus<M, S, T>(group: &mut BenchmarkGroup<'_, T>, name: &str)
+where
+    M: ConstMontyParams<{ U256::LIMBS }>,
+    S: SpecialFormParams<{ U256::LIMBS }>,
+    T: Measurement,
+{
+    let mut rng = ChaCha8Rng::from_seed([7u8; 32]);
+
+    group.bench_function(format!("ConstMontyForm mul, {name}"), |b| {
+        b.iter_batched(
+            || {
+                let x = ConstMontyForm::<M, { U256::LIMBS }>::random_from_rng(&mut rng);
+                let y = ConstMontyForm::<M, { U256::LIMBS }>::random_from_rng(&mut rng);
+                (x, y)
+            },
+            |(x, y)| black_box(x).mul(&black_box(y)),
+            BatchSize::SmallInput,
+        )
+    });
+
+    group.bench_function(format!("SpecialFormElement mul, {name}"), |b| {
+        b.iter_batched(
+            || {
+                let x = SpecialFormElement::<S, { U256::LIMBS }>::random_from_rng(&mut rng);
+                let y = SpecialFormElement::<S, { U256::LIMBS }>::random_from_rng(&mut rng);
+                (x, y)
+            },
+            |(x, y)| black_box(x).mul(&black_box(y)),
+            BatchSize::SmallInput,
+        )
+    });
+
+    group.bench_function(format!("ConstMontyForm square, {name}"), |b| {
+        b.iter_batched(
+            || ConstMontyForm::<M, { U256::LIMBS }>::random_from_rng(&mut rng),
+            |x| black_box(x).square(),
+            BatchSize::SmallInput,
+        )
+    });
+
+    group.bench_function(format!("SpecialFormElement square, {name}"), |b| {
+        b.iter_batched(
+            || SpecialFormElement::<S, { U256::LIMBS }>::random_from_rng(&mut rng),
+            |x| black_box(x).square(),
+            BatchSize::SmallInput,
+        )
+    });
+
+    group.bench_function(format!("ConstMontyForm pow, {name}"), |b| {
+        b.iter_batched(
+            || {
+                let x = ConstMontyForm::<M, { U256::LIMBS }>::random_from_rng(&mut rng);
+                let e = U256::random_from_rng(&mut rng);
+                (x, e)
+            },
+            |(x, e)| black_box(x).pow(&black_box(e)),
+            BatchSize::SmallInput,
+        )
+    });
+
+    group.bench_function(format!("SpecialFormElement pow, {name}"), |b| {
+        b.iter_batched(
+            || {
+                let x = SpecialFormElement::<S, { U256::LIMBS }>::random_from_rng(&mut rng);
+                let e = U256::random_from_rng(&mut rng);
+                (x, e)
+            },
+            |(x, e)| black_box(x).pow(&black_box(e)),
+            BatchSize::SmallInput,
+        )
+    });
+}
+
+fn bench_special_form(c: &mut Criterion) {
+    let mut group = c.benchmark_group("Special form arithmetic");
+    bench_modulus::<Curve25519Modulus, Curve25519SpecialModulus, _>(&mut group, "2^255 - 19");
+    #[cfg(target_pointer_width = "64")]
+    bench_modulus::<Secp256k1Modulus, Secp256k1SpecialModulus, _>(&mut group, "secp256k1");
+    group.finish();
+}
+
+criterion_group!(benches, bench_special_form);
+criterion_main!(benches);
@@ -5,7 +5,7 @@ use criterion::{
 };
 use crypto_bigint::{
     Gcd, Limb, NonZero, Odd, OddUint, Random, RandomBits, RandomMod, Reciprocal, U128, U256, U512,
-    U1024, U2048, U4096, U8192, Uint,
+    U1024, U2048, U4096, U8192, Uint, UintReciprocal,
 };
 use rand_core::{RngCore, SeedableRng};
 use std::hint::black_box;
@@ -340,6 +340,16 @@ fn bench_division(c: &mut Criterion) {
         )
     });
 
+    group.bench_function("div/rem, U256/U128, with reciprocal", |b| {
+        let y = NonZero::<U128>::random_from_rng(&mut rng);
+        let reciprocal = UintReciprocal::new(&y);
+        b.iter_batched(
+            || U256::random_from_rng(&mut rng),
+            |x| x.div_rem_with_reciprocal(&reciprocal),
+            BatchSize::SmallInput,
+        )
+    });
+
     group.bench_function("div/rem, U256/U128 (in U256)", |b| {
         b.iter_batched(
             || {
@@ -1,7 +1,10 @@
 //! Checked arithmetic.
 
-use crate::{CheckedAdd, CheckedDiv, CheckedMul, CheckedSub, Choice, CtEq, CtOption, CtSelect};
-use core::ops::{Add, Div, Mul, Sub};
+use crate::{
+    CheckedAdd, CheckedDiv, CheckedMul, CheckedNeg, CheckedRem, CheckedShl, CheckedShr, CheckedSub,
+    Choice, CtEq, CtOption, CtSelect,
+};
+use core::ops::{Add, Div, Mul, Neg, Rem, Shl, Shr, Sub};
 
 #[cfg(feature = "serde")]
 use serdect::serde::{Deserialize, Deserializer, Serialize, Serializer};
@@ -276,6 +279,142 @@ where
     }
 }
 
+impl<T> Rem<Self> for Checked<T>
+where
+    T: CheckedRem + CtSelect + Default,
+{
+    type Output = Checked<T>;
+
+    #[inline]
+    fn rem(self, rhs: Self) -> Self::Output {
+        Checked(
+            self.0
+                .as_ref()
+                .and_then(|lhs| rhs.0.as_ref().and_then(|rhs| lhs.checked_rem(rhs))),
+        )
+    }
+}
+
+impl<T> Rem<&Self> for Checked<T>
+where
+    T: CheckedRem + CtSelect + Default,
+{
+    type Output = Checked<T>;
+
+    #[inline]
+    fn rem(self, rhs: &Self) -> Self::Output {
+        Checked(
+            self.0
+                .as_ref()
+                .and_then(|lhs| rhs.0.as_ref().and_then(|rhs| lhs.checked_rem(rhs))),
+        )
+    }
+}
+
+impl<T> Rem<Checked<T>> for &Checked<T>
+where
+    T: CheckedRem + CtSelect + Default,
+{
+    type Output = Checked<T>;
+
+    #[inline]
+    fn rem(self, rhs: Checked<T>) -> Self::Output {
+        Checked(
+            self.0
+                .as_ref()
+                .and_then(|lhs| rhs.0.as_ref().and_then(|rhs| lhs.checked_rem(rhs))),
+        )
+    }
+}
+
+impl<T> Rem<&Checked<T>> for &Checked<T>
+where
+    T: CheckedRem + CtSelect + Default,
+{
+    type Output = Checked<T>;
+
+    #[inline]
+    fn rem(self, rhs: &Checked<T>) -> Self::Output {
+        Checked(
+            self.0
+                .as_ref()
+                .and_then(|lhs| rhs.0.as_ref().and_then(|rhs| lhs.checked_rem(rhs))),
+        )
+    }
+}
+
+impl<T> Neg for Checked<T>
+where
+    T: CheckedNeg + CtSelect + Default,
+{
+    type Output = Checked<T>;
+
+    #[inline]
+    fn neg(self) -> Self::Output {
+        Checked(self.0.as_ref().and_then(|val| val.checked_neg()))
+    }
+}
+
+impl<T> Neg for &Checked<T>
+where
+    T: CheckedNeg + CtSelect + Default,
+{
+    type Output = Checked<T>;
+
+    #[inline]
+    fn neg(self) -> Self::Output {
+        Checked(self.0.as_ref().and_then(|val| val.checked_neg()))
+    }
+}
+
+impl<T> Shl<u32> for Checked<T>
+where
+    T: CheckedShl + CtSelect + Default,
+{
+    type Output = Checked<T>;
+
+    #[inline]
+    fn shl(self, shift: u32) -> Self::Output {
+        Checked(self.0.as_ref().and_then(|val| val.checked_shl(shift)))
+    }
+}
+
+impl<T> Shl<u32> for &Checked<T>
+where
+    T: CheckedShl + CtSelect + Default,
+{
+    type Output = Checked<T>;
+
+    #[inline]
+    fn shl(self, shift: u32) -> Self::Output {
+        Checked(self.0.as_ref().and_then(|val| val.checked_shl(shift)))
+    }
+}
+
+impl<T> Shr<u32> for Checked<T>
+where
+    T: CheckedShr + CtSelect + Default,
+{
+    type Output = Checked<T>;
+
+    #[inline]
+    fn shr(self, shift: u32) -> Self::Output {
+        Checked(self.0.as_ref().and_then(|val| val.checked_shr(shift)))
+    }
+}
+
+impl<T> Shr<u32> for &Checked<T>
+where
+    T: CheckedShr + CtSelect + Default,
+{
+    type Output = Checked<T>;
+
+    #[inline]
+    fn shr(self, shift: u32) -> Self::Output {
+        Checked(self.0.as_ref().and_then(|val| val.checked_shr(shift)))
+    }
+}
+
 impl<T> CtEq for Checked<T>
 where
     T: CtEq,
@@ -367,3 +506,132 @@ where
         CtEq::ct_eq(self, rhs).into()
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::Checked;
+    use crate::{I128, U128};
+
+    fn is_none<T>(checked: Checked<T>) -> bool {
+        checked.0.is_none().to_bool()
+    }
+
+    #[test]
+    fn uint_ops() {
+        let max = Checked::new(U128::MAX);
+        let zero = Checked::new(U128::ZERO);
+        let one = Checked::new(U128::ONE);
+        let two = Checked::new(U128::from_u8(2));
+        let seven = Checked::new(U128::from_u8(7));
+
+        assert_eq!((one + two).0.unwrap(), U128::from_u8(3));
+        assert!(is_none(max + one));
+        assert!(is_none(one - two));
+        assert!(is_none(max * two));
+        assert_eq!((seven / two).0.unwrap(), U128::from_u8(3));
+        assert!(is_none(seven / zero));
+        assert_eq!((seven % two).0.unwrap(), U128::ONE);
+        assert!(is_none(seven % zero));
+        assert_eq!((-zero).0.unwrap(), U128::ZERO);
+        assert!(is_none(-one));
+        assert_eq!((one << 127).0.unwrap(), U128::ONE.shl(127));
+        assert!(is_none(one << 128));
+        assert_eq!((max >> 127).0.unwrap(), U128::ONE);
+        assert!(is_none(max >> 128));
+
+        // `none` is sticky
+        assert!(is_none((max + one) - max));
+        assert!(is_none(-(one - two)));
+    }
+
+    #[test]
+    fn int_ops() {
+        let max = Checked::new(I128::MAX);
+        let min = Checked::new(I128::MIN);
+        let zero = Checked::new(I128::ZERO);
+        let one = Checked::new(I128::ONE);
+        let minus_one = Checked::new(I128::MINUS_ONE);
+        let seven = Checked::new(I128::from(7i32));
+
+        assert_eq!((seven + minus_one).0.unwrap(), I128::from(6i32));
+        assert!(is_none(max + one));
+        assert!(is_none(min - one));
+        assert_eq!((seven * minus_one).0.unwrap(), I128::from(-7i32));
+        assert!(is_none(min * minus_one));
+        assert_eq!(
+            (-seven / Checked::new(I128::from(2i32))).0.unwrap(),
+            I128::from(-3i32)
+        );
+        assert!(is_none(seven / zero));
+        assert!(is_none(min / minus_one));
+        assert_eq!(
+            (-seven % Checked::new(I128::from(2i32))).0.unwrap(),
+            I128::MINUS_ONE
+        );
+        assert!(is_none(seven % zero));
+        assert_eq!((-&max).0.unwrap(), I128::MIN.wrapping_add(&I128::ONE));
+        assert!(is_none(-min));
+        assert_eq!((minus_one << 127).0.unwrap(), I128::MIN);
+        assert!(is_none(one << 128));
+        assert_eq!((min >> 127).0.unwrap(), I128::MINUS_ONE);
+        assert!(is_none(&min >> 128));
+
+        let mut x = seven;
+        x *= minus_one;
+        x -= max;
+        assert!(is_none(x));
+    }
+
+    #[cfg(feature = "alloc")]
+    #[test]
+    fn boxed_uint_ops() {
+        use crate::{BoxedUint, Resize};
+
+        let max = Checked::new(BoxedUint::max(128));
+        let zero = Checked::new(BoxedUint::zero_with_precision(128));
+        let one = Checked::new(BoxedUint::one_with_precision(128));
+        let two = Checked::new(BoxedUint::from(2u8).resize(128));
+        let seven = Checked::new(BoxedUint::from(7u8).resize(128));
+
+        assert!(is_none(&max + &one));
+        assert!(is_none(&one - &two));
+        assert!(is_none(&max * &two));
+        assert_eq!((&seven / &two).0.unwrap(), BoxedUint::from(3u8).resize(128));
+        assert!(is_none(&seven / &zero));
+        assert_eq!(
+            (&seven % &two).0.unwrap(),
+            BoxedUint::one_with_precision(128)
+        );
+        assert!(is_none(&seven % &zero));
+        assert_eq!((-&zero).0.unwrap(), BoxedUint::zero_with_precision(128));
+        assert!(is_none(-&one));
+        assert_eq!((&max >> 127).0.unwrap(), BoxedUint::one_with_precision(128));
+        assert!(is_none(&one << 128));
+
+        // Operands of differing precision
+        let wide_seven = Checked::new(BoxedUint::from(7u8).resize(256));
+        assert_eq!(
+            (&max / &wide_seven).0.unwrap(),
+            BoxedUint::from(u128::MAX / 7)
+        );
+        assert_eq!(
+            (&max % &wide_seven).0.unwrap(),
+            BoxedUint::from(u128::MAX % 7).resize(256)
+        );
+        assert!(is_none(
+            &seven / Checked::new(BoxedUint::zero_with_precision(256))
+        ));
+
+        // A `none` placeholder of a differing precision does not panic
+        let none = Checked(crate::CtOption::<BoxedUint>::none());
+        assert!(is_none(&none + &max));
+        assert!(is_none(&max / &none));
+        assert!(is_none(&max % &none));
+
+        let mut x = one;
+        x += &seven;
+        x *= two;
+        x -= &max;
+        assert!(is_none(x));
+    }
+}
@@ -16,12 +16,16 @@ mod bit_and;
 mod bit_not;
 mod bit_or;
 mod bit_xor;
+mod bits;
+#[cfg(feature = "alloc")]
+pub(crate) mod boxed;
 mod cmp;
 mod div;
 mod div_uint;
 mod encoding;
 mod from;
 mod gcd;
+mod ilog;
 mod invert_mod;
 mod mod_symbol;
 mod mul;
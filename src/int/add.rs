@@ -1,6 +1,9 @@
 //! [`Int`] addition operations.
 
-use crate::{Add, AddAssign, Checked, CheckedAdd, Choice, CtOption, Int, Wrapping, WrappingAdd};
+use crate::{
+    Add, AddAssign, Checked, CheckedAdd, Choice, CtOption, Int, Saturating, SaturatingAdd,
+    Wrapping, WrappingAdd,
+};
 
 impl<const LIMBS: usize> Int<LIMBS> {
     /// Perform checked addition. Returns `none` when the addition overflowed.
@@ -33,6 +36,15 @@ impl<const LIMBS: usize> Int<LIMBS> {
     pub const fn wrapping_add(&self, rhs: &Self) -> Self {
         Self(self.0.wrapping_add(&rhs.0))
     }
+
+    /// Perform saturating addition, returning `MAX` or `MIN` on overflow, depending on its
+    /// direction.
+    pub const fn saturating_add(&self, rhs: &Self) -> Self {
+        let (res, overflow) = self.overflowing_add(rhs);
+        // Overflow is only possible when both operands have the same sign
+        let bound = Self::select(&Self::MAX, &Self::MIN, rhs.is_negative());
+        Self::select(&res, &bound, overflow)
+    }
 }
 
 impl<const LIMBS: usize> Add for Int<LIMBS> {
@@ -88,12 +100,30 @@ impl<const LIMBS: usize> AddAssign<&Checked<Int<LIMBS>>> for Checked<Int<LIMBS>>
     }
 }
 
+impl<const LIMBS: usize> AddAssign for Saturating<Int<LIMBS>> {
+    fn add_assign(&mut self, other: Self) {
+        *self = *self + other;
+    }
+}
+
+impl<const LIMBS: usize> AddAssign<&Saturating<Int<LIMBS>>> for Saturating<Int<LIMBS>> {
+    fn add_assign(&mut self, other: &Self) {
+        *self = *self + other;
+    }
+}
+
 impl<const LIMBS: usize> CheckedAdd for Int<LIMBS> {
     fn checked_add(&self, rhs: &Self) -> CtOption<Self> {
         self.checked_add(rhs)
     }
 }
 
+impl<const LIMBS: usize> SaturatingAdd for Int<LIMBS> {
+    fn saturating_add(&self, rhs: &Self) -> Self {
+        self.saturating_add(rhs)
+    }
+}
+
 impl<const LIMBS: usize> WrappingAdd for Int<LIMBS> {
     fn wrapping_add(&self, v: &Self) -> Self {
         self.wrapping_add(v)
@@ -318,4 +348,15 @@ mod tests {
         let (_val, overflow) = I128::MAX.overflowing_add(&I128::MAX);
         assert!(overflow.to_bool());
     }
+
+    #[test]
+    fn saturating_add() {
+        let two = I128::from(2i32);
+        assert_eq!(I128::ONE.saturating_add(&I128::ONE), two);
+        assert_eq!(I128::MAX.saturating_add(&I128::ONE), I128::MAX);
+        assert_eq!(I128::MAX.saturating_add(&I128::MAX), I128::MAX);
+        assert_eq!(I128::MAX.saturating_add(&I128::MIN), I128::MINUS_ONE);
+        assert_eq!(I128::MIN.saturating_add(&I128::MINUS_ONE), I128::MIN);
+        assert_eq!(I128::MIN.saturating_add(&I128::MIN), I128::MIN);
+    }
 }
@@ -0,0 +1,74 @@
+//! [`Int`] bit-length operations.
+
+use crate::{Int, Uint};
+
+impl<const LIMBS: usize> Int<LIMBS> {
+    /// Calculate the number of bits needed to represent this number in two's complement, i.e. the
+    /// bits of its magnitude plus a sign bit.
+    ///
+    /// This is `self.bits() + 1` for non-negative values and `(!self).bits() + 1` for negative
+    /// ones, so that zero and `-1` take a single bit and [`Int::MIN`] takes [`Int::BITS`] bits.
+    pub const fn bits(&self) -> u32 {
+        Uint::select(&self.0, &self.0.not(), self.is_negative()).bits() + 1
+    }
+
+    /// Calculate the number of bits needed to represent this number in two's complement, in
+    /// variable-time with respect to `self`.
+    ///
+    /// See [`Int::bits`] for details.
+    pub const fn bits_vartime(&self) -> u32 {
+        if self.is_negative().to_bool_vartime() {
+            self.0.not().bits_vartime() + 1
+        } else {
+            self.0.bits_vartime() + 1
+        }
+    }
+
+    /// Calculate the number of leading zeros in the two's complement binary representation of this
+    /// number.
+    pub const fn leading_zeros(&self) -> u32 {
+        self.0.leading_zeros()
+    }
+
+    /// Calculate the number of leading zeros in the two's complement binary representation of this
+    /// number, in variable-time with respect to `self`.
+    pub const fn leading_zeros_vartime(&self) -> u32 {
+        self.0.leading_zeros_vartime()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{I64, I128};
+
+    #[test]
+    fn bits() {
+        for (n, expected) in [
+            (0i64, 1),
+            (1, 2),
+            (-1, 1),
+            (127, 8),
+            (128, 9),
+            (-128, 8),
+            (-129, 9),
+            (i64::MAX, 64),
+            (i64::MIN, 64),
+        ] {
+            let n = I64::from_i64(n);
+            assert_eq!(n.bits(), expected);
+            assert_eq!(n.bits_vartime(), expected);
+        }
+
+        assert_eq!(I128::MIN.bits(), 128);
+        assert_eq!(I128::MINUS_ONE.bits_vartime(), 1);
+    }
+
+    #[test]
+    fn leading_zeros() {
+        for n in [0i64, 1, -1, 255, -256, i64::MAX, i64::MIN] {
+            let expected = n.leading_zeros();
+            assert_eq!(I64::from_i64(n).leading_zeros(), expected);
+            assert_eq!(I64::from_i64(n).leading_zeros_vartime(), expected);
+        }
+    }
+}
@@ -0,0 +1,507 @@
+//! Heap-allocated big signed integers.
+
+mod add;
+mod bit_and;
+mod bit_not;
+mod bit_or;
+mod bit_xor;
+mod cmp;
+mod div;
+mod encoding;
+mod from;
+mod mul;
+mod neg;
+mod select;
+mod shl;
+mod shr;
+mod sign;
+mod sub;
+
+use crate::{
+    BoxedUint, Choice, CtEq, CtOption, Integer, Limb, NonZero, Odd, One, Resize, Signed, Word, Zero,
+};
+use alloc::{boxed::Box, vec::Vec};
+use core::fmt;
+
+#[cfg(feature = "zeroize")]
+use zeroize::Zeroize;
+
+/// Fixed-precision heap-allocated big _signed_ integer.
+/// See [`BoxedUint`] for _unsigned_ integers.
+///
+/// Alternative to the stack-allocated [`Int`][`crate::Int`] but with a fixed precision chosen at
+/// runtime instead of compile time. Values are stored in two's complement representation.
+///
+/// Created as a [`BoxedUint`] newtype.
+#[derive(Clone)]
+pub struct BoxedInt(pub(crate) BoxedUint);
+
+impl BoxedInt {
+    /// Get the value `0` represented as succinctly as possible.
+    pub fn zero() -> Self {
+        Self(BoxedUint::zero())
+    }
+
+    /// Get the value `0` with the given number of bits of precision.
+    ///
+    /// `at_least_bits_precision` is rounded up to a multiple of [`Limb::BITS`].
+    pub fn zero_with_precision(at_least_bits_precision: u32) -> Self {
+        Self(BoxedUint::zero_with_precision(at_least_bits_precision))
+    }
+
+    /// Get the value `1` represented as succinctly as possible.
+    pub fn one() -> Self {
+        Self(BoxedUint::one())
+    }
+
+    /// Get the value `1` with the given number of bits of precision.
+    ///
+    /// `at_least_bits_precision` is rounded up to a multiple of [`Limb::BITS`].
+    pub fn one_with_precision(at_least_bits_precision: u32) -> Self {
+        Self(BoxedUint::one_with_precision(at_least_bits_precision))
+    }
+
+    /// Get the value `-1` with the given number of bits of precision.
+    ///
+    /// `at_least_bits_precision` is rounded up to a multiple of [`Limb::BITS`].
+    pub fn minus_one_with_precision(at_least_bits_precision: u32) -> Self {
+        Self(BoxedUint::max(at_least_bits_precision))
+    }
+
+    /// Get the smallest value a [`BoxedInt`] created with `at_least_bits_precision` precision
+    /// bits requested can express.
+    ///
+    /// That is, returns the value `-2^(self.bits_precision() - 1)`.
+    pub fn min(at_least_bits_precision: u32) -> Self {
+        Self::max(at_least_bits_precision).not()
+    }
+
+    /// Get the largest value a [`BoxedInt`] created with `at_least_bits_precision` precision
+    /// bits requested can express.
+    ///
+    /// That is, returns the value `2^(self.bits_precision() - 1) - 1`.
+    pub fn max(at_least_bits_precision: u32) -> Self {
+        let mut ret = BoxedUint::max(at_least_bits_precision);
+        ret.shr1_assign();
+        Self(ret)
+    }
+
+    /// Create a [`BoxedInt`] from an array of [`Word`]s (i.e. word-sized unsigned
+    /// integers), interpreted as a two's complement value.
+    #[inline]
+    pub fn from_words(words: impl IntoIterator<Item = Word>) -> Self {
+        Self(BoxedUint::from_words(words))
+    }
+
+    /// Create a boxed slice of [`Word`]s (i.e. word-sized unsigned integers) from
+    /// a [`BoxedInt`].
+    #[inline]
+    pub fn to_words(&self) -> Box<[Word]> {
+        self.0.to_words()
+    }
+
+    /// Borrow the inner limbs as a slice of [`Word`]s.
+    pub fn as_words(&self) -> &[Word] {
+        self.0.as_words()
+    }
+
+    /// Borrow the inner limbs as a mutable slice of [`Word`]s.
+    pub fn as_mut_words(&mut self) -> &mut [Word] {
+        self.0.as_mut_words()
+    }
+
+    /// Borrow the limbs of this [`BoxedInt`].
+    pub fn as_limbs(&self) -> &[Limb] {
+        self.0.as_limbs()
+    }
+
+    /// Borrow the limbs of this [`BoxedInt`] mutably.
+    pub fn as_mut_limbs(&mut self) -> &mut [Limb] {
+        self.0.as_mut_limbs()
+    }
+
+    /// Convert this [`BoxedInt`] into its inner limbs.
+    pub fn to_limbs(&self) -> Box<[Limb]> {
+        self.0.to_limbs()
+    }
+
+    /// Convert this [`BoxedInt`] into its inner limbs.
+    pub fn into_limbs(self) -> Box<[Limb]> {
+        self.0.into_limbs()
+    }
+
+    /// Get the number of limbs in this [`BoxedInt`].
+    pub fn nlimbs(&self) -> usize {
+        self.0.nlimbs()
+    }
+
+    /// Get the precision of this [`BoxedInt`] in bits.
+    #[inline(always)]
+    pub fn bits_precision(&self) -> u32 {
+        self.0.bits_precision()
+    }
+
+    /// Convert to a [`NonZero<BoxedInt>`].
+    ///
+    /// Returns some if the original value is non-zero, and false otherwise.
+    pub fn to_nz(&self) -> CtOption<NonZero<Self>> {
+        NonZero::new(self.clone())
+    }
+
+    /// Convert to an [`Odd<BoxedInt>`].
+    ///
+    /// Returns some if the original value is odd, and false otherwise.
+    pub fn to_odd(&self) -> CtOption<Odd<Self>> {
+        Odd::new(self.clone())
+    }
+
+    /// Interpret the data in this object as a [`BoxedUint`] instead.
+    ///
+    /// Note: this is a casting operation. See
+    /// - [`Self::try_into_uint`] for the checked equivalent, and
+    /// - [`Self::abs`] to obtain the absolute value of `self`.
+    pub fn as_uint(&self) -> &BoxedUint {
+        &self.0
+    }
+
+    /// Get a [`BoxedUint`] equivalent of this value; returns `None` if `self` is negative.
+    ///
+    /// Note: this is a checked conversion operation. See
+    /// - [`Self::as_uint`] for the unchecked equivalent, and
+    /// - [`Self::abs`] to obtain the absolute value of `self`.
+    pub fn try_into_uint(self) -> CtOption<BoxedUint> {
+        let is_negative = self.is_negative();
+        CtOption::new(self.0, !is_negative)
+    }
+
+    /// Whether this [`BoxedInt`] is equal to the minimum value for its precision.
+    pub fn is_min(&self) -> Choice {
+        self.0.ct_eq(&Self::min(self.bits_precision()).0)
+    }
+
+    /// Whether this [`BoxedInt`] is equal to the maximum value for its precision.
+    pub fn is_max(&self) -> Choice {
+        self.0.ct_eq(&Self::max(self.bits_precision()).0)
+    }
+
+    /// Is this [`BoxedInt`] equal to zero?
+    pub fn is_zero(&self) -> Choice {
+        self.0.is_zero()
+    }
+
+    /// Is this [`BoxedInt`] *NOT* equal to zero?
+    #[inline]
+    pub fn is_nonzero(&self) -> Choice {
+        self.0.is_nonzero()
+    }
+
+    /// Is this [`BoxedInt`] equal to one?
+    pub fn is_one(&self) -> Choice {
+        self.0.is_one()
+    }
+
+    /// Invert the most significant bit (msb) of this [`BoxedInt`].
+    fn invert_msb(&self) -> BoxedUint {
+        let mut ret = self.0.clone();
+        if let Some(hi) = ret.limbs.last_mut() {
+            *hi = hi.bitxor(Limb::ONE.shl(Limb::BITS - 1));
+        }
+        ret
+    }
+
+    /// Get the limb at position `i` of this value sign-extended to an arbitrary precision.
+    #[inline]
+    fn sign_extended_limb(&self, i: usize, extension: Limb) -> Limb {
+        *self.0.limbs.get(i).unwrap_or(&extension)
+    }
+
+    /// Get the limb which is used to sign-extend this value: `Limb::MAX` if `self` is negative,
+    /// and `Limb::ZERO` otherwise.
+    #[inline]
+    fn sign_extension(&self) -> Limb {
+        Limb::select(Limb::ZERO, Limb::MAX, self.is_negative())
+    }
+
+    /// Perform a carry chain-like operation over the limbs of the inputs,
+    /// constructing a result from the returned limbs and carry which is
+    /// widened to the same width as the widest input.
+    ///
+    /// If one of the two values has fewer limbs than the other, it is sign-extended.
+    #[inline]
+    fn fold_limbs<F>(lhs: &Self, rhs: &Self, mut carry: Limb, f: F) -> (Self, Limb)
+    where
+        F: Fn(Limb, Limb, Limb) -> (Limb, Limb),
+    {
+        let nlimbs = core::cmp::max(lhs.nlimbs(), rhs.nlimbs());
+        let (lhs_ext, rhs_ext) = (lhs.sign_extension(), rhs.sign_extension());
+        let mut limbs = Vec::with_capacity(nlimbs);
+
+        for i in 0..nlimbs {
+            let a = lhs.sign_extended_limb(i, lhs_ext);
+            let b = rhs.sign_extended_limb(i, rhs_ext);
+            let (limb, c) = f(a, b, carry);
+            limbs.push(limb);
+            carry = c;
+        }
+
+        (Self(limbs.into()), carry)
+    }
+
+    /// Iterate over the limbs of the inputs, applying the given function, and
+    /// constructing a result from the returned values.
+    ///
+    /// If one of the two values has fewer limbs than the other, it is sign-extended.
+    #[inline]
+    fn map_limbs<F>(lhs: &Self, rhs: &Self, f: F) -> Self
+    where
+        F: Fn(Limb, Limb) -> Limb,
+    {
+        Self::fold_limbs(lhs, rhs, Limb::ZERO, |a, b, _| (f(a, b), Limb::ZERO)).0
+    }
+}
+
+impl Resize for BoxedInt {
+    type Output = BoxedInt;
+
+    fn resize_unchecked(self, at_least_bits_precision: u32) -> Self::Output {
+        (&self).resize_unchecked(at_least_bits_precision)
+    }
+
+    fn try_resize(self, at_least_bits_precision: u32) -> Option<BoxedInt> {
+        (&self).try_resize(at_least_bits_precision)
+    }
+}
+
+impl Resize for &BoxedInt {
+    type Output = BoxedInt;
+
+    /// Resize `self`, sign-extending when the precision grows and truncating when it shrinks.
+    fn resize_unchecked(self, at_least_bits_precision: u32) -> Self::Output {
+        let extension = self.sign_extension();
+        let mut ret = BoxedUint::zero_with_precision(at_least_bits_precision);
+        for (i, limb) in ret.limbs.iter_mut().enumerate() {
+            *limb = self.sign_extended_limb(i, extension);
+        }
+        BoxedInt(ret)
+    }
+
+    fn try_resize(self, at_least_bits_precision: u32) -> Option<BoxedInt> {
+        let ret = self.resize_unchecked(at_least_bits_precision);
+        let roundtrip = (&ret).resize_unchecked(self.bits_precision());
+        if roundtrip.0.ct_eq(&self.0).to_bool_vartime() {
+            Some(ret)
+        } else {
+            None
+        }
+    }
+}
+
+impl AsRef<[Word]> for BoxedInt {
+    fn as_ref(&self) -> &[Word] {
+        self.as_words()
+    }
+}
+
+impl AsMut<[Word]> for BoxedInt {
+    fn as_mut(&mut self) -> &mut [Word] {
+        self.as_mut_words()
+    }
+}
+
+impl AsRef<[Limb]> for BoxedInt {
+    fn as_ref(&self) -> &[Limb] {
+        self.as_limbs()
+    }
+}
+
+impl AsMut<[Limb]> for BoxedInt {
+    fn as_mut(&mut self) -> &mut [Limb] {
+        self.as_mut_limbs()
+    }
+}
+
+impl Default for BoxedInt {
+    fn default() -> Self {
+        Self::zero()
+    }
+}
+
+impl Integer for BoxedInt {
+    fn as_limbs(&self) -> &[Limb] {
+        self.0.as_limbs()
+    }
+
+    fn as_mut_limbs(&mut self) -> &mut [Limb] {
+        self.0.as_mut_limbs()
+    }
+
+    fn nlimbs(&self) -> usize {
+        self.0.nlimbs()
+    }
+}
+
+impl Signed for BoxedInt {
+    type Unsigned = BoxedUint;
+
+    fn abs_sign(&self) -> (BoxedUint, Choice) {
+        self.abs_sign()
+    }
+
+    fn is_negative(&self) -> Choice {
+        self.is_negative()
+    }
+
+    fn is_positive(&self) -> Choice {
+        self.is_positive()
+    }
+}
+
+impl Zero for BoxedInt {
+    fn zero() -> Self {
+        Self::zero()
+    }
+
+    fn is_zero(&self) -> Choice {
+        self.is_zero()
+    }
+
+    fn set_zero(&mut self) {
+        Zero::set_zero(&mut self.0)
+    }
+}
+
+impl One for BoxedInt {
+    fn one() -> Self {
+        Self::one()
+    }
+
+    fn one_like(other: &Self) -> Self {
+        Self(BoxedUint::one_like(&other.0))
+    }
+
+    fn is_one(&self) -> Choice {
+        self.is_one()
+    }
+
+    fn set_one(&mut self) {
+        One::set_one(&mut self.0)
+    }
+}
+
+impl num_traits::Zero for BoxedInt {
+    fn zero() -> Self {
+        Self::zero()
+    }
+
+    fn is_zero(&self) -> bool {
+        self.is_zero().into()
+    }
+
+    fn set_zero(&mut self) {
+        Zero::set_zero(self)
+    }
+}
+
+impl num_traits::One for BoxedInt {
+    fn one() -> Self {
+        Self::one()
+    }
+
+    fn is_one(&self) -> bool {
+        self.is_one().into()
+    }
+
+    fn set_one(&mut self) {
+        One::set_one(self)
+    }
+}
+
+#[cfg(feature = "zeroize")]
+impl Zeroize for BoxedInt {
+    fn zeroize(&mut self) {
+        self.0.zeroize();
+    }
+}
+
+impl fmt::Debug for BoxedInt {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "BoxedInt(0x{self:X})")
+    }
+}
+
+impl fmt::Display for BoxedInt {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt::UpperHex::fmt(self, f)
+    }
+}
+
+impl fmt::Binary for BoxedInt {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt::Binary::fmt(&self.0, f)
+    }
+}
+
+impl fmt::LowerHex for BoxedInt {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt::LowerHex::fmt(&self.0, f)
+    }
+}
+
+impl fmt::UpperHex for BoxedInt {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt::UpperHex::fmt(&self.0, f)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::BoxedInt;
+    use crate::{BoxedUint, Resize};
+
+    #[test]
+    fn min_max() {
+        let min = BoxedInt::min(128);
+        let max = BoxedInt::max(128);
+        assert_eq!(min.as_uint(), &BoxedUint::one().resize(128).shl(127));
+        assert_eq!(max.as_uint(), &BoxedUint::max(128).shr(1));
+        assert!(min.is_min().to_bool());
+        assert!(max.is_max().to_bool());
+        assert!(!max.is_min().to_bool());
+    }
+
+    #[test]
+    fn resize_sign_extends() {
+        let minus_one = BoxedInt::minus_one_with_precision(64);
+        let wide = (&minus_one).resize(256);
+        assert_eq!(wide, BoxedInt::minus_one_with_precision(256));
+        assert_eq!(wide.resize(64), minus_one);
+
+        let min = BoxedInt::min(128);
+        assert!((&min).try_resize(64).is_none());
+        assert_eq!((&min).resize(256).resize(128), min);
+    }
+
+    #[test]
+    fn fmt_upper_hex() {
+        let n = BoxedInt::from_be_hex("aaaaaaaabbbbbbbbccccccccdddddddd", 128).unwrap();
+        assert_eq!(format!("{n:X}"), "AAAAAAAABBBBBBBBCCCCCCCCDDDDDDDD");
+        assert_eq!(
+            format!("{n:?}"),
+            "BoxedInt(0xAAAAAAAABBBBBBBBCCCCCCCCDDDDDDDD)"
+        );
+    }
+
+    #[test]
+    fn to_uint() {
+        assert!(bool::from(BoxedInt::min(128).try_into_uint().is_none()));
+        assert!(bool::from(
+            BoxedInt::minus_one_with_precision(128)
+                .try_into_uint()
+                .is_none()
+        ));
+        assert_eq!(
+            BoxedInt::max(128).try_into_uint().unwrap(),
+            BoxedUint::max(128).shr(1)
+        );
+    }
+}
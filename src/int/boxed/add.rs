@@ -0,0 +1,154 @@
+//! [`BoxedInt`] addition operations.
+
+use crate::{Add, AddAssign, BoxedInt, CheckedAdd, Choice, CtOption, Limb, Wrapping, WrappingAdd};
+
+impl BoxedInt {
+    /// Perform checked addition. Returns `none` when the addition overflowed.
+    ///
+    /// The result has the precision of the wider of the two operands.
+    pub fn checked_add(&self, rhs: &Self) -> CtOption<Self> {
+        let (value, overflow) = self.overflowing_add(rhs);
+        CtOption::new(value, !overflow)
+    }
+
+    /// Perform addition, raising the `overflow` flag on overflow.
+    ///
+    /// The result has the precision of the wider of the two operands.
+    pub fn overflowing_add(&self, rhs: &Self) -> (Self, Choice) {
+        // Step 1. add operands
+        let res = self.wrapping_add(rhs);
+
+        // Step 2. determine whether overflow happened.
+        // Note:
+        // - overflow can only happen when the inputs have the same sign, and
+        // - overflow occurs if and only if the result has the opposite sign from both inputs.
+        //
+        // We can thus express the overflow flag as: (self.msb == rhs.msb) & (self.msb != res.msb)
+        let self_msb = self.is_negative();
+        let overflow = self_msb.eq(rhs.is_negative()) & self_msb.ne(res.is_negative());
+
+        // Step 3. Construct result
+        (res, overflow)
+    }
+
+    /// Perform wrapping addition, discarding overflow.
+    ///
+    /// The result has the precision of the wider of the two operands.
+    pub fn wrapping_add(&self, rhs: &Self) -> Self {
+        Self::fold_limbs(self, rhs, Limb::ZERO, |a, b, c| a.carrying_add(b, c)).0
+    }
+}
+
+impl Add for BoxedInt {
+    type Output = Self;
+
+    fn add(self, rhs: Self) -> Self {
+        self.add(&rhs)
+    }
+}
+
+impl Add<&BoxedInt> for BoxedInt {
+    type Output = Self;
+
+    fn add(self, rhs: &Self) -> Self {
+        Add::add(&self, rhs)
+    }
+}
+
+impl Add<BoxedInt> for &BoxedInt {
+    type Output = BoxedInt;
+
+    fn add(self, rhs: BoxedInt) -> BoxedInt {
+        Add::add(self, &rhs)
+    }
+}
+
+impl Add<&BoxedInt> for &BoxedInt {
+    type Output = BoxedInt;
+
+    fn add(self, rhs: &BoxedInt) -> BoxedInt {
+        self.checked_add(rhs)
+            .expect("attempted to add with overflow")
+    }
+}
+
+impl AddAssign for BoxedInt {
+    fn add_assign(&mut self, rhs: Self) {
+        *self += &rhs;
+    }
+}
+
+impl AddAssign<&BoxedInt> for BoxedInt {
+    fn add_assign(&mut self, rhs: &Self) {
+        *self = &*self + rhs;
+    }
+}
+
+impl AddAssign for Wrapping<BoxedInt> {
+    fn add_assign(&mut self, other: Self) {
+        *self += &other;
+    }
+}
+
+impl AddAssign<&Wrapping<BoxedInt>> for Wrapping<BoxedInt> {
+    fn add_assign(&mut self, other: &Self) {
+        self.0 = self.0.wrapping_add(&other.0);
+    }
+}
+
+impl CheckedAdd for BoxedInt {
+    fn checked_add(&self, rhs: &Self) -> CtOption<Self> {
+        self.checked_add(rhs)
+    }
+}
+
+impl WrappingAdd for BoxedInt {
+    fn wrapping_add(&self, v: &Self) -> Self {
+        self.wrapping_add(v)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{BoxedInt, Resize};
+
+    #[test]
+    fn checked_add() {
+        let min = BoxedInt::min(128);
+        let max = BoxedInt::max(128);
+        let minus_one = BoxedInt::minus_one_with_precision(128);
+        let one = BoxedInt::one_with_precision(128);
+        let zero = BoxedInt::zero_with_precision(128);
+
+        assert!(min.checked_add(&min).is_none().to_bool());
+        assert!(min.checked_add(&minus_one).is_none().to_bool());
+        assert_eq!(min.checked_add(&zero).unwrap(), min);
+        assert_eq!(min.checked_add(&max).unwrap(), minus_one);
+        assert_eq!(minus_one.checked_add(&one).unwrap(), zero);
+        assert!(max.checked_add(&one).is_none().to_bool());
+        assert_eq!(max.checked_add(&minus_one).unwrap(), max.wrapping_sub(&one));
+    }
+
+    #[test]
+    fn add_mixed_precision() {
+        let minus_one = BoxedInt::minus_one_with_precision(64);
+        let max = BoxedInt::max(128);
+
+        // `minus_one` is sign-extended to the precision of `max`
+        let res = &minus_one + &max;
+        assert_eq!(res.bits_precision(), 128);
+        assert_eq!(res, max.wrapping_sub(&BoxedInt::one()));
+
+        let res = &max + &minus_one;
+        assert_eq!(res, max.wrapping_sub(&BoxedInt::one()));
+
+        let res = BoxedInt::max(64) + BoxedInt::one_with_precision(128);
+        assert_eq!(res, BoxedInt::min(64).resize(128).wrapping_neg());
+    }
+
+    #[test]
+    #[should_panic]
+    fn add_overflow() {
+        let _ = BoxedInt::max(128) + BoxedInt::one();
+    }
+}
@@ -0,0 +1,112 @@
+//! [`BoxedInt`] bitwise AND operations.
+
+use crate::{BoxedInt, CtOption, Wrapping};
+use core::ops::{BitAnd, BitAndAssign};
+
+impl BoxedInt {
+    /// Computes bitwise `a & b`.
+    ///
+    /// The result has the precision of the wider of the two operands.
+    #[inline(always)]
+    pub fn bitand(&self, rhs: &Self) -> Self {
+        Self::map_limbs(self, rhs, |a, b| a.bitand(b))
+    }
+
+    /// Perform wrapping bitwise `AND`.
+    ///
+    /// There's no way wrapping could ever happen.
+    /// This function exists so that all operations are accounted for in the wrapping operations
+    pub fn wrapping_and(&self, rhs: &Self) -> Self {
+        self.bitand(rhs)
+    }
+
+    /// Perform checked bitwise `AND`, returning a [`CtOption`] which `is_some` always
+    pub fn checked_and(&self, rhs: &Self) -> CtOption<Self> {
+        CtOption::some(self.bitand(rhs))
+    }
+}
+
+impl BitAnd for BoxedInt {
+    type Output = Self;
+
+    fn bitand(self, rhs: Self) -> BoxedInt {
+        BoxedInt::bitand(&self, &rhs)
+    }
+}
+
+impl BitAnd<&BoxedInt> for BoxedInt {
+    type Output = BoxedInt;
+
+    fn bitand(self, rhs: &BoxedInt) -> BoxedInt {
+        BoxedInt::bitand(&self, rhs)
+    }
+}
+
+impl BitAnd<BoxedInt> for &BoxedInt {
+    type Output = BoxedInt;
+
+    fn bitand(self, rhs: BoxedInt) -> BoxedInt {
+        BoxedInt::bitand(self, &rhs)
+    }
+}
+
+impl BitAnd<&BoxedInt> for &BoxedInt {
+    type Output = BoxedInt;
+
+    fn bitand(self, rhs: &BoxedInt) -> BoxedInt {
+        BoxedInt::bitand(self, rhs)
+    }
+}
+
+impl BitAndAssign for BoxedInt {
+    fn bitand_assign(&mut self, other: Self) {
+        *self = BoxedInt::bitand(self, &other);
+    }
+}
+
+impl BitAndAssign<&BoxedInt> for BoxedInt {
+    fn bitand_assign(&mut self, other: &Self) {
+        *self = BoxedInt::bitand(self, other);
+    }
+}
+
+impl BitAnd for Wrapping<BoxedInt> {
+    type Output = Self;
+
+    fn bitand(self, rhs: Self) -> Wrapping<BoxedInt> {
+        Wrapping(BoxedInt::bitand(&self.0, &rhs.0))
+    }
+}
+
+impl BitAnd<&Wrapping<BoxedInt>> for &Wrapping<BoxedInt> {
+    type Output = Wrapping<BoxedInt>;
+
+    fn bitand(self, rhs: &Wrapping<BoxedInt>) -> Wrapping<BoxedInt> {
+        Wrapping(BoxedInt::bitand(&self.0, &rhs.0))
+    }
+}
+
+impl BitAndAssign for Wrapping<BoxedInt> {
+    fn bitand_assign(&mut self, other: Self) {
+        self.0 &= other.0;
+    }
+}
+
+impl BitAndAssign<&Wrapping<BoxedInt>> for Wrapping<BoxedInt> {
+    fn bitand_assign(&mut self, other: &Self) {
+        self.0 &= &other.0;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::BoxedInt;
+
+    #[test]
+    fn bitand_sign_extends() {
+        let minus_one = BoxedInt::minus_one_with_precision(64);
+        let max = BoxedInt::max(128);
+        assert_eq!(&minus_one & &max, max);
+        assert_eq!(&max & &BoxedInt::zero(), BoxedInt::zero_with_precision(128));
+    }
+}
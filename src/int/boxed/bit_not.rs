@@ -0,0 +1,41 @@
+//! [`BoxedInt`] bitwise NOT operations.
+
+use crate::{BoxedInt, BoxedUint, Wrapping};
+use core::ops::Not;
+
+impl BoxedInt {
+    /// Computes bitwise `!a`.
+    pub fn not(&self) -> Self {
+        Self(BoxedUint::not(&self.0))
+    }
+}
+
+impl Not for BoxedInt {
+    type Output = Self;
+
+    fn not(self) -> Self {
+        BoxedInt::not(&self)
+    }
+}
+
+impl Not for Wrapping<BoxedInt> {
+    type Output = Self;
+
+    fn not(self) -> <Self as Not>::Output {
+        Wrapping(self.0.not())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::BoxedInt;
+
+    #[test]
+    fn bitnot_ok() {
+        assert_eq!(
+            BoxedInt::zero_with_precision(128).not(),
+            BoxedInt::minus_one_with_precision(128)
+        );
+        assert_eq!(BoxedInt::max(128).not(), BoxedInt::min(128));
+    }
+}
@@ -0,0 +1,111 @@
+//! [`BoxedInt`] bitwise OR operations.
+
+use crate::{BoxedInt, CtOption, Wrapping};
+use core::ops::{BitOr, BitOrAssign};
+
+impl BoxedInt {
+    /// Computes bitwise `a | b`.
+    ///
+    /// The result has the precision of the wider of the two operands.
+    #[inline(always)]
+    pub fn bitor(&self, rhs: &Self) -> Self {
+        Self::map_limbs(self, rhs, |a, b| a.bitor(b))
+    }
+
+    /// Perform wrapping bitwise `OR`.
+    ///
+    /// There's no way wrapping could ever happen.
+    /// This function exists so that all operations are accounted for in the wrapping operations
+    pub fn wrapping_or(&self, rhs: &Self) -> Self {
+        self.bitor(rhs)
+    }
+
+    /// Perform checked bitwise `OR`, returning a [`CtOption`] which `is_some` always
+    pub fn checked_or(&self, rhs: &Self) -> CtOption<Self> {
+        CtOption::some(self.bitor(rhs))
+    }
+}
+
+impl BitOr for BoxedInt {
+    type Output = Self;
+
+    fn bitor(self, rhs: Self) -> BoxedInt {
+        BoxedInt::bitor(&self, &rhs)
+    }
+}
+
+impl BitOr<&BoxedInt> for BoxedInt {
+    type Output = BoxedInt;
+
+    fn bitor(self, rhs: &BoxedInt) -> BoxedInt {
+        BoxedInt::bitor(&self, rhs)
+    }
+}
+
+impl BitOr<BoxedInt> for &BoxedInt {
+    type Output = BoxedInt;
+
+    fn bitor(self, rhs: BoxedInt) -> BoxedInt {
+        BoxedInt::bitor(self, &rhs)
+    }
+}
+
+impl BitOr<&BoxedInt> for &BoxedInt {
+    type Output = BoxedInt;
+
+    fn bitor(self, rhs: &BoxedInt) -> BoxedInt {
+        BoxedInt::bitor(self, rhs)
+    }
+}
+
+impl BitOrAssign for BoxedInt {
+    fn bitor_assign(&mut self, other: Self) {
+        *self = BoxedInt::bitor(self, &other);
+    }
+}
+
+impl BitOrAssign<&BoxedInt> for BoxedInt {
+    fn bitor_assign(&mut self, other: &Self) {
+        *self = BoxedInt::bitor(self, other);
+    }
+}
+
+impl BitOr for Wrapping<BoxedInt> {
+    type Output = Self;
+
+    fn bitor(self, rhs: Self) -> Wrapping<BoxedInt> {
+        Wrapping(BoxedInt::bitor(&self.0, &rhs.0))
+    }
+}
+
+impl BitOr<&Wrapping<BoxedInt>> for &Wrapping<BoxedInt> {
+    type Output = Wrapping<BoxedInt>;
+
+    fn bitor(self, rhs: &Wrapping<BoxedInt>) -> Wrapping<BoxedInt> {
+        Wrapping(BoxedInt::bitor(&self.0, &rhs.0))
+    }
+}
+
+impl BitOrAssign for Wrapping<BoxedInt> {
+    fn bitor_assign(&mut self, other: Self) {
+        self.0 |= other.0;
+    }
+}
+
+impl BitOrAssign<&Wrapping<BoxedInt>> for Wrapping<BoxedInt> {
+    fn bitor_assign(&mut self, other: &Self) {
+        self.0 |= &other.0;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::BoxedInt;
+
+    #[test]
+    fn bitor_sign_extends() {
+        let minus_one = BoxedInt::minus_one_with_precision(64);
+        let max = BoxedInt::max(128);
+        assert_eq!(&minus_one | &max, BoxedInt::minus_one_with_precision(128));
+    }
+}
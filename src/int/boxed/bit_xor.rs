@@ -0,0 +1,112 @@
+//! [`BoxedInt`] bitwise XOR operations.
+
+use crate::{BoxedInt, CtOption, Wrapping};
+use core::ops::{BitXor, BitXorAssign};
+
+impl BoxedInt {
+    /// Computes bitwise `a ^ b`.
+    ///
+    /// The result has the precision of the wider of the two operands.
+    #[inline(always)]
+    pub fn bitxor(&self, rhs: &Self) -> Self {
+        Self::map_limbs(self, rhs, |a, b| a.bitxor(b))
+    }
+
+    /// Perform wrapping bitwise `XOR`.
+    ///
+    /// There's no way wrapping could ever happen.
+    /// This function exists so that all operations are accounted for in the wrapping operations
+    pub fn wrapping_xor(&self, rhs: &Self) -> Self {
+        self.bitxor(rhs)
+    }
+
+    /// Perform checked bitwise `XOR`, returning a [`CtOption`] which `is_some` always
+    pub fn checked_xor(&self, rhs: &Self) -> CtOption<Self> {
+        CtOption::some(self.bitxor(rhs))
+    }
+}
+
+impl BitXor for BoxedInt {
+    type Output = Self;
+
+    fn bitxor(self, rhs: Self) -> BoxedInt {
+        BoxedInt::bitxor(&self, &rhs)
+    }
+}
+
+impl BitXor<&BoxedInt> for BoxedInt {
+    type Output = BoxedInt;
+
+    fn bitxor(self, rhs: &BoxedInt) -> BoxedInt {
+        BoxedInt::bitxor(&self, rhs)
+    }
+}
+
+impl BitXor<BoxedInt> for &BoxedInt {
+    type Output = BoxedInt;
+
+    fn bitxor(self, rhs: BoxedInt) -> BoxedInt {
+        BoxedInt::bitxor(self, &rhs)
+    }
+}
+
+impl BitXor<&BoxedInt> for &BoxedInt {
+    type Output = BoxedInt;
+
+    fn bitxor(self, rhs: &BoxedInt) -> BoxedInt {
+        BoxedInt::bitxor(self, rhs)
+    }
+}
+
+impl BitXorAssign for BoxedInt {
+    fn bitxor_assign(&mut self, other: Self) {
+        *self = BoxedInt::bitxor(self, &other);
+    }
+}
+
+impl BitXorAssign<&BoxedInt> for BoxedInt {
+    fn bitxor_assign(&mut self, other: &Self) {
+        *self = BoxedInt::bitxor(self, other);
+    }
+}
+
+impl BitXor for Wrapping<BoxedInt> {
+    type Output = Self;
+
+    fn bitxor(self, rhs: Self) -> Wrapping<BoxedInt> {
+        Wrapping(BoxedInt::bitxor(&self.0, &rhs.0))
+    }
+}
+
+impl BitXor<&Wrapping<BoxedInt>> for &Wrapping<BoxedInt> {
+    type Output = Wrapping<BoxedInt>;
+
+    fn bitxor(self, rhs: &Wrapping<BoxedInt>) -> Wrapping<BoxedInt> {
+        Wrapping(BoxedInt::bitxor(&self.0, &rhs.0))
+    }
+}
+
+impl BitXorAssign for Wrapping<BoxedInt> {
+    fn bitxor_assign(&mut self, other: Self) {
+        self.0 ^= other.0;
+    }
+}
+
+impl BitXorAssign<&Wrapping<BoxedInt>> for Wrapping<BoxedInt> {
+    fn bitxor_assign(&mut self, other: &Self) {
+        self.0 ^= &other.0;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::BoxedInt;
+
+    #[test]
+    fn bitxor_sign_extends() {
+        let minus_one = BoxedInt::minus_one_with_precision(64);
+        let max = BoxedInt::max(128);
+        assert_eq!(&minus_one ^ &max, BoxedInt::min(128));
+        assert_eq!(&max ^ &max, BoxedInt::zero_with_precision(128));
+    }
+}
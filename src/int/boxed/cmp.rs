@@ -0,0 +1,151 @@
+//! [`BoxedInt`] comparisons.
+//!
+//! By default, these are all constant-time.
+//!
+//! Values of differing precision are compared as if the narrower one were sign-extended to the
+//! precision of the wider one.
+
+use crate::{BoxedInt, BoxedUint, Choice, CtEq, CtGt, CtLt, Resize};
+use core::cmp::{Ordering, max};
+
+impl BoxedInt {
+    /// Map `lhs` and `rhs` to [`BoxedUint`]s of equal precision whose unsigned order matches the
+    /// signed order of the inputs.
+    fn ordered_pair(lhs: &Self, rhs: &Self) -> (BoxedUint, BoxedUint) {
+        let bits_precision = max(lhs.bits_precision(), rhs.bits_precision());
+        (
+            lhs.resize_unchecked(bits_precision).invert_msb(),
+            rhs.resize_unchecked(bits_precision).invert_msb(),
+        )
+    }
+
+    /// Returns the Ordering between `self` and `rhs` in variable time.
+    pub fn cmp_vartime(&self, rhs: &Self) -> Ordering {
+        let (lhs, rhs) = Self::ordered_pair(self, rhs);
+        lhs.cmp_vartime(&rhs)
+    }
+}
+
+impl CtEq for BoxedInt {
+    #[inline]
+    fn ct_eq(&self, other: &Self) -> Choice {
+        let (lhs_ext, rhs_ext) = (self.sign_extension(), other.sign_extension());
+        let mut ret = Choice::TRUE;
+
+        for i in 0..max(self.nlimbs(), other.nlimbs()) {
+            let a = self.sign_extended_limb(i, lhs_ext);
+            let b = other.sign_extended_limb(i, rhs_ext);
+            ret &= a.ct_eq(&b);
+        }
+
+        ret
+    }
+}
+
+impl CtGt for BoxedInt {
+    #[inline]
+    fn ct_gt(&self, other: &Self) -> Choice {
+        let (lhs, rhs) = Self::ordered_pair(self, other);
+        lhs.ct_gt(&rhs)
+    }
+}
+
+impl CtLt for BoxedInt {
+    #[inline]
+    fn ct_lt(&self, other: &Self) -> Choice {
+        let (lhs, rhs) = Self::ordered_pair(self, other);
+        lhs.ct_lt(&rhs)
+    }
+}
+
+impl Eq for BoxedInt {}
+
+impl PartialEq for BoxedInt {
+    fn eq(&self, other: &Self) -> bool {
+        self.ct_eq(other).into()
+    }
+}
+
+impl Ord for BoxedInt {
+    fn cmp(&self, other: &Self) -> Ordering {
+        let (lhs, rhs) = Self::ordered_pair(self, other);
+        lhs.cmp(&rhs)
+    }
+}
+
+impl PartialOrd for BoxedInt {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+#[cfg(feature = "subtle")]
+impl subtle::ConstantTimeEq for BoxedInt {
+    #[inline]
+    fn ct_eq(&self, other: &Self) -> subtle::Choice {
+        CtEq::ct_eq(self, other).into()
+    }
+}
+
+#[cfg(feature = "subtle")]
+impl subtle::ConstantTimeGreater for BoxedInt {
+    #[inline]
+    fn ct_gt(&self, other: &Self) -> subtle::Choice {
+        CtGt::ct_gt(self, other).into()
+    }
+}
+
+#[cfg(feature = "subtle")]
+impl subtle::ConstantTimeLess for BoxedInt {
+    #[inline]
+    fn ct_lt(&self, other: &Self) -> subtle::Choice {
+        CtLt::ct_lt(self, other).into()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{BoxedInt, CtEq, CtGt, CtLt};
+    use core::cmp::Ordering;
+
+    #[test]
+    fn ct_eq() {
+        let a = BoxedInt::minus_one_with_precision(64);
+        let b = BoxedInt::minus_one_with_precision(192);
+        let c = BoxedInt::max(64);
+
+        assert!(a.ct_eq(&b).to_bool());
+        assert!(b.ct_eq(&a).to_bool());
+        assert!(!a.ct_eq(&c).to_bool());
+        assert!(!b.ct_eq(&c).to_bool());
+    }
+
+    #[test]
+    fn ct_gt_lt() {
+        let min = BoxedInt::min(128);
+        let minus_one = BoxedInt::minus_one_with_precision(64);
+        let zero = BoxedInt::zero();
+        let max = BoxedInt::max(128);
+
+        assert!(max.ct_gt(&zero).to_bool());
+        assert!(zero.ct_gt(&minus_one).to_bool());
+        assert!(minus_one.ct_gt(&min).to_bool());
+        assert!(!min.ct_gt(&minus_one).to_bool());
+        assert!(min.ct_lt(&max).to_bool());
+        assert!(minus_one.ct_lt(&zero).to_bool());
+        assert!(!zero.ct_lt(&zero).to_bool());
+    }
+
+    #[test]
+    fn cmp() {
+        let min = BoxedInt::min(128);
+        let minus_one = BoxedInt::minus_one_with_precision(64);
+        let max = BoxedInt::max(128);
+
+        assert_eq!(min.cmp(&minus_one), Ordering::Less);
+        assert_eq!(max.cmp(&minus_one), Ordering::Greater);
+        assert_eq!(minus_one.cmp(&minus_one), Ordering::Equal);
+        assert_eq!(min.cmp_vartime(&max), Ordering::Less);
+        assert_eq!(max.cmp_vartime(&min), Ordering::Greater);
+    }
+}
@@ -0,0 +1,367 @@
+//! [`BoxedInt`] division operations.
+
+use crate::{
+    BoxedInt, BoxedUint, CheckedDiv, Choice, CtOption, CtSelect, Div, DivAssign, DivVartime,
+    NonZero, Rem, RemAssign, Wrapping,
+};
+
+/// Checked division operations.
+impl BoxedInt {
+    /// Base div_rem operation on dividing [`BoxedInt`]s.
+    ///
+    /// Computes the quotient and remainder of `self / rhs`.
+    /// Furthermore, returns the signs of `self` and `rhs`.
+    #[inline]
+    fn div_rem_base(&self, rhs: &NonZero<Self>) -> (BoxedUint, BoxedUint, Choice, Choice) {
+        // Step 1: split operands into signs and magnitudes.
+        let (lhs_mag, lhs_sgn) = self.abs_sign();
+        let (rhs_mag, rhs_sgn) = rhs.abs_sign();
+
+        // Step 2. Divide magnitudes
+        let (quotient, remainder) = lhs_mag.div_rem(&rhs_mag);
+
+        (quotient, remainder, lhs_sgn, rhs_sgn)
+    }
+
+    /// Compute the quotient and remainder of `self / rhs`.
+    ///
+    /// The quotient has the precision of `self`, the remainder the precision of `rhs`.
+    ///
+    /// Returns `none` for the quotient when `MIN / -1`; that quotient cannot be captured in a
+    /// [`BoxedInt`] with the precision of `self`.
+    ///
+    /// Note: this operation rounds towards zero, truncating any fractional part of the exact
+    /// result. The remainder has the same sign as `self`.
+    ///
+    /// Example:
+    /// ```
+    /// use crypto_bigint::BoxedInt;
+    /// let three = BoxedInt::from(3i64).to_nz().unwrap();
+    /// let (quotient, remainder) = BoxedInt::from(-8i64).checked_div_rem(&three);
+    /// assert_eq!(quotient.unwrap(), BoxedInt::from(-2i64));
+    /// assert_eq!(remainder, BoxedInt::from(-2i64));
+    /// ```
+    pub fn checked_div_rem(&self, rhs: &NonZero<Self>) -> (CtOption<Self>, Self) {
+        let (quotient, remainder, lhs_sgn, rhs_sgn) = self.div_rem_base(rhs);
+        let opposing_signs = lhs_sgn.ne(rhs_sgn);
+        (
+            Self::new_from_abs_sign(quotient, opposing_signs),
+            // safe; the magnitude of the remainder is smaller than that of `rhs`.
+            BoxedInt(remainder).wrapping_neg_if(lhs_sgn),
+        )
+    }
+
+    /// Perform checked division, returning a [`CtOption`] which `is_some` if
+    /// - the `rhs != 0`, and
+    /// - `self != MIN` or `rhs != MINUS_ONE`.
+    ///
+    /// Note: this operation rounds towards zero, truncating any fractional part of the exact result.
+    pub fn checked_div(&self, rhs: &Self) -> CtOption<Self> {
+        NonZero::new(rhs.clone()).and_then(|rhs| self.checked_div_rem(&rhs).0)
+    }
+
+    /// Computes `self % rhs`, returns the remainder.
+    ///
+    /// The remainder has the precision of `rhs` and the same sign as `self`.
+    pub fn rem(&self, rhs: &NonZero<Self>) -> Self {
+        self.checked_div_rem(rhs).1
+    }
+}
+
+/// Vartime checked division operations.
+impl BoxedInt {
+    /// Variable time equivalent of [`Self::div_rem_base`].
+    ///
+    /// This is variable only with respect to `rhs`.
+    ///
+    /// When used with a fixed `rhs`, this function is constant-time with respect
+    /// to `self`.
+    #[inline]
+    fn div_rem_base_vartime(&self, rhs: &NonZero<Self>) -> (BoxedUint, BoxedUint, Choice, Choice) {
+        let (lhs_mag, lhs_sgn) = self.abs_sign();
+        let (rhs_mag, rhs_sgn) = rhs.abs_sign();
+        let (quotient, remainder) = lhs_mag.div_rem_vartime(&rhs_mag);
+        (quotient, remainder, lhs_sgn, rhs_sgn)
+    }
+
+    /// Variable time equivalent of [`Self::checked_div_rem`].
+    ///
+    /// This is variable only with respect to `rhs`.
+    ///
+    /// When used with a fixed `rhs`, this function is constant-time with respect
+    /// to `self`.
+    pub fn checked_div_rem_vartime(&self, rhs: &NonZero<Self>) -> (CtOption<Self>, Self) {
+        let (quotient, remainder, lhs_sgn, rhs_sgn) = self.div_rem_base_vartime(rhs);
+        let opposing_signs = lhs_sgn.ne(rhs_sgn);
+        (
+            Self::new_from_abs_sign(quotient, opposing_signs),
+            BoxedInt(remainder).wrapping_neg_if(lhs_sgn),
+        )
+    }
+
+    /// Variable time equivalent of [`Self::checked_div`].
+    ///
+    /// This is variable only with respect to `rhs`.
+    ///
+    /// When used with a fixed `rhs`, this function is constant-time with respect
+    /// to `self`.
+    pub fn checked_div_vartime(&self, rhs: &NonZero<Self>) -> CtOption<Self> {
+        self.checked_div_rem_vartime(rhs).0
+    }
+
+    /// Variable time equivalent of [`Self::rem`].
+    ///
+    /// This is variable only with respect to `rhs`.
+    ///
+    /// When used with a fixed `rhs`, this function is constant-time with respect
+    /// to `self`.
+    pub fn rem_vartime(&self, rhs: &NonZero<Self>) -> Self {
+        self.checked_div_rem_vartime(rhs).1
+    }
+}
+
+/// Checked div-floor operations.
+impl BoxedInt {
+    /// Perform checked floored division, returning a [`CtOption`] which `is_some` only if
+    /// - the `rhs != 0`, and
+    /// - `self != MIN` or `rhs != MINUS_ONE`.
+    ///
+    /// Note: this operation rounds down.
+    pub fn checked_div_floor(&self, rhs: &Self) -> CtOption<Self> {
+        NonZero::new(rhs.clone()).and_then(|rhs| self.checked_div_rem_floor(&rhs).0)
+    }
+
+    /// Perform checked division and mod, returning the quotient and remainder.
+    ///
+    /// The quotient is a [`CtOption`] which `is_some` only if
+    /// - the `rhs != 0`, and
+    /// - `self != MIN` or `rhs != MINUS_ONE`.
+    ///
+    /// Note: this operation rounds down; the remainder has the same sign as `rhs`.
+    ///
+    /// Example:
+    /// ```
+    /// use crypto_bigint::BoxedInt;
+    /// let three = BoxedInt::from(3i64).to_nz().unwrap();
+    /// let (quotient, remainder) = BoxedInt::from(-8i64).checked_div_rem_floor(&three);
+    /// assert_eq!(quotient.unwrap(), BoxedInt::from(-3i64));
+    /// assert_eq!(remainder, BoxedInt::from(1i64));
+    /// ```
+    pub fn checked_div_rem_floor(&self, rhs: &NonZero<Self>) -> (CtOption<Self>, Self) {
+        let (quotient, remainder, lhs_sgn, rhs_sgn) = self.div_rem_base(rhs);
+
+        // Modify quotient and remainder when lhs and rhs have opposing signs and the remainder is
+        // non-zero.
+        let opposing_signs = lhs_sgn.xor(rhs_sgn);
+        let modify = remainder.is_nonzero().and(opposing_signs);
+
+        // Increase the quotient by one.
+        let quotient_plus_one =
+            quotient.wrapping_add(&BoxedUint::one_with_precision(quotient.bits_precision())); // cannot wrap.
+        let quotient = quotient.ct_select(&quotient_plus_one, modify);
+
+        // Invert the remainder.
+        let inv_remainder = rhs.abs().as_ref().wrapping_sub(&remainder);
+        let remainder = remainder.ct_select(&inv_remainder, modify);
+
+        // Negate output when lhs and rhs have opposing signs, and the remainder when rhs is
+        // negative.
+        let quotient = Self::new_from_abs_sign(quotient, opposing_signs);
+        let remainder = BoxedInt(remainder).wrapping_neg_if(rhs_sgn); // rem always small enough
+
+        (quotient, remainder)
+    }
+}
+
+impl CheckedDiv for BoxedInt {
+    fn checked_div(&self, rhs: &BoxedInt) -> CtOption<Self> {
+        self.checked_div(rhs)
+    }
+}
+
+impl Div<&NonZero<BoxedInt>> for &BoxedInt {
+    type Output = CtOption<BoxedInt>;
+
+    fn div(self, rhs: &NonZero<BoxedInt>) -> Self::Output {
+        self.checked_div_rem(rhs).0
+    }
+}
+
+impl Div<&NonZero<BoxedInt>> for BoxedInt {
+    type Output = CtOption<BoxedInt>;
+
+    fn div(self, rhs: &NonZero<BoxedInt>) -> Self::Output {
+        &self / rhs
+    }
+}
+
+impl Div<NonZero<BoxedInt>> for &BoxedInt {
+    type Output = CtOption<BoxedInt>;
+
+    fn div(self, rhs: NonZero<BoxedInt>) -> Self::Output {
+        self / &rhs
+    }
+}
+
+impl Div<NonZero<BoxedInt>> for BoxedInt {
+    type Output = CtOption<BoxedInt>;
+
+    fn div(self, rhs: NonZero<BoxedInt>) -> Self::Output {
+        &self / &rhs
+    }
+}
+
+impl DivAssign<&NonZero<BoxedInt>> for BoxedInt {
+    fn div_assign(&mut self, rhs: &NonZero<BoxedInt>) {
+        *self = (&*self / rhs).expect("cannot represent positive equivalent of MIN as int");
+    }
+}
+
+impl DivAssign<NonZero<BoxedInt>> for BoxedInt {
+    fn div_assign(&mut self, rhs: NonZero<BoxedInt>) {
+        *self /= &rhs
+    }
+}
+
+impl DivVartime for BoxedInt {
+    fn div_vartime(&self, rhs: &NonZero<BoxedInt>) -> Self {
+        self.checked_div_vartime(rhs)
+            .expect("cannot represent positive equivalent of MIN as int")
+    }
+}
+
+impl Div<NonZero<BoxedInt>> for Wrapping<BoxedInt> {
+    type Output = Wrapping<BoxedInt>;
+
+    fn div(self, rhs: NonZero<BoxedInt>) -> Self::Output {
+        &self / &rhs
+    }
+}
+
+impl Div<&NonZero<BoxedInt>> for &Wrapping<BoxedInt> {
+    type Output = Wrapping<BoxedInt>;
+
+    fn div(self, rhs: &NonZero<BoxedInt>) -> Self::Output {
+        Wrapping((&self.0 / rhs).expect("cannot represent positive equivalent of MIN as int"))
+    }
+}
+
+impl DivAssign<&NonZero<BoxedInt>> for Wrapping<BoxedInt> {
+    fn div_assign(&mut self, rhs: &NonZero<BoxedInt>) {
+        *self = &*self / rhs;
+    }
+}
+
+impl DivAssign<NonZero<BoxedInt>> for Wrapping<BoxedInt> {
+    fn div_assign(&mut self, rhs: NonZero<BoxedInt>) {
+        *self /= &rhs;
+    }
+}
+
+impl Rem<&NonZero<BoxedInt>> for &BoxedInt {
+    type Output = BoxedInt;
+
+    fn rem(self, rhs: &NonZero<BoxedInt>) -> Self::Output {
+        BoxedInt::rem(self, rhs)
+    }
+}
+
+impl Rem<&NonZero<BoxedInt>> for BoxedInt {
+    type Output = BoxedInt;
+
+    fn rem(self, rhs: &NonZero<BoxedInt>) -> Self::Output {
+        BoxedInt::rem(&self, rhs)
+    }
+}
+
+impl Rem<NonZero<BoxedInt>> for &BoxedInt {
+    type Output = BoxedInt;
+
+    fn rem(self, rhs: NonZero<BoxedInt>) -> Self::Output {
+        BoxedInt::rem(self, &rhs)
+    }
+}
+
+impl Rem<NonZero<BoxedInt>> for BoxedInt {
+    type Output = BoxedInt;
+
+    fn rem(self, rhs: NonZero<BoxedInt>) -> Self::Output {
+        BoxedInt::rem(&self, &rhs)
+    }
+}
+
+impl RemAssign<&NonZero<BoxedInt>> for BoxedInt {
+    fn rem_assign(&mut self, rhs: &NonZero<BoxedInt>) {
+        *self = BoxedInt::rem(self, rhs)
+    }
+}
+
+impl RemAssign<NonZero<BoxedInt>> for BoxedInt {
+    fn rem_assign(&mut self, rhs: NonZero<BoxedInt>) {
+        *self %= &rhs
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{BoxedInt, DivVartime};
+
+    fn int(n: i64) -> BoxedInt {
+        BoxedInt::from(n)
+    }
+
+    #[test]
+    fn checked_div_rem() {
+        for (lhs, rhs, q, r) in [
+            (8, 3, 2, 2),
+            (-8, 3, -2, -2),
+            (8, -3, -2, 2),
+            (-8, -3, 2, -2),
+        ] {
+            let rhs = int(rhs).to_nz().unwrap();
+            let (quotient, remainder) = int(lhs).checked_div_rem(&rhs);
+            assert_eq!(quotient.unwrap(), int(q));
+            assert_eq!(remainder, int(r));
+
+            let (quotient, remainder) = int(lhs).checked_div_rem_vartime(&rhs);
+            assert_eq!(quotient.unwrap(), int(q));
+            assert_eq!(remainder, int(r));
+            assert_eq!(int(lhs).div_vartime(&rhs), int(q));
+        }
+    }
+
+    #[test]
+    fn checked_div_rem_floor() {
+        for (lhs, rhs, q, r) in [
+            (8, 3, 2, 2),
+            (-8, 3, -3, 1),
+            (8, -3, -3, -1),
+            (-8, -3, 2, -2),
+            (-9, 3, -3, 0),
+        ] {
+            let rhs = int(rhs).to_nz().unwrap();
+            let (quotient, remainder) = int(lhs).checked_div_rem_floor(&rhs);
+            assert_eq!(quotient.unwrap(), int(q));
+            assert_eq!(remainder, int(r));
+        }
+    }
+
+    #[test]
+    fn div_min_by_minus_one() {
+        let min = BoxedInt::min(128);
+        let minus_one = BoxedInt::minus_one_with_precision(128);
+        assert!(min.checked_div(&minus_one).is_none().to_bool());
+        assert!(min.checked_div(&BoxedInt::zero()).is_none().to_bool());
+        assert_eq!(min.checked_div(&min).unwrap(), BoxedInt::one());
+    }
+
+    #[test]
+    fn rem_mixed_precision() {
+        let lhs = BoxedInt::min(256);
+        let rhs = int(-7).to_nz().unwrap();
+        let rem = &lhs % &rhs;
+        assert_eq!(rem.bits_precision(), 64);
+        // 2^255 = 1 (mod 7), and the remainder takes the sign of the dividend
+        assert_eq!(rem, int(-1));
+    }
+}
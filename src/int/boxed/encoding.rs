@@ -0,0 +1,77 @@
+//! Decoding/encoding operations for [`BoxedInt`].
+
+use crate::{BoxedInt, BoxedUint, CtOption, DecodeError};
+use alloc::boxed::Box;
+
+impl BoxedInt {
+    /// Create a new [`BoxedInt`] from the provided big endian bytes, interpreted as the
+    /// two's complement representation of a value with the given precision.
+    ///
+    /// See [`BoxedUint::from_be_slice`] for more details.
+    pub fn from_be_slice(bytes: &[u8], bits_precision: u32) -> Result<Self, DecodeError> {
+        BoxedUint::from_be_slice(bytes, bits_precision).map(Self)
+    }
+
+    /// Create a new [`BoxedInt`] from the provided little endian bytes, interpreted as the
+    /// two's complement representation of a value with the given precision.
+    ///
+    /// See [`BoxedUint::from_le_slice`] for more details.
+    pub fn from_le_slice(bytes: &[u8], bits_precision: u32) -> Result<Self, DecodeError> {
+        BoxedUint::from_le_slice(bytes, bits_precision).map(Self)
+    }
+
+    /// Serialize this [`BoxedInt`] as big-endian two's complement, using the full precision.
+    pub fn to_be_bytes(&self) -> Box<[u8]> {
+        self.0.to_be_bytes()
+    }
+
+    /// Serialize this [`BoxedInt`] as little-endian two's complement, using the full precision.
+    pub fn to_le_bytes(&self) -> Box<[u8]> {
+        self.0.to_le_bytes()
+    }
+
+    /// Create a new [`BoxedInt`] from the provided big endian hex string, interpreted as the
+    /// two's complement representation of a value with the given precision.
+    ///
+    /// See [`BoxedUint::from_be_hex`] for more details.
+    pub fn from_be_hex(hex: &str, bits_precision: u32) -> CtOption<Self> {
+        BoxedUint::from_be_hex(hex, bits_precision).map(Self)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{BoxedInt, DecodeError};
+
+    #[test]
+    fn from_be_slice() {
+        let bytes = hex_literal::hex!("FFFFFFFFFFFFFFFE");
+        let n = BoxedInt::from_be_slice(&bytes, 64).unwrap();
+        assert_eq!(n, BoxedInt::from(-2i64));
+        assert_eq!(&*n.to_be_bytes(), &bytes);
+
+        assert_eq!(
+            BoxedInt::from_be_slice(&bytes, 32),
+            Err(DecodeError::InputSize)
+        );
+    }
+
+    #[test]
+    fn from_le_slice() {
+        let bytes = hex_literal::hex!("FEFFFFFFFFFFFFFF");
+        let n = BoxedInt::from_le_slice(&bytes, 64).unwrap();
+        assert_eq!(n, BoxedInt::from(-2i64));
+        assert_eq!(&*n.to_le_bytes(), &bytes);
+    }
+
+    #[test]
+    fn from_be_hex() {
+        let n = BoxedInt::from_be_hex("FFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFF", 128).unwrap();
+        assert_eq!(n, BoxedInt::minus_one_with_precision(128));
+        assert!(
+            BoxedInt::from_be_hex("GGFFFFFFFFFFFFFFFFFFFFFFFFFFFFFF", 128)
+                .is_none()
+                .to_bool()
+        );
+    }
+}
@@ -0,0 +1,137 @@
+//! `From`-like conversions for [`BoxedInt`].
+
+use crate::{BoxedInt, BoxedUint, CtEq, CtOption, I64, I128, Int, Limb, Resize, Uint};
+
+impl BoxedInt {
+    /// Convert this [`BoxedInt`] into an [`Int`] with the given number of limbs.
+    ///
+    /// Returns `None` if the value cannot be represented by an [`Int<LIMBS>`].
+    pub fn to_int<const LIMBS: usize>(&self) -> CtOption<Int<LIMBS>> {
+        let resized = self.resize_unchecked(Uint::<LIMBS>::BITS);
+        let roundtrip = (&resized).resize_unchecked(self.bits_precision());
+        let fits = roundtrip.0.ct_eq(&self.0);
+
+        let mut limbs = [Limb::ZERO; LIMBS];
+        limbs.copy_from_slice(resized.as_limbs());
+        CtOption::new(Int::from_bits(Uint::new(limbs)), fits)
+    }
+
+    /// Convert a [`BoxedUint`] into a [`BoxedInt`] of the same precision.
+    ///
+    /// Returns `None` if the value does not fit, i.e. if its most significant bit is set.
+    pub fn from_uint(uint: BoxedUint) -> CtOption<Self> {
+        let ret = Self(uint);
+        let is_negative = ret.is_negative();
+        CtOption::new(ret, !is_negative)
+    }
+}
+
+impl From<i8> for BoxedInt {
+    fn from(n: i8) -> Self {
+        I64::from_i8(n).into()
+    }
+}
+
+impl From<i16> for BoxedInt {
+    fn from(n: i16) -> Self {
+        I64::from_i16(n).into()
+    }
+}
+
+impl From<i32> for BoxedInt {
+    fn from(n: i32) -> Self {
+        I64::from_i32(n).into()
+    }
+}
+
+impl From<i64> for BoxedInt {
+    fn from(n: i64) -> Self {
+        I64::from_i64(n).into()
+    }
+}
+
+impl From<i128> for BoxedInt {
+    fn from(n: i128) -> Self {
+        I128::from_i128(n).into()
+    }
+}
+
+impl<const LIMBS: usize> From<Int<LIMBS>> for BoxedInt {
+    #[inline]
+    fn from(int: Int<LIMBS>) -> BoxedInt {
+        Self::from(&int)
+    }
+}
+
+impl<const LIMBS: usize> From<&Int<LIMBS>> for BoxedInt {
+    #[inline]
+    fn from(int: &Int<LIMBS>) -> BoxedInt {
+        Self(BoxedUint::from(int.as_uint()))
+    }
+}
+
+impl From<&BoxedUint> for BoxedInt {
+    /// Convert a [`BoxedUint`] into a [`BoxedInt`] one limb wider than the original value, so
+    /// that every value can be represented.
+    fn from(uint: &BoxedUint) -> BoxedInt {
+        Self(uint.resize_unchecked(uint.bits_precision() + Limb::BITS))
+    }
+}
+
+impl From<BoxedUint> for BoxedInt {
+    /// Convert a [`BoxedUint`] into a [`BoxedInt`] one limb wider than the original value, so
+    /// that every value can be represented.
+    fn from(uint: BoxedUint) -> BoxedInt {
+        Self::from(&uint)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{BoxedInt, BoxedUint, I128, Limb, Resize};
+
+    #[test]
+    fn from_i64() {
+        let n = BoxedInt::from(-42i64);
+        assert_eq!(n.bits_precision(), 64);
+        assert_eq!(n.abs(), BoxedUint::from(42u64));
+        assert!(n.is_negative().to_bool());
+    }
+
+    #[test]
+    fn from_i128() {
+        let n = BoxedInt::from(i128::MIN);
+        assert_eq!(n.bits_precision(), 128);
+        assert!(n.is_min().to_bool());
+    }
+
+    #[test]
+    fn from_int() {
+        let n = BoxedInt::from(I128::from(-5i64));
+        assert_eq!(n.to_int::<{ I128::LIMBS }>().unwrap(), I128::from(-5i64));
+        assert_eq!(n, BoxedInt::from(-5i32));
+    }
+
+    #[test]
+    fn to_int_checked() {
+        let min = BoxedInt::min(256);
+        assert!(min.to_int::<{ I128::LIMBS }>().is_none().to_bool());
+
+        let minus_one = BoxedInt::minus_one_with_precision(256);
+        assert_eq!(
+            minus_one.to_int::<{ I128::LIMBS }>().unwrap(),
+            I128::MINUS_ONE
+        );
+    }
+
+    #[test]
+    fn from_uint() {
+        let max = BoxedUint::max(128);
+        assert!(BoxedInt::from_uint(max.clone()).is_none().to_bool());
+
+        let n = BoxedInt::from(&max);
+        assert_eq!(n.bits_precision(), 128 + Limb::BITS);
+        assert!(n.is_positive().to_bool());
+        assert_eq!(n.abs().resize_unchecked(128), max);
+    }
+}
@@ -0,0 +1,237 @@
+//! [`BoxedInt`] multiplication operations.
+
+use crate::{
+    BoxedInt, BoxedUint, CheckedMul, Choice, ConcatenatingMul, CtOption, CtSelect, Limb, Mul,
+    MulAssign, Resize, Wrapping, WrappingMul,
+};
+
+impl BoxedInt {
+    /// Compute "wide" multiplication as a 2-tuple `(magnitude, negate)`.
+    ///
+    /// The magnitude of the product has a limb count equal to the sum of the input limb counts;
+    /// `negate` indicates whether the result should be negated when converted to a [`BoxedInt`].
+    ///
+    /// Note: even if `negate` is truthy, the magnitude might be zero!
+    pub fn widening_mul(&self, rhs: &Self) -> (BoxedUint, Choice) {
+        // Step 1: split operands into their signs and magnitudes.
+        let (lhs_abs, lhs_sgn) = self.abs_sign();
+        let (rhs_abs, rhs_sgn) = rhs.abs_sign();
+
+        // Step 2: multiply the magnitudes
+        let product = lhs_abs.mul(&rhs_abs);
+
+        // Step 3. Determine if the result should be negated.
+        // This should be done if and only if lhs and rhs have opposing signs.
+        (product, lhs_sgn.xor(rhs_sgn))
+    }
+
+    /// Multiply `self` by `rhs`, returning a "wide" result with a limb count equal to the sum of
+    /// the input limb counts.
+    pub fn concatenating_mul(&self, rhs: &Self) -> Self {
+        let (product_abs, product_sign) = self.widening_mul(rhs);
+
+        // always fits
+        let mut ret = Self(product_abs);
+        ret.0.conditional_wrapping_neg_assign(product_sign);
+        ret
+    }
+
+    /// Multiply `self` by `rhs`, returning a `CtOption` which is `is_some` only if
+    /// overflow did not occur.
+    ///
+    /// The result has the same precision as `self`.
+    pub fn checked_mul(&self, rhs: &Self) -> CtOption<Self> {
+        let (product_abs, product_sign) = self.widening_mul(rhs);
+        let (lo, hi) = product_abs.as_limbs().split_at(self.nlimbs());
+
+        let hi_is_zero = hi
+            .iter()
+            .fold(Limb::ZERO, |acc, limb| acc.bitor(*limb))
+            .is_zero();
+        let lo = BoxedUint::from(lo);
+
+        BoxedInt::new_from_abs_sign(lo, product_sign).filter_by(hi_is_zero)
+    }
+
+    /// Multiply `self` by `rhs`, saturating at the numeric bounds instead of overflowing.
+    ///
+    /// The result has the same precision as `self`.
+    pub fn saturating_mul(&self, rhs: &Self) -> Self {
+        let is_neg = self.is_negative().xor(rhs.is_negative());
+        let bits = self.bits_precision();
+        let bound = Self::max(bits).ct_select(&Self::min(bits), is_neg);
+        self.checked_mul(rhs).unwrap_or(bound)
+    }
+
+    /// Multiply `self` by `rhs`, wrapping the result in case of overflow.
+    ///
+    /// The result has the same precision as `self`.
+    pub fn wrapping_mul(&self, rhs: &Self) -> Self {
+        let rhs = rhs.resize_unchecked(self.bits_precision());
+        Self(self.0.wrapping_mul(&rhs.0))
+    }
+
+    /// Square self, returning a "wide" result with twice the limb count of `self`.
+    pub fn square(&self) -> BoxedUint {
+        self.abs().square()
+    }
+
+    /// Square self, checking that the result fits in the original precision.
+    pub fn checked_square(&self) -> CtOption<BoxedUint> {
+        self.abs().checked_square()
+    }
+
+    /// Perform wrapping square, discarding overflow.
+    pub fn wrapping_square(&self) -> BoxedUint {
+        self.abs().wrapping_square()
+    }
+}
+
+impl CheckedMul for BoxedInt {
+    #[inline]
+    fn checked_mul(&self, rhs: &BoxedInt) -> CtOption<Self> {
+        self.checked_mul(rhs)
+    }
+}
+
+impl WrappingMul for BoxedInt {
+    fn wrapping_mul(&self, v: &Self) -> Self {
+        self.wrapping_mul(v)
+    }
+}
+
+impl ConcatenatingMul for BoxedInt {
+    type Output = Self;
+
+    #[inline]
+    fn concatenating_mul(&self, rhs: BoxedInt) -> Self {
+        self.concatenating_mul(&rhs)
+    }
+}
+
+impl ConcatenatingMul<&BoxedInt> for BoxedInt {
+    type Output = Self;
+
+    #[inline]
+    fn concatenating_mul(&self, rhs: &BoxedInt) -> Self {
+        self.concatenating_mul(rhs)
+    }
+}
+
+impl Mul<BoxedInt> for BoxedInt {
+    type Output = BoxedInt;
+
+    fn mul(self, rhs: BoxedInt) -> Self {
+        Mul::mul(&self, &rhs)
+    }
+}
+
+impl Mul<&BoxedInt> for BoxedInt {
+    type Output = BoxedInt;
+
+    fn mul(self, rhs: &BoxedInt) -> Self {
+        Mul::mul(&self, rhs)
+    }
+}
+
+impl Mul<BoxedInt> for &BoxedInt {
+    type Output = BoxedInt;
+
+    fn mul(self, rhs: BoxedInt) -> Self::Output {
+        Mul::mul(self, &rhs)
+    }
+}
+
+impl Mul<&BoxedInt> for &BoxedInt {
+    type Output = BoxedInt;
+
+    fn mul(self, rhs: &BoxedInt) -> Self::Output {
+        self.checked_mul(rhs)
+            .expect("attempted to multiply with overflow")
+    }
+}
+
+impl MulAssign<BoxedInt> for BoxedInt {
+    fn mul_assign(&mut self, rhs: BoxedInt) {
+        self.mul_assign(&rhs)
+    }
+}
+
+impl MulAssign<&BoxedInt> for BoxedInt {
+    fn mul_assign(&mut self, rhs: &BoxedInt) {
+        *self = Mul::mul(&*self, rhs)
+    }
+}
+
+impl MulAssign<Wrapping<BoxedInt>> for Wrapping<BoxedInt> {
+    fn mul_assign(&mut self, other: Wrapping<BoxedInt>) {
+        *self = Wrapping(self.0.wrapping_mul(&other.0));
+    }
+}
+
+impl MulAssign<&Wrapping<BoxedInt>> for Wrapping<BoxedInt> {
+    fn mul_assign(&mut self, other: &Wrapping<BoxedInt>) {
+        *self = Wrapping(self.0.wrapping_mul(&other.0));
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{BoxedInt, BoxedUint};
+
+    #[test]
+    fn checked_mul() {
+        let min = BoxedInt::min(128);
+        let max = BoxedInt::max(128);
+        let minus_one = BoxedInt::minus_one_with_precision(128);
+        let one = BoxedInt::one_with_precision(128);
+        let two = BoxedInt::from(2i32);
+
+        assert_eq!(min.checked_mul(&one).unwrap(), min);
+        assert!(min.checked_mul(&minus_one).is_none().to_bool());
+        assert_eq!(max.checked_mul(&minus_one).unwrap(), min.wrapping_add(&one));
+        assert!(max.checked_mul(&two).is_none().to_bool());
+        assert_eq!(
+            minus_one.checked_mul(&two).unwrap(),
+            minus_one.wrapping_add(&minus_one)
+        );
+        assert_eq!(
+            BoxedInt::from(-3i64)
+                .checked_mul(&BoxedInt::from(7i128))
+                .unwrap(),
+            BoxedInt::from(-21i64)
+        );
+    }
+
+    #[test]
+    fn concatenating_mul() {
+        let min = BoxedInt::min(128);
+        let product = min.concatenating_mul(&min);
+        assert_eq!(product.bits_precision(), 256);
+        assert!(product.is_positive().to_bool());
+        assert_eq!(product.abs(), BoxedUint::one_with_precision(256).shl(254));
+    }
+
+    #[test]
+    fn saturating_mul() {
+        let max = BoxedInt::max(128);
+        let min = BoxedInt::min(128);
+        let two = BoxedInt::from(2i32);
+        let minus_two = BoxedInt::from(-2i32);
+
+        assert_eq!(max.saturating_mul(&two), max);
+        assert_eq!(max.saturating_mul(&minus_two), min);
+        assert_eq!(min.saturating_mul(&minus_two), max);
+    }
+
+    #[test]
+    fn wrapping_mul() {
+        let min = BoxedInt::min(128);
+        let minus_one = BoxedInt::from(-1i32);
+        assert_eq!(min.wrapping_mul(&minus_one), min);
+        assert_eq!(
+            BoxedInt::from(-5i128).wrapping_mul(&BoxedInt::from(3i32)),
+            BoxedInt::from(-15i128)
+        );
+    }
+}
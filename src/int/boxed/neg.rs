@@ -0,0 +1,89 @@
+//! [`BoxedInt`] negation-related operations.
+
+use crate::{BoxedInt, Choice, CtOption, WrappingNeg};
+
+impl BoxedInt {
+    /// Map this [`BoxedInt`] to its two's-complement negation.
+    ///
+    /// Returns the negation, as well as whether the operation overflowed.
+    /// The operation overflows when attempting to negate the minimum value; the positive
+    /// counterpart of this value cannot be represented.
+    pub fn overflowing_neg(&self) -> (Self, Choice) {
+        let res = self.wrapping_neg();
+        // Only zero and MIN keep their sign under negation; of those, only MIN is negative.
+        let overflow = self.is_negative() & res.is_negative();
+        (res, overflow)
+    }
+
+    /// Wrapping negate this [`BoxedInt`].
+    ///
+    /// Warning: this operation maps the minimum value to itself, since the positive counterpart
+    /// of this value cannot be represented.
+    pub fn wrapping_neg(&self) -> Self {
+        Self(self.0.wrapping_neg())
+    }
+
+    /// Wrapping negate this [`BoxedInt`] if `negate` is truthy; otherwise do nothing.
+    ///
+    /// Warning: this operation maps the minimum value to itself, since the positive counterpart
+    /// of this value cannot be represented.
+    pub fn wrapping_neg_if(&self, negate: Choice) -> Self {
+        let mut ret = self.0.clone();
+        ret.conditional_wrapping_neg_assign(negate);
+        Self(ret)
+    }
+
+    /// Negate this [`BoxedInt`].
+    ///
+    /// Yields `None` when `self` is the minimum value, since the positive counterpart of this
+    /// value cannot be represented.
+    pub fn checked_neg(&self) -> CtOption<Self> {
+        let (value, overflow) = self.overflowing_neg();
+        CtOption::new(value, !overflow)
+    }
+}
+
+impl WrappingNeg for BoxedInt {
+    #[inline]
+    fn wrapping_neg(&self) -> Self {
+        self.wrapping_neg()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::BoxedInt;
+
+    #[test]
+    fn overflowing_neg() {
+        let min = BoxedInt::min(128);
+        let minus_one = BoxedInt::minus_one_with_precision(128);
+        let one = BoxedInt::one_with_precision(128);
+        let zero = BoxedInt::zero_with_precision(128);
+
+        let (res, overflow) = min.overflowing_neg();
+        assert_eq!(res, min);
+        assert!(overflow.to_bool());
+
+        let (res, overflow) = minus_one.overflowing_neg();
+        assert_eq!(res, one);
+        assert!(!overflow.to_bool());
+
+        let (res, overflow) = zero.overflowing_neg();
+        assert_eq!(res, zero);
+        assert!(!overflow.to_bool());
+
+        let (res, overflow) = BoxedInt::max(128).overflowing_neg();
+        assert_eq!(res, min.wrapping_add(&one));
+        assert!(!overflow.to_bool());
+    }
+
+    #[test]
+    fn checked_neg() {
+        assert!(BoxedInt::min(128).checked_neg().is_none().to_bool());
+        assert_eq!(
+            BoxedInt::one_with_precision(128).checked_neg().unwrap(),
+            BoxedInt::minus_one_with_precision(128)
+        );
+    }
+}
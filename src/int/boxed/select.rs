@@ -0,0 +1,37 @@
+//! Constant-time selection support.
+
+use crate::{BoxedInt, Choice, CtSelect};
+
+impl CtSelect for BoxedInt {
+    #[inline]
+    fn ct_select(&self, other: &Self, choice: Choice) -> Self {
+        Self(self.0.ct_select(&other.0, choice))
+    }
+
+    #[inline]
+    fn ct_assign(&mut self, other: &Self, choice: Choice) {
+        self.0.ct_assign(&other.0, choice)
+    }
+
+    #[inline]
+    fn ct_swap(&mut self, other: &mut Self, choice: Choice) {
+        self.0.ct_swap(&mut other.0, choice)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{BoxedInt, Choice, CtSelect};
+
+    #[test]
+    fn ct_select() {
+        let a = BoxedInt::from_be_hex("00002222444466668888AAAACCCCEEEE", 128).unwrap();
+        let b = BoxedInt::from_be_hex("11113333555577779999BBBBDDDDFFFF", 128).unwrap();
+
+        let select_0 = BoxedInt::ct_select(&a, &b, Choice::FALSE);
+        assert_eq!(a, select_0);
+
+        let select_1 = BoxedInt::ct_select(&a, &b, Choice::TRUE);
+        assert_eq!(b, select_1);
+    }
+}
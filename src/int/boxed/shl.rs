@@ -0,0 +1,123 @@
+//! [`BoxedInt`] bitwise left shift operations.
+
+use crate::{BoxedInt, BoxedUint, CtOption, Shl, ShlAssign, ShlVartime, WrappingShl};
+
+impl BoxedInt {
+    /// Computes `self << shift`.
+    ///
+    /// Panics if `shift >= self.bits_precision()`.
+    pub fn shl(&self, shift: u32) -> Self {
+        Self(BoxedUint::shl(&self.0, shift))
+    }
+
+    /// Computes `self << shift` in variable time.
+    ///
+    /// Panics if `shift >= self.bits_precision()`.
+    pub fn shl_vartime(&self, shift: u32) -> Self {
+        Self(
+            self.0
+                .shl_vartime(shift)
+                .expect("attempt to shift left with overflow"),
+        )
+    }
+
+    /// Computes `self << shift`.
+    ///
+    /// Returns `None` if `shift >= self.bits_precision()`.
+    pub fn overflowing_shl(&self, shift: u32) -> CtOption<Self> {
+        let (result, overflow) = self.0.overflowing_shl(shift);
+        CtOption::new(Self(result), !overflow)
+    }
+
+    /// Computes `self << shift`.
+    ///
+    /// Returns `None` if `shift >= self.bits_precision()`.
+    ///
+    /// NOTE: this operation is variable time with respect to `shift` *ONLY*.
+    ///
+    /// When used with a fixed `shift`, this function is constant-time with respect
+    /// to `self`.
+    pub fn overflowing_shl_vartime(&self, shift: u32) -> CtOption<Self> {
+        let (result, overflow) = self.0.overflowing_shl_vartime(shift);
+        CtOption::new(Self(result), !overflow)
+    }
+
+    /// Computes `self << shift` in a panic-free manner, returning zero if the shift exceeds the
+    /// precision.
+    pub fn wrapping_shl(&self, shift: u32) -> Self {
+        Self(self.0.wrapping_shl(shift))
+    }
+
+    /// Computes `self << shift` in variable-time in a panic-free manner, returning zero if the
+    /// shift exceeds the precision.
+    pub fn wrapping_shl_vartime(&self, shift: u32) -> Self {
+        Self(self.0.wrapping_shl_vartime(shift))
+    }
+}
+
+macro_rules! impl_shl {
+    ($($shift:ty),+) => {
+        $(
+            impl Shl<$shift> for BoxedInt {
+                type Output = BoxedInt;
+
+                #[inline]
+                fn shl(self, shift: $shift) -> BoxedInt {
+                    <&Self>::shl(&self, shift)
+                }
+            }
+
+            impl Shl<$shift> for &BoxedInt {
+                type Output = BoxedInt;
+
+                #[inline]
+                fn shl(self, shift: $shift) -> BoxedInt {
+                    BoxedInt::shl(self, u32::try_from(shift).expect("invalid shift"))
+                }
+            }
+
+            impl ShlAssign<$shift> for BoxedInt {
+                fn shl_assign(&mut self, shift: $shift) {
+                    *self = BoxedInt::shl(self, u32::try_from(shift).expect("invalid shift"))
+                }
+            }
+        )+
+    };
+}
+
+impl_shl!(i32, u32, usize);
+
+impl WrappingShl for BoxedInt {
+    fn wrapping_shl(&self, shift: u32) -> BoxedInt {
+        self.wrapping_shl(shift)
+    }
+}
+
+impl ShlVartime for BoxedInt {
+    fn overflowing_shl_vartime(&self, shift: u32) -> CtOption<Self> {
+        self.overflowing_shl_vartime(shift)
+    }
+
+    fn wrapping_shl_vartime(&self, shift: u32) -> Self {
+        self.wrapping_shl_vartime(shift)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::BoxedInt;
+
+    #[test]
+    fn shl() {
+        let minus_one = BoxedInt::minus_one_with_precision(128);
+        let minus_two = minus_one.wrapping_add(&minus_one);
+
+        assert_eq!(&minus_one << 1, minus_two);
+        assert_eq!(minus_one.shl_vartime(127), BoxedInt::min(128));
+        assert!(minus_one.overflowing_shl(128).is_none().to_bool());
+        assert_eq!(
+            minus_one.wrapping_shl(128),
+            BoxedInt::zero_with_precision(128)
+        );
+    }
+}
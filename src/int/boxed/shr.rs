@@ -0,0 +1,170 @@
+//! [`BoxedInt`] bitwise right shift operations.
+
+use crate::{
+    BoxedInt, BoxedUint, Choice, CtOption, CtSelect, Shr, ShrAssign, ShrVartime, WrappingShr,
+};
+
+impl BoxedInt {
+    /// Computes `self >> shift`.
+    ///
+    /// Note, this is _signed_ shift right, i.e., the value shifted in on the left is equal to
+    /// the most significant bit.
+    ///
+    /// Panics if `shift >= self.bits_precision()`.
+    pub fn shr(&self, shift: u32) -> Self {
+        self.overflowing_shr(shift)
+            .expect("`shift` within the bit size of the integer")
+    }
+
+    /// Computes `self >> shift` in variable time.
+    ///
+    /// Note, this is _signed_ shift right, i.e., the value shifted in on the left is equal to
+    /// the most significant bit.
+    ///
+    /// Panics if `shift >= self.bits_precision()`.
+    pub fn shr_vartime(&self, shift: u32) -> Self {
+        self.overflowing_shr_vartime(shift)
+            .expect("`shift` within the bit size of the integer")
+    }
+
+    /// Computes `self >> shift`.
+    ///
+    /// Note, this is _signed_ shift right, i.e., the value shifted in on the left is equal to
+    /// the most significant bit.
+    ///
+    /// Returns `None` if `shift >= self.bits_precision()`.
+    pub fn overflowing_shr(&self, shift: u32) -> CtOption<Self> {
+        let (result, overflow) = self.sign_extending_shr(shift, BoxedUint::overflowing_shr);
+        CtOption::new(result, !overflow)
+    }
+
+    /// Computes `self >> shift`.
+    ///
+    /// NOTE: this is _signed_ shift right, i.e., the value shifted in on the left is equal to
+    /// the most significant bit.
+    ///
+    /// Returns `None` if `shift >= self.bits_precision()`.
+    ///
+    /// NOTE: this operation is variable time with respect to `shift` *ONLY*.
+    ///
+    /// When used with a fixed `shift`, this function is constant-time with respect
+    /// to `self`.
+    pub fn overflowing_shr_vartime(&self, shift: u32) -> CtOption<Self> {
+        let (result, overflow) = self.sign_extending_shr(shift, BoxedUint::overflowing_shr_vartime);
+        CtOption::new(result, !overflow)
+    }
+
+    /// Computes `self >> shift` in a panic-free manner.
+    ///
+    /// If the shift exceeds the precision, returns
+    /// - `0` when `self` is non-negative, and
+    /// - `-1` when `self` is negative.
+    pub fn wrapping_shr(&self, shift: u32) -> Self {
+        self.sign_extending_shr(shift, BoxedUint::overflowing_shr).0
+    }
+
+    /// Computes `self >> shift` in variable-time in a panic-free manner.
+    ///
+    /// If the shift exceeds the precision, returns
+    /// - `0` when `self` is non-negative, and
+    /// - `-1` when `self` is negative.
+    pub fn wrapping_shr_vartime(&self, shift: u32) -> Self {
+        self.sign_extending_shr(shift, BoxedUint::overflowing_shr_vartime)
+            .0
+    }
+
+    /// Perform a logical right shift using the provided `shr` function, and shift in copies of
+    /// the most significant bit on the left.
+    ///
+    /// In the event of overflow, the result is `-1` if `self` is negative and `0` otherwise.
+    fn sign_extending_shr(
+        &self,
+        shift: u32,
+        shr: fn(&BoxedUint, u32) -> (BoxedUint, Choice),
+    ) -> (Self, Choice) {
+        let (mut result, overflow) = shr(&self.0, shift);
+
+        // Build a mask with the top `shift` bits set, and apply it if `self` is negative.
+        let mask = shr(&BoxedUint::max(self.bits_precision()), shift).0.not();
+        let extended = result.bitor(&mask);
+        result.ct_assign(&extended, self.is_negative());
+
+        (Self(result), overflow)
+    }
+}
+
+macro_rules! impl_shr {
+    ($($shift:ty),+) => {
+        $(
+            impl Shr<$shift> for BoxedInt {
+                type Output = BoxedInt;
+
+                #[inline]
+                fn shr(self, shift: $shift) -> BoxedInt {
+                    <&Self>::shr(&self, shift)
+                }
+            }
+
+            impl Shr<$shift> for &BoxedInt {
+                type Output = BoxedInt;
+
+                #[inline]
+                fn shr(self, shift: $shift) -> BoxedInt {
+                    BoxedInt::shr(self, u32::try_from(shift).expect("invalid shift"))
+                }
+            }
+
+            impl ShrAssign<$shift> for BoxedInt {
+                fn shr_assign(&mut self, shift: $shift) {
+                    *self = BoxedInt::shr(self, u32::try_from(shift).expect("invalid shift"))
+                }
+            }
+        )+
+    };
+}
+
+impl_shr!(i32, u32, usize);
+
+impl WrappingShr for BoxedInt {
+    fn wrapping_shr(&self, shift: u32) -> BoxedInt {
+        self.wrapping_shr(shift)
+    }
+}
+
+impl ShrVartime for BoxedInt {
+    fn overflowing_shr_vartime(&self, shift: u32) -> CtOption<Self> {
+        self.overflowing_shr_vartime(shift)
+    }
+
+    fn wrapping_shr_vartime(&self, shift: u32) -> Self {
+        self.wrapping_shr_vartime(shift)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::BoxedInt;
+
+    #[test]
+    fn shr() {
+        let min = BoxedInt::min(128);
+        let minus_one = BoxedInt::minus_one_with_precision(128);
+        let max = BoxedInt::max(128);
+
+        assert_eq!(&min >> 127, minus_one);
+        assert_eq!(min.shr_vartime(127), minus_one);
+        assert_eq!(&max >> 126, BoxedInt::one_with_precision(128));
+        assert_eq!((&min >> 1u32).wrapping_add(&(&min >> 1u32)), min);
+    }
+
+    #[test]
+    fn wrapping_shr() {
+        let minus_one = BoxedInt::minus_one_with_precision(128);
+        let max = BoxedInt::max(128);
+
+        assert!(minus_one.overflowing_shr(128).is_none().to_bool());
+        assert_eq!(minus_one.wrapping_shr(128), minus_one);
+        assert_eq!(minus_one.wrapping_shr_vartime(200), minus_one);
+        assert_eq!(max.wrapping_shr(128), BoxedInt::zero_with_precision(128));
+    }
+}
@@ -0,0 +1,101 @@
+//! [`BoxedInt`] sign-related operations.
+
+use crate::{BoxedInt, BoxedUint, Choice, CtOption, word};
+
+impl BoxedInt {
+    /// Construct new [`BoxedInt`] from an absolute value and sign.
+    ///
+    /// The result has the same precision as `abs`.
+    ///
+    /// Returns `None` when the result exceeds the bounds of a [`BoxedInt`] with that precision.
+    pub fn new_from_abs_sign(abs: BoxedUint, is_negative: Choice) -> CtOption<Self> {
+        let abs_int = BoxedInt(abs);
+        let abs_msb = abs_int.is_negative();
+        let signed = abs_int.wrapping_neg_if(is_negative);
+
+        // abs is an acceptable input if the high bit is unset, covering 0..=MAX,
+        // or if it is equal to MIN (bit sequence '1000...0000') and the sign is negative.
+        let fits = !abs_msb | (is_negative & signed.is_negative());
+        CtOption::new(signed, fits)
+    }
+
+    /// Whether this [`BoxedInt`] is negative, as a `Choice`.
+    #[inline]
+    pub fn is_negative(&self) -> Choice {
+        word::choice_from_msb(self.0.limbs.last().map(|limb| limb.0).unwrap_or(0))
+    }
+
+    /// Whether this [`BoxedInt`] is positive, as a `Choice`.
+    pub fn is_positive(&self) -> Choice {
+        !self.is_negative() & self.is_nonzero()
+    }
+
+    /// The sign and magnitude of this [`BoxedInt`].
+    pub fn abs_sign(&self) -> (BoxedUint, Choice) {
+        let sign = self.is_negative();
+        // Note: this negate_if is safe to use, since we are negating based on self.is_negative()
+        let abs = self.wrapping_neg_if(sign);
+        (abs.0, sign)
+    }
+
+    /// The magnitude of this [`BoxedInt`].
+    pub fn abs(&self) -> BoxedUint {
+        self.abs_sign().0
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{BoxedInt, BoxedUint, Choice};
+
+    #[test]
+    fn new_from_abs_sign() {
+        let zero = BoxedUint::zero_with_precision(128);
+        assert!(
+            BoxedInt::new_from_abs_sign(zero.clone(), Choice::FALSE)
+                .is_some()
+                .to_bool()
+        );
+        assert!(
+            BoxedInt::new_from_abs_sign(zero, Choice::TRUE)
+                .is_some()
+                .to_bool()
+        );
+
+        let min_abs = BoxedInt::min(128).abs();
+        assert!(
+            BoxedInt::new_from_abs_sign(min_abs.clone(), Choice::FALSE)
+                .is_none()
+                .to_bool()
+        );
+        assert_eq!(
+            BoxedInt::new_from_abs_sign(min_abs, Choice::TRUE).unwrap(),
+            BoxedInt::min(128)
+        );
+        assert!(
+            BoxedInt::new_from_abs_sign(BoxedUint::max(128), Choice::TRUE)
+                .is_none()
+                .to_bool()
+        );
+    }
+
+    #[test]
+    fn is_negative() {
+        assert!(BoxedInt::min(128).is_negative().to_bool());
+        assert!(
+            BoxedInt::minus_one_with_precision(128)
+                .is_negative()
+                .to_bool()
+        );
+        assert!(!BoxedInt::zero().is_negative().to_bool());
+        assert!(!BoxedInt::max(128).is_negative().to_bool());
+    }
+
+    #[test]
+    fn is_positive() {
+        assert!(!BoxedInt::min(128).is_positive().to_bool());
+        assert!(!BoxedInt::zero().is_positive().to_bool());
+        assert!(BoxedInt::one().is_positive().to_bool());
+        assert!(BoxedInt::max(128).is_positive().to_bool());
+    }
+}
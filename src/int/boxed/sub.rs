@@ -0,0 +1,143 @@
+//! [`BoxedInt`] subtraction operations.
+
+use crate::{BoxedInt, CheckedSub, Choice, CtOption, Limb, Sub, SubAssign, Wrapping, WrappingSub};
+
+impl BoxedInt {
+    /// Perform checked subtraction. Returns `none` when the subtraction underflowed.
+    ///
+    /// The result has the precision of the wider of the two operands.
+    pub fn checked_sub(&self, rhs: &Self) -> CtOption<Self> {
+        let (value, underflow) = self.underflowing_sub(rhs);
+        CtOption::new(value, !underflow)
+    }
+
+    /// Perform subtraction, returning the result along with a [`Choice`] which `is_true`
+    /// only if the operation underflowed.
+    ///
+    /// The result has the precision of the wider of the two operands.
+    pub fn underflowing_sub(&self, rhs: &Self) -> (Self, Choice) {
+        // Step 1. subtract operands
+        let res = self.wrapping_sub(rhs);
+
+        // Step 2. check whether underflow happened.
+        // Note:
+        // - underflow can only happen when the inputs have opposing signs, and
+        // - underflow occurs if and only if the result and the lhs have opposing signs.
+        //
+        // We can thus express the overflow flag as: (self.msb != rhs.msb) & (self.msb != res.msb)
+        let self_msb = self.is_negative();
+        let underflow = self_msb.ne(rhs.is_negative()) & self_msb.ne(res.is_negative());
+
+        // Step 3. Construct result
+        (res, underflow)
+    }
+
+    /// Perform wrapping subtraction, discarding underflow and wrapping around the boundary of the
+    /// type.
+    ///
+    /// The result has the precision of the wider of the two operands.
+    pub fn wrapping_sub(&self, rhs: &Self) -> Self {
+        Self::fold_limbs(self, rhs, Limb::ZERO, |a, b, c| a.borrowing_sub(b, c)).0
+    }
+}
+
+impl CheckedSub for BoxedInt {
+    fn checked_sub(&self, rhs: &Self) -> CtOption<Self> {
+        self.checked_sub(rhs)
+    }
+}
+
+impl Sub for BoxedInt {
+    type Output = Self;
+
+    fn sub(self, rhs: Self) -> Self {
+        self.sub(&rhs)
+    }
+}
+
+impl Sub<&BoxedInt> for BoxedInt {
+    type Output = Self;
+
+    fn sub(self, rhs: &Self) -> Self {
+        Sub::sub(&self, rhs)
+    }
+}
+
+impl Sub<BoxedInt> for &BoxedInt {
+    type Output = BoxedInt;
+
+    fn sub(self, rhs: BoxedInt) -> BoxedInt {
+        Sub::sub(self, &rhs)
+    }
+}
+
+impl Sub<&BoxedInt> for &BoxedInt {
+    type Output = BoxedInt;
+
+    fn sub(self, rhs: &BoxedInt) -> BoxedInt {
+        self.checked_sub(rhs)
+            .expect("attempted to subtract with underflow")
+    }
+}
+
+impl SubAssign for BoxedInt {
+    fn sub_assign(&mut self, rhs: Self) {
+        *self -= &rhs;
+    }
+}
+
+impl SubAssign<&BoxedInt> for BoxedInt {
+    fn sub_assign(&mut self, rhs: &Self) {
+        *self = &*self - rhs;
+    }
+}
+
+impl SubAssign for Wrapping<BoxedInt> {
+    fn sub_assign(&mut self, other: Self) {
+        *self -= &other;
+    }
+}
+
+impl SubAssign<&Wrapping<BoxedInt>> for Wrapping<BoxedInt> {
+    fn sub_assign(&mut self, other: &Self) {
+        self.0 = self.0.wrapping_sub(&other.0);
+    }
+}
+
+impl WrappingSub for BoxedInt {
+    fn wrapping_sub(&self, v: &Self) -> Self {
+        self.wrapping_sub(v)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{BoxedInt, Resize};
+
+    #[test]
+    fn checked_sub() {
+        let min = BoxedInt::min(128);
+        let max = BoxedInt::max(128);
+        let minus_one = BoxedInt::minus_one_with_precision(128);
+        let one = BoxedInt::one_with_precision(128);
+        let zero = BoxedInt::zero_with_precision(128);
+
+        assert!(min.checked_sub(&one).is_none().to_bool());
+        assert!(min.checked_sub(&max).is_none().to_bool());
+        assert_eq!(min.checked_sub(&minus_one).unwrap(), min.wrapping_add(&one));
+        assert_eq!(zero.checked_sub(&max).unwrap(), min.wrapping_add(&one));
+        assert!(zero.checked_sub(&min).is_none().to_bool());
+        assert!(max.checked_sub(&minus_one).is_none().to_bool());
+        assert_eq!(max.checked_sub(&max).unwrap(), zero);
+    }
+
+    #[test]
+    fn sub_mixed_precision() {
+        let one = BoxedInt::one_with_precision(64);
+        let min = (&BoxedInt::min(64)).resize(128);
+        let res = &min - &one;
+        assert_eq!(res.bits_precision(), 128);
+        assert!(res.is_negative().to_bool());
+        assert_eq!(res.checked_add(&one).unwrap(), min);
+    }
+}
@@ -1,6 +1,6 @@
 //! [`Int`] division operations.
 
-use crate::{CheckedDiv, Choice, CtOption, DivVartime, Int, NonZero, Uint, Wrapping};
+use crate::{CheckedDiv, CheckedRem, Choice, CtOption, DivVartime, Int, NonZero, Uint, Wrapping};
 use core::ops::{Div, DivAssign, Rem, RemAssign};
 
 /// Checked division operations.
@@ -77,6 +77,16 @@ impl<const LIMBS: usize> Int<LIMBS> {
     ) -> Int<RHS_LIMBS> {
         self.checked_div_rem(rhs).1
     }
+
+    /// Perform checked reduction, returning a [`CtOption`] which `is_some` only if the `rhs != 0`.
+    ///
+    /// The remainder has the same sign as `self`.
+    pub fn checked_rem<const RHS_LIMBS: usize>(
+        &self,
+        rhs: &Int<RHS_LIMBS>,
+    ) -> CtOption<Int<RHS_LIMBS>> {
+        NonZero::new(*rhs).map(|rhs| self.rem(&rhs))
+    }
 }
 
 /// Vartime checked division operations.
@@ -298,6 +308,12 @@ impl<const LIMBS: usize, const RHS_LIMBS: usize> CheckedDiv<Int<RHS_LIMBS>> for
     }
 }
 
+impl<const LIMBS: usize> CheckedRem for Int<LIMBS> {
+    fn checked_rem(&self, rhs: &Int<LIMBS>) -> CtOption<Self> {
+        self.checked_rem(rhs)
+    }
+}
+
 impl<const LIMBS: usize, const RHS_LIMBS: usize> Div<&NonZero<Int<RHS_LIMBS>>> for &Int<LIMBS> {
     type Output = CtOption<Int<LIMBS>>;
 
@@ -638,4 +654,21 @@ mod tests {
         assert_eq!(myfn(I128::MAX, I128::from(1)), I128::MAX);
         assert_eq!(myfn(I128::MAX, I128::MAX), I128::from(1));
     }
+
+    #[test]
+    fn test_checked_rem() {
+        let seven = I128::from(7i32);
+        assert_eq!(
+            I128::from(-20i32).checked_rem(&seven).unwrap(),
+            I128::from(-6i32)
+        );
+        assert_eq!(
+            I128::from(20i32)
+                .checked_rem(&seven.wrapping_neg())
+                .unwrap(),
+            I128::from(6i32)
+        );
+        assert_eq!(I128::MIN.checked_rem(&I128::MINUS_ONE).unwrap(), I128::ZERO);
+        assert!(I128::ONE.checked_rem(&I128::ZERO).is_none().to_bool());
+    }
 }
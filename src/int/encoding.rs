@@ -1,5 +1,8 @@
 //! Const-friendly decoding/encoding operations for [`Int`].
 
+#[cfg(all(feature = "der", feature = "hybrid-array"))]
+mod der;
+
 use crate::{Int, Uint};
 
 impl<const LIMBS: usize> Int<LIMBS> {
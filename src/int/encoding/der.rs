@@ -0,0 +1,209 @@
+//! Support for decoding/encoding [`Int`] as an ASN.1 DER `INTEGER`.
+
+use crate::{ArrayEncoding, Int, Uint, hybrid_array::Array};
+use ::der::{DecodeValue, EncodeValue, FixedTag, Length, Tag, asn1::AnyRef};
+
+impl<'a, const LIMBS: usize> TryFrom<AnyRef<'a>> for Int<LIMBS>
+where
+    Uint<LIMBS>: ArrayEncoding,
+{
+    type Error = der::Error;
+
+    fn try_from(any: AnyRef<'a>) -> der::Result<Int<LIMBS>> {
+        any.decode_as()
+    }
+}
+
+impl<'a, const LIMBS: usize> DecodeValue<'a> for Int<LIMBS>
+where
+    Uint<LIMBS>: ArrayEncoding,
+{
+    type Error = der::Error;
+
+    fn decode_value<R: der::Reader<'a>>(reader: &mut R, header: der::Header) -> der::Result<Self> {
+        let len = usize::try_from(header.length())?;
+        let mut array: Array<u8, <Uint<LIMBS> as ArrayEncoding>::ByteSize> = Array::default();
+
+        // A minimal encoding of a value in range never takes more bytes than the full precision
+        let offset = array
+            .len()
+            .checked_sub(len)
+            .ok_or_else(|| reader.error(Self::TAG.length_error()))?;
+
+        let bytes = reader.read_into(&mut array[offset..])?;
+        validate_canonical(bytes)?;
+
+        // Sign-extend the value to the full precision
+        if is_negative(bytes) {
+            array[..offset].fill(0xFF);
+        }
+
+        Ok(Self(Uint::from_be_byte_array(array)))
+    }
+}
+
+impl<const LIMBS: usize> EncodeValue for Int<LIMBS>
+where
+    Uint<LIMBS>: ArrayEncoding,
+{
+    fn value_len(&self) -> der::Result<Length> {
+        let array = self.0.to_be_byte_array();
+        strip_sign_extension(&array).len().try_into()
+    }
+
+    fn encode_value(&self, encoder: &mut impl der::Writer) -> der::Result<()> {
+        let array = self.0.to_be_byte_array();
+        encoder.write(strip_sign_extension(&array))
+    }
+}
+
+impl<const LIMBS: usize> FixedTag for Int<LIMBS>
+where
+    Uint<LIMBS>: ArrayEncoding,
+{
+    const TAG: Tag = Tag::Integer;
+}
+
+/// Is the big-endian two's complement integer in `bytes` negative?
+fn is_negative(bytes: &[u8]) -> bool {
+    matches!(bytes.first(), Some(byte) if *byte >= 0x80)
+}
+
+/// Checks that the big-endian two's complement integer in `bytes` is minimally encoded, as
+/// required by DER.
+///
+/// The encoding must be non-empty, and must not start with a redundant `0x00` byte for a
+/// non-negative value, nor with a redundant `0xFF` byte for a negative one.
+fn validate_canonical(bytes: &[u8]) -> der::Result<()> {
+    match bytes {
+        [] => Err(Tag::Integer.length_error().into()),
+        [0x00, byte, ..] if *byte < 0x80 => Err(Tag::Integer.non_canonical_error().into()),
+        [0xFF, byte, ..] if *byte >= 0x80 => Err(Tag::Integer.non_canonical_error().into()),
+        _ => Ok(()),
+    }
+}
+
+/// Strips the redundant leading sign extension bytes from the big-endian two's complement integer
+/// in `bytes`, so that it is minimally encoded.
+fn strip_sign_extension(mut bytes: &[u8]) -> &[u8] {
+    while let [first, second, ..] = bytes {
+        let is_redundant =
+            (*first == 0x00 && *second < 0x80) || (*first == 0xFF && *second >= 0x80);
+        if !is_redundant {
+            break;
+        }
+        bytes = &bytes[1..];
+    }
+    bytes
+}
+
+#[cfg(feature = "alloc")]
+mod allocating {
+    use super::{is_negative, strip_sign_extension, validate_canonical};
+    use crate::{BoxedInt, Limb};
+    use alloc::vec;
+    use der::{DecodeValue, EncodeValue, FixedTag, Length, Reader, Tag};
+
+    impl EncodeValue for BoxedInt {
+        fn value_len(&self) -> der::Result<Length> {
+            strip_sign_extension(&self.to_be_bytes()).len().try_into()
+        }
+
+        fn encode_value(&self, encoder: &mut impl der::Writer) -> der::Result<()> {
+            encoder.write(strip_sign_extension(&self.to_be_bytes()))
+        }
+    }
+
+    impl<'a> DecodeValue<'a> for BoxedInt {
+        type Error = der::Error;
+
+        fn decode_value<R: Reader<'a>>(reader: &mut R, header: der::Header) -> der::Result<Self> {
+            let mut bytes = vec![0u8; usize::try_from(header.length())?];
+            reader.read_into(&mut bytes)?;
+            validate_canonical(&bytes)?;
+
+            // Sign-extend the value to a whole number of limbs
+            let padded_len = bytes.len().div_ceil(Limb::BYTES) * Limb::BYTES;
+            let fill = if is_negative(&bytes) { 0xFF } else { 0x00 };
+            let mut padded = vec![fill; padded_len];
+            padded[padded_len - bytes.len()..].copy_from_slice(&bytes);
+
+            BoxedInt::from_be_slice(&padded, padded_len as u32 * 8)
+                .map_err(|_| Self::TAG.value_error().into())
+        }
+    }
+
+    impl FixedTag for BoxedInt {
+        const TAG: Tag = Tag::Integer;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{I64, I128, Int, Uint};
+    use der::{Decode, Encode, ErrorKind, Tag};
+
+    /// Encodes `n` and checks the result against the expected DER `INTEGER` value bytes.
+    fn assert_encoding<const LIMBS: usize>(n: Int<LIMBS>, expected: &[u8])
+    where
+        Uint<LIMBS>: crate::ArrayEncoding,
+    {
+        let mut buf = [0u8; 64];
+        let der = n.encode_to_slice(&mut buf).unwrap();
+        assert_eq!(&der[..2], &[0x02, expected.len() as u8]);
+        assert_eq!(&der[2..], expected);
+        assert_eq!(Int::<LIMBS>::from_der(der).unwrap(), n);
+
+        #[cfg(feature = "alloc")]
+        {
+            let boxed = crate::BoxedInt::from(n);
+            let mut boxed_buf = [0u8; 64];
+            assert_eq!(boxed.encode_to_slice(&mut boxed_buf).unwrap(), der);
+            assert_eq!(crate::BoxedInt::from_der(der).unwrap(), boxed);
+        }
+    }
+
+    #[test]
+    fn encode_decode() {
+        assert_encoding(I64::ZERO, &[0x00]);
+        assert_encoding(I64::ONE, &[0x01]);
+        assert_encoding(I64::MINUS_ONE, &[0xFF]);
+        assert_encoding(I64::from_i64(127), &[0x7F]);
+        assert_encoding(I64::from_i64(128), &[0x00, 0x80]);
+        assert_encoding(I64::from_i64(-128), &[0x80]);
+        assert_encoding(I64::from_i64(-129), &[0xFF, 0x7F]);
+        assert_encoding(I64::from_i64(256), &[0x01, 0x00]);
+        assert_encoding(I64::from_i64(-32768), &[0x80, 0x00]);
+        assert_encoding(I64::MAX, &[0x7F, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF]);
+        assert_encoding(I64::MIN, &[0x80, 0, 0, 0, 0, 0, 0, 0]);
+        assert_encoding(I128::from_i128(-(1 << 64)), &[0xFF, 0, 0, 0, 0, 0, 0, 0, 0]);
+    }
+
+    #[test]
+    fn reject_non_canonical() {
+        for der in [
+            &[0x02, 0x02, 0x00, 0x7F][..],
+            &[0x02, 0x02, 0xFF, 0x80],
+            &[0x02, 0x02, 0x00, 0x00],
+            &[0x02, 0x02, 0xFF, 0xFF],
+        ] {
+            let err = I128::from_der(der).unwrap_err();
+            assert_eq!(err.kind(), ErrorKind::Noncanonical { tag: Tag::Integer });
+
+            #[cfg(feature = "alloc")]
+            assert!(crate::BoxedInt::from_der(der).is_err());
+        }
+
+        // Empty value
+        assert!(I128::from_der(&[0x02, 0x00]).is_err());
+        #[cfg(feature = "alloc")]
+        assert!(crate::BoxedInt::from_der(&[0x02, 0x00]).is_err());
+    }
+
+    #[test]
+    fn reject_out_of_range() {
+        // 2^63 and -2^63 - 1 don't fit in 64 bits
+        assert!(I64::from_der(&[0x02, 0x09, 0x00, 0x80, 0, 0, 0, 0, 0, 0, 0]).is_err());
+        assert!(I64::from_der(&[0x02, 0x09, 0xFF, 0x7F, 0, 0, 0, 0, 0, 0, 0]).is_err());
+    }
+}
@@ -0,0 +1,190 @@
+//! [`Int`] integer logarithm operations.
+
+use crate::{Choice, CtOption, Int};
+
+impl<const LIMBS: usize> Int<LIMBS> {
+    /// Computes `floor(log2(self))` in constant time.
+    ///
+    /// # Panics
+    /// - if `self` is not positive.
+    pub const fn ilog2(&self) -> u32 {
+        self.checked_ilog2()
+            .expect_copied("logarithm of a non-positive number is undefined")
+    }
+
+    /// Computes `floor(log2(self))`.
+    ///
+    /// This method is variable-time with respect to `self`.
+    ///
+    /// # Panics
+    /// - if `self` is not positive.
+    pub const fn ilog2_vartime(&self) -> u32 {
+        self.checked_ilog2_vartime()
+            .expect_copied("logarithm of a non-positive number is undefined")
+    }
+
+    /// Computes `floor(log2(self))` in constant time, returning a [`CtOption`] which `is_some`
+    /// only if `self` is positive.
+    pub const fn checked_ilog2(&self) -> CtOption<u32> {
+        let log = self.0.checked_ilog2();
+        CtOption::new(*log.as_inner_unchecked(), self.is_positive())
+    }
+
+    /// Computes `floor(log2(self))`, returning a [`CtOption`] which `is_some`
+    /// only if `self` is positive.
+    ///
+    /// This method is variable-time with respect to `self`.
+    pub const fn checked_ilog2_vartime(&self) -> CtOption<u32> {
+        let log = self.0.checked_ilog2_vartime();
+        CtOption::new(*log.as_inner_unchecked(), self.is_positive())
+    }
+
+    /// Computes `floor(log10(self))` in constant time.
+    ///
+    /// # Panics
+    /// - if `self` is not positive.
+    pub const fn ilog10(&self) -> u32 {
+        self.checked_ilog10()
+            .expect_copied("logarithm of a non-positive number is undefined")
+    }
+
+    /// Computes `floor(log10(self))`.
+    ///
+    /// This method is variable-time with respect to `self`.
+    ///
+    /// # Panics
+    /// - if `self` is not positive.
+    pub const fn ilog10_vartime(&self) -> u32 {
+        self.checked_ilog10_vartime()
+            .expect_copied("logarithm of a non-positive number is undefined")
+    }
+
+    /// Computes `floor(log10(self))` in constant time, returning a [`CtOption`] which `is_some`
+    /// only if `self` is positive.
+    pub const fn checked_ilog10(&self) -> CtOption<u32> {
+        let log = self.0.checked_ilog10();
+        CtOption::new(*log.as_inner_unchecked(), self.is_positive())
+    }
+
+    /// Computes `floor(log10(self))`, returning a [`CtOption`] which `is_some`
+    /// only if `self` is positive.
+    ///
+    /// This method is variable-time with respect to `self`.
+    pub const fn checked_ilog10_vartime(&self) -> CtOption<u32> {
+        let log = self.0.checked_ilog10_vartime();
+        CtOption::new(*log.as_inner_unchecked(), self.is_positive())
+    }
+
+    /// Computes `floor(log_base(self))`.
+    ///
+    /// This method is constant-time with respect to `self`, but not `base`.
+    ///
+    /// # Panics
+    /// - if `self` is not positive or `base` is less than 2.
+    pub const fn ilog(&self, base: &Self) -> u32 {
+        self.checked_ilog(base).expect_copied(
+            "logarithm of a non-positive number or to a base less than 2 is undefined",
+        )
+    }
+
+    /// Computes `floor(log_base(self))`.
+    ///
+    /// This method is variable-time with respect to `self` and `base`.
+    ///
+    /// # Panics
+    /// - if `self` is not positive or `base` is less than 2.
+    pub const fn ilog_vartime(&self, base: &Self) -> u32 {
+        self.checked_ilog_vartime(base).expect_copied(
+            "logarithm of a non-positive number or to a base less than 2 is undefined",
+        )
+    }
+
+    /// Computes `floor(log_base(self))`, returning a [`CtOption`] which `is_some`
+    /// only if `self` is positive and `base` is at least 2.
+    ///
+    /// This method is constant-time with respect to `self`, but not `base`.
+    pub const fn checked_ilog(&self, base: &Self) -> CtOption<u32> {
+        // A negative `base` would be interpreted as a large unsigned one, so don't bother
+        let base_is_negative = base.is_negative().to_bool_vartime();
+        let base = if base_is_negative { Self::ZERO } else { *base };
+
+        let log = self.0.checked_ilog(&base.0);
+        let is_some = log.is_some().and(self.is_positive());
+        CtOption::new(*log.as_inner_unchecked(), is_some)
+    }
+
+    /// Computes `floor(log_base(self))`, returning a [`CtOption`] which `is_some`
+    /// only if `self` is positive and `base` is at least 2.
+    ///
+    /// This method is variable-time with respect to `self` and `base`.
+    pub const fn checked_ilog_vartime(&self, base: &Self) -> CtOption<u32> {
+        if self
+            .is_positive()
+            .and(base.is_positive())
+            .not()
+            .to_bool_vartime()
+        {
+            return CtOption::new(0, Choice::FALSE);
+        }
+
+        self.0.checked_ilog_vartime(&base.0)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{I64, I128};
+
+    #[test]
+    fn ilog2() {
+        for n in [1i64, 2, 3, 1000, i64::MAX] {
+            let expected = n.ilog2();
+            assert_eq!(I64::from_i64(n).ilog2(), expected);
+            assert_eq!(I64::from_i64(n).ilog2_vartime(), expected);
+        }
+
+        for n in [I64::ZERO, I64::MINUS_ONE, I64::MIN] {
+            assert!(n.checked_ilog2().is_none().to_bool());
+            assert!(n.checked_ilog2_vartime().is_none().to_bool());
+        }
+    }
+
+    #[test]
+    #[should_panic]
+    fn ilog2_negative() {
+        I64::MINUS_ONE.ilog2();
+    }
+
+    #[test]
+    fn ilog10() {
+        for n in [1i128, 9, 10, 99, 100, i64::MAX as i128, i128::MAX] {
+            let expected = n.ilog10();
+            assert_eq!(I128::from_i128(n).ilog10(), expected);
+            assert_eq!(I128::from_i128(n).ilog10_vartime(), expected);
+        }
+
+        for n in [I128::ZERO, I128::from_i128(-10), I128::MIN] {
+            assert!(n.checked_ilog10().is_none().to_bool());
+            assert!(n.checked_ilog10_vartime().is_none().to_bool());
+        }
+    }
+
+    #[test]
+    fn ilog() {
+        for base in [2i64, 3, 7, 16, 1 << 32, i64::MAX] {
+            for n in [1i64, 2, 1000, i64::MAX] {
+                let expected = n.ilog(base);
+                let (n, base) = (I64::from_i64(n), I64::from_i64(base));
+                assert_eq!(n.ilog(&base), expected);
+                assert_eq!(n.ilog_vartime(&base), expected);
+            }
+        }
+
+        let n = I64::from_i64(1000);
+        for base in [0i64, 1, -1, -2, i64::MIN] {
+            let base = I64::from_i64(base);
+            assert!(n.checked_ilog(&base).is_none().to_bool());
+            assert!(n.checked_ilog_vartime(&base).is_none().to_bool());
+        }
+    }
+}
@@ -1,7 +1,8 @@
 //! [`Int`] multiplication operations.
 
 use crate::{
-    Checked, CheckedMul, Choice, ConcatMixed, CtOption, Int, Mul, MulAssign, Uint, WrappingMul,
+    Checked, CheckedMul, Choice, ConcatMixed, CtOption, Int, Mul, MulAssign, Saturating,
+    SaturatingMul, Uint, WrappingMul,
 };
 
 impl<const LIMBS: usize> Int<LIMBS> {
@@ -196,6 +197,24 @@ impl<const LIMBS: usize> MulAssign<&Checked<Int<LIMBS>>> for Checked<Int<LIMBS>>
     }
 }
 
+impl<const LIMBS: usize> MulAssign<Saturating<Int<LIMBS>>> for Saturating<Int<LIMBS>> {
+    fn mul_assign(&mut self, other: Saturating<Int<LIMBS>>) {
+        *self = *self * other;
+    }
+}
+
+impl<const LIMBS: usize> MulAssign<&Saturating<Int<LIMBS>>> for Saturating<Int<LIMBS>> {
+    fn mul_assign(&mut self, other: &Saturating<Int<LIMBS>>) {
+        *self = *self * other;
+    }
+}
+
+impl<const LIMBS: usize> SaturatingMul for Int<LIMBS> {
+    fn saturating_mul(&self, rhs: &Self) -> Self {
+        self.saturating_mul(rhs)
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use crate::{I64, I128, I256, Int, U64, U128, U256};
@@ -1,6 +1,6 @@
 //! [`Int`] negation-related operations.
 
-use crate::{Choice, CtOption, Int, Uint, WrappingNeg};
+use crate::{CheckedNeg, Choice, CtOption, Int, SaturatingNeg, Uint, WrappingNeg};
 
 impl<const LIMBS: usize> Int<LIMBS> {
     /// Map this [`Int`] to its two's-complement negation:
@@ -37,6 +37,29 @@ impl<const LIMBS: usize> Int<LIMBS> {
         let (value, overflow) = self.overflowing_neg();
         CtOption::new(value, overflow.not())
     }
+
+    /// Saturating negate this [`Int`].
+    ///
+    /// Maps [`Int::MIN`] to [`Int::MAX`], since the positive counterpart of this value cannot be
+    /// represented.
+    pub const fn saturating_neg(&self) -> Self {
+        let (value, overflow) = self.overflowing_neg();
+        Self::select(&value, &Self::MAX, overflow)
+    }
+}
+
+impl<const LIMBS: usize> CheckedNeg for Int<LIMBS> {
+    #[inline]
+    fn checked_neg(&self) -> CtOption<Self> {
+        self.checked_neg()
+    }
+}
+
+impl<const LIMBS: usize> SaturatingNeg for Int<LIMBS> {
+    #[inline]
+    fn saturating_neg(&self) -> Self {
+        self.saturating_neg()
+    }
 }
 
 impl<const LIMBS: usize> WrappingNeg for Int<LIMBS> {
@@ -121,4 +144,15 @@ mod tests {
             positive
         );
     }
+
+    #[test]
+    fn saturating_neg() {
+        assert_eq!(I128::MIN.saturating_neg(), I128::MAX);
+        assert_eq!(
+            I128::MAX.saturating_neg(),
+            I128::MIN.wrapping_add(&I128::ONE)
+        );
+        assert_eq!(I128::ONE.saturating_neg(), I128::MINUS_ONE);
+        assert_eq!(I128::ZERO.saturating_neg(), I128::ZERO);
+    }
 }
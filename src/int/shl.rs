@@ -1,6 +1,6 @@
 //! [`Int`] bitwise left shift operations.
 
-use crate::{CtOption, Int, ShlVartime, Uint, WrappingShl};
+use crate::{CheckedShl, CtOption, Int, SaturatingShl, ShlVartime, Uint, WrappingShl};
 use core::ops::{Shl, ShlAssign};
 
 impl<const LIMBS: usize> Int<LIMBS> {
@@ -49,6 +49,15 @@ impl<const LIMBS: usize> Int<LIMBS> {
     pub const fn wrapping_shl_vartime(&self, shift: u32) -> Self {
         Self(self.0.wrapping_shl_vartime(shift))
     }
+
+    /// Computes `self << shift` in a panic-free manner, returning `MAX` or `MIN` (depending on
+    /// the sign of `self`) if the result doesn't fit.
+    pub const fn saturating_shl(&self, shift: u32) -> Self {
+        let res = self.wrapping_shl(shift);
+        let overflow = Uint::eq(&res.wrapping_shr(shift).0, &self.0).not();
+        let bound = Self::select(&Self::MAX, &Self::MIN, self.is_negative());
+        Self::select(&res, &bound, overflow)
+    }
 }
 
 macro_rules! impl_shl {
@@ -83,6 +92,18 @@ macro_rules! impl_shl {
 
 impl_shl!(i32, u32, usize);
 
+impl<const LIMBS: usize> SaturatingShl for Int<LIMBS> {
+    fn saturating_shl(&self, shift: u32) -> Int<LIMBS> {
+        self.saturating_shl(shift)
+    }
+}
+
+impl<const LIMBS: usize> CheckedShl for Int<LIMBS> {
+    fn checked_shl(&self, shift: u32) -> CtOption<Self> {
+        self.overflowing_shl(shift)
+    }
+}
+
 impl<const LIMBS: usize> WrappingShl for Int<LIMBS> {
     fn wrapping_shl(&self, shift: u32) -> Int<LIMBS> {
         self.wrapping_shl(shift)
@@ -164,4 +185,17 @@ mod tests {
     fn shl64() {
         assert_eq!(N << 64, SIXTY_FOUR);
     }
+
+    #[test]
+    fn saturating_shl() {
+        assert_eq!(I256::ONE.saturating_shl(1), I256::from(2i32));
+        assert_eq!(I256::ONE.saturating_shl(254), I256::ONE.shl(254));
+        assert_eq!(I256::ONE.saturating_shl(255), I256::MAX);
+        assert_eq!(I256::MINUS_ONE.saturating_shl(255), I256::MIN);
+        assert_eq!(I256::MINUS_ONE.saturating_shl(300), I256::MIN);
+        assert_eq!(N.saturating_shl(1), TWO_N);
+        assert_eq!(N.saturating_shl(200), I256::MIN);
+        assert_eq!(N.wrapping_neg().saturating_shl(200), I256::MAX);
+        assert_eq!(I256::ZERO.saturating_shl(300), I256::ZERO);
+    }
 }
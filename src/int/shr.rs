@@ -1,6 +1,6 @@
 //! [`Int`] bitwise right shift operations.
 
-use crate::{Choice, CtOption, Int, Limb, ShrVartime, Uint, WrappingShr};
+use crate::{CheckedShr, Choice, CtOption, Int, Limb, ShrVartime, Uint, WrappingShr};
 use core::ops::{Shr, ShrAssign};
 
 impl<const LIMBS: usize> Int<LIMBS> {
@@ -163,6 +163,12 @@ macro_rules! impl_shr {
 
 impl_shr!(i32, u32, usize);
 
+impl<const LIMBS: usize> CheckedShr for Int<LIMBS> {
+    fn checked_shr(&self, shift: u32) -> CtOption<Self> {
+        self.overflowing_shr(shift)
+    }
+}
+
 impl<const LIMBS: usize> WrappingShr for Int<LIMBS> {
     fn wrapping_shr(&self, shift: u32) -> Int<LIMBS> {
         self.wrapping_shr(shift)
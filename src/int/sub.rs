@@ -1,6 +1,9 @@
 //! [`Int`] subtraction operations.
 
-use crate::{Checked, CheckedSub, Choice, CtOption, Int, Sub, SubAssign, Wrapping, WrappingSub};
+use crate::{
+    Checked, CheckedSub, Choice, CtOption, Int, Saturating, SaturatingSub, Sub, SubAssign,
+    Wrapping, WrappingSub,
+};
 
 impl<const LIMBS: usize> Int<LIMBS> {
     /// Perform subtraction, returning the result along with a [`Choice`] which `is_true`
@@ -29,6 +32,15 @@ impl<const LIMBS: usize> Int<LIMBS> {
     pub const fn wrapping_sub(&self, rhs: &Self) -> Self {
         self.underflowing_sub(rhs).0
     }
+
+    /// Perform saturating subtraction, returning `MAX` or `MIN` on underflow, depending on its
+    /// direction.
+    pub const fn saturating_sub(&self, rhs: &Self) -> Self {
+        let (res, underflow) = self.underflowing_sub(rhs);
+        // Underflow is only possible when the operands have opposing signs
+        let bound = Self::select(&Self::MIN, &Self::MAX, rhs.is_negative());
+        Self::select(&res, &bound, underflow)
+    }
 }
 
 impl<const LIMBS: usize> CheckedSub for Int<LIMBS> {
@@ -91,6 +103,24 @@ impl<const LIMBS: usize> SubAssign<&Checked<Int<LIMBS>>> for Checked<Int<LIMBS>>
     }
 }
 
+impl<const LIMBS: usize> SubAssign for Saturating<Int<LIMBS>> {
+    fn sub_assign(&mut self, other: Self) {
+        *self = *self - other;
+    }
+}
+
+impl<const LIMBS: usize> SubAssign<&Saturating<Int<LIMBS>>> for Saturating<Int<LIMBS>> {
+    fn sub_assign(&mut self, other: &Self) {
+        *self = *self - other;
+    }
+}
+
+impl<const LIMBS: usize> SaturatingSub for Int<LIMBS> {
+    fn saturating_sub(&self, rhs: &Self) -> Self {
+        self.saturating_sub(rhs)
+    }
+}
+
 impl<const LIMBS: usize> WrappingSub for Int<LIMBS> {
     fn wrapping_sub(&self, v: &Self) -> Self {
         Self(self.0.wrapping_sub(&v.0))
@@ -227,4 +257,14 @@ mod tests {
         let result = I128::MIN.wrapping_sub(&two);
         assert_eq!(result, max_minus_one);
     }
+
+    #[test]
+    fn saturating_sub() {
+        assert_eq!(I128::ONE.saturating_sub(&I128::ONE), I128::ZERO);
+        assert_eq!(I128::MAX.saturating_sub(&I128::MINUS_ONE), I128::MAX);
+        assert_eq!(I128::ZERO.saturating_sub(&I128::MIN), I128::MAX);
+        assert_eq!(I128::MINUS_ONE.saturating_sub(&I128::MIN), I128::MAX);
+        assert_eq!(I128::MIN.saturating_sub(&I128::ONE), I128::MIN);
+        assert_eq!(I128::MIN.saturating_sub(&I128::MAX), I128::MIN);
+    }
 }
@@ -1,4 +1,4 @@
-use crate::{Choice, CtEq};
+use crate::{Choice, CtEq, Limb, NonZero, UintRef, Word};
 use core::ops::Neg;
 
 /// Possible return values for Jacobi symbol calculations.
@@ -85,9 +85,72 @@ impl Neg for JacobiSymbol {
     }
 }
 
+/// Compute the Jacobi symbol `(a|n)` for a non-zero word-sized `a` and an odd multi-limb `n`.
+pub(crate) const fn jacobi_symbol_word_vartime(a: Word, n: &UintRef) -> i8 {
+    let twos = a.trailing_zeros();
+    let odd = a >> twos;
+    let n_lo = n.0[0].0;
+    let mut sign = 1;
+
+    // (2|n) = -1 if and only if n ≡ 3, 5 (mod 8)
+    if twos % 2 == 1 && matches!(n_lo & 7, 3 | 5) {
+        sign = -sign;
+    }
+
+    // Quadratic reciprocity: (odd|n) = (n|odd) * (-1)^((odd - 1)/2 * (n - 1)/2)
+    if odd & n_lo & 3 == 3 {
+        sign = -sign;
+    }
+
+    let n_mod_odd = n.rem_limb(NonZero::<Limb>::new_unwrap(Limb(odd))).0;
+    sign * jacobi_word_vartime(n_mod_odd, odd)
+}
+
+/// Compute the Jacobi symbol `(a|n)` for word-sized `a` and odd `n`.
+pub(crate) const fn jacobi_word_vartime(mut a: Word, mut n: Word) -> i8 {
+    let mut sign = 1;
+    a %= n;
+    while a != 0 {
+        while a % 2 == 0 {
+            a /= 2;
+            if matches!(n & 7, 3 | 5) {
+                sign = -sign;
+            }
+        }
+        (a, n) = (n, a);
+        if a & n & 3 == 3 {
+            sign = -sign;
+        }
+        a %= n;
+    }
+    if n == 1 { sign } else { 0 }
+}
+
 #[cfg(feature = "subtle")]
 impl subtle::ConstantTimeEq for JacobiSymbol {
     fn ct_eq(&self, other: &Self) -> subtle::Choice {
         CtEq::ct_eq(self, other).into()
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::{jacobi_symbol_word_vartime, jacobi_word_vartime};
+    use crate::U128;
+
+    #[test]
+    fn jacobi_word() {
+        assert_eq!(jacobi_word_vartime(2, 7), 1);
+        assert_eq!(jacobi_word_vartime(3, 7), -1);
+        assert_eq!(jacobi_word_vartime(7, 7), 0);
+        assert_eq!(jacobi_word_vartime(1001, 9907), -1);
+    }
+
+    #[test]
+    fn jacobi_symbol_word() {
+        let n = U128::from_u64(9907);
+        assert_eq!(jacobi_symbol_word_vartime(1001, n.as_uint_ref()), -1);
+        assert_eq!(jacobi_symbol_word_vartime(2, n.as_uint_ref()), -1);
+        assert_eq!(jacobi_symbol_word_vartime(9907, n.as_uint_ref()), 0);
+    }
+}
@@ -26,6 +26,8 @@
 //!   Type aliases are provided for various sizes, e.g. [`U128`], [`U384`], [`U256`], [`U2048`],
 //!   [`U3072`], [`U4096`].
 //! - [`BoxedUint`]: heap-allocated big integer type. Requires the `alloc` crate feature is enabled.
+//! - [`Int`] and [`BoxedInt`]: signed (two's complement) counterparts of [`Uint`] and
+//!   [`BoxedUint`] respectively.
 //!
 //! Big integer types in this crate use a 32-bit or 64-bit saturated representation, depending on
 //! the underlying CPU's pointer width.
@@ -62,7 +64,7 @@
 //! such as [`Add`], [`Sub`], [`Mul`], and [`Div`].
 //!
 //! To use these traits you must first pick a wrapper type which determines
-//! overflow behavior: [`Wrapping`] or [`Checked`].
+//! overflow behavior: [`Wrapping`], [`Checked`], or [`Saturating`].
 //!
 //! #### Wrapping arithmetic
 //!
@@ -88,6 +90,19 @@
 //! assert_eq!(c.0.unwrap(), U256::from(3u8))
 //! ```
 //!
+//! #### Saturating arithmetic
+//!
+//! ```
+//! use crypto_bigint::{U256, Saturating};
+//!
+//! let a = Saturating(U256::MAX);
+//! let b = Saturating(U256::ONE);
+//!
+//! // `MAX` + 1 saturates at `MAX`, and 1 - `MAX` saturates at zero
+//! assert_eq!((a + b).0, U256::MAX);
+//! assert_eq!((b - a).0, U256::ZERO);
+//! ```
+//!
 //! ### Modular arithmetic
 //!
 //! See the [`modular`] module for types which implement Montgomery form modular arithmetic.
@@ -156,11 +171,11 @@
 //! [`Rem`]: core::ops::Rem
 //! [`Sub`]: core::ops::Sub
 //!
-//! ## `crypto-primes` crate
+//! ## Primality testing
 //!
-//! This crate contains no prime number related functionality (e.g. random prime generation). Such
-//! functionality can be found in the companion [`crypto-primes`](https://docs.rs/crypto-primes)
-//! crate.
+//! The [`primality`] module contains probabilistic primality tests (Miller-Rabin, strong Lucas
+//! and Baillie-PSW). More extensive prime number related functionality can be found in the
+//! companion [`crypto-primes`](https://docs.rs/crypto-primes) crate.
 
 #[cfg(feature = "alloc")]
 #[allow(unused_imports)]
@@ -174,10 +189,12 @@ pub use crate::{
     limb::Limb,
     non_zero::*,
     odd::*,
+    saturating::Saturating,
     traits::*,
     uint::{
         div_limb::Reciprocal,
         encoding::{EncodedUint, TryFromSliceError},
+        reciprocal::UintReciprocal,
         *,
     },
     word::{WideWord, Word},
@@ -188,7 +205,12 @@ pub use ctutils;
 pub use ctutils::{Choice, CtOption};
 
 #[cfg(feature = "alloc")]
-pub use crate::uint::boxed::BoxedUint;
+pub use crate::{
+    int::boxed::BoxedInt,
+    uint::boxed::{BoxedUint, reciprocal::BoxedUintReciprocal},
+};
+#[cfg(feature = "ff")]
+pub use ff;
 #[cfg(feature = "rand_core")]
 pub use rand_core;
 #[cfg(feature = "rlp")]
@@ -205,6 +227,7 @@ pub use {
 mod macros;
 
 pub mod modular;
+pub mod primality;
 
 #[cfg(feature = "hybrid-array")]
 mod array;
@@ -215,6 +238,7 @@ mod limb;
 mod non_zero;
 mod odd;
 mod primitives;
+mod saturating;
 mod traits;
 mod uint;
 mod word;
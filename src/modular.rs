@@ -1,7 +1,7 @@
 //! Modular arithmetic support.
 //!
 //! This module provides support for various modular arithmetic operations, implemented in terms of
-//! Montgomery form.
+//! Montgomery form or Barrett reduction.
 //!
 //! # Constant moduli
 //!
@@ -11,14 +11,60 @@
 //! The [`impl_modulus!`][`crate::impl_modulus`] macro can be used to define a compile-time modulus,
 //! whereas the [`const_monty_form!`][`crate::const_monty_form`] macro can define a [`ConstMontyForm`] constant.
 //!
+//! Pseudo-Mersenne moduli with a [`SpecialForm`] `2^k - c`, such as `2^255 - 19`, can instead be
+//! defined with the [`const_special_form_params!`][`crate::const_special_form_params`] macro, whose
+//! [`SpecialFormElement`]s reduce products by multiplying their high limbs by `c` instead of using
+//! Montgomery reduction.
+//!
+//! Prime moduli can be defined with the
+//! [`const_prime_monty_params!`][`crate::const_prime_monty_params`] macro, which additionally
+//! derives their [`PrimeParams`], such as the 2-adicity and a primitive root of unity, at compile
+//! time.
+//!
 //! # Dynamic moduli chosen at runtime
 //!
 //! The [`MontyForm`] and [`MontyParams`] types implement support for modular arithmetic where
 //! the modulus can vary at runtime.
+//!
+//! # Generic modular arithmetic
+//!
+//! The [`ModularRing`][`crate::ModularRing`] trait abstracts over the Montgomery form types above,
+//! allowing generic algorithms such as polynomial evaluation to be written without depending on
+//! the representation.
+//!
+//! # Barrett reduction
+//!
+//! The [`BarrettParams`] type (and its heap-allocated counterpart `BoxedBarrettParams`) performs
+//! modular reduction and multiplication with respect to any non-zero modulus, including even ones,
+//! without converting values to and from Montgomery form.
+//!
+//! # Fixed-base exponentiation
+//!
+//! The `FixedBaseTable` type precomputes comb tables for a fixed base, such as a group generator,
+//! which speed up raising it to many different exponents. Requires the `alloc` feature.
+//!
+//! # Powers of a modulus
+//!
+//! The `PowerModulus` type computes Montgomery parameters for the powers `m^k` of an odd modulus,
+//! as used by the Paillier and Damgård–Jurik cryptosystems, and moves residues and inverses
+//! between them. Requires the `alloc` feature.
+//!
+//! # Number-theoretic transform
+//!
+//! The [`NttParams`] type precomputes twiddle tables for the negacyclic number-theoretic transform
+//! over a word-sized prime, as used for polynomial multiplication by lattice-based schemes.
+//!
+//! # Chinese remaindering
+//!
+//! The [`CrtParams`] type (and its heap-allocated counterpart `BoxedCrtParams`) reconstructs an
+//! integer modulo a product of pairwise coprime moduli from its residues modulo each of them.
 
+mod barrett;
 mod const_monty_form;
+mod crt;
 mod lincomb;
 mod monty_form;
+mod ntt;
 mod reduction;
 
 mod add;
@@ -27,20 +73,45 @@ mod div_by_2;
 mod mul;
 mod pow;
 pub(crate) mod safegcd;
+mod special_form;
+mod sqrt;
 mod sub;
 
+#[cfg(feature = "alloc")]
+mod batch_invert;
+#[cfg(feature = "alloc")]
+mod boxed_barrett;
+#[cfg(feature = "alloc")]
+mod boxed_crt;
 #[cfg(feature = "alloc")]
 pub(crate) mod boxed_monty_form;
+#[cfg(feature = "alloc")]
+mod fixed_base;
+#[cfg(feature = "alloc")]
+mod power_modulus;
 
 pub use self::{
-    const_monty_form::{ConstMontyForm, ConstMontyParams},
+    barrett::BarrettParams,
+    const_monty_form::{ConstMontyForm, ConstMontyParams, ConstPrimeMontyParams, PrimeParams},
+    crt::CrtParams,
     monty_form::{MontyForm, MontyParams},
+    ntt::NttParams,
+    special_form::{SpecialForm, SpecialFormElement, SpecialFormParams},
 };
 
+#[cfg(feature = "ff")]
+pub use self::const_monty_form::PrimeFieldParams;
+
 pub(crate) use self::safegcd::SafeGcdInverter;
 
 #[cfg(feature = "alloc")]
-pub use self::boxed_monty_form::{BoxedMontyForm, BoxedMontyParams};
+pub use self::{
+    boxed_barrett::BoxedBarrettParams,
+    boxed_crt::BoxedCrtParams,
+    boxed_monty_form::{BoxedMontyForm, BoxedMontyParams},
+    fixed_base::FixedBaseTable,
+    power_modulus::PowerModulus,
+};
 
 /// A generalization for numbers kept in optimized representations (e.g. Montgomery)
 /// that can be converted back to the original form.
@@ -55,11 +126,13 @@ pub trait Retrieve {
 #[cfg(test)]
 mod tests {
     use crate::{
-        NonZero, U64, U128, U256, Uint, const_monty_params,
+        ModularRing, NonZero, U64, U128, U256, Uint, const_monty_params, const_special_form_params,
         modular::{
             const_monty_form::{ConstMontyForm, ConstMontyParams},
+            monty_form::MontyForm,
             mul::{mul_montgomery_form, square_montgomery_form},
             reduction::montgomery_reduction,
+            special_form::SpecialFormElement,
         },
     };
 
@@ -314,4 +387,66 @@ mod tests {
         // Confirm that when creating a Modular and retrieving the value, that it equals the original
         assert_eq!(x, x_mod.retrieve());
     }
+
+    /// Evaluate the polynomial with the given coefficients (lowest degree first) at `x`.
+    fn horner<R: ModularRing>(coefficients: &[R], x: &R) -> R {
+        coefficients
+            .iter()
+            .rev()
+            .fold(x.zero_like(), |acc, c| acc * x + c)
+    }
+
+    /// Check the [`ModularRing`] operations on `x`, given the smallest quadratic non-residue
+    /// modulo its modulus.
+    fn check_modular_ring<R: ModularRing>(x: R, non_residue: u8) {
+        let zero = x.zero_like();
+        let one = x.one_like();
+        let two = x.new_like(&R::Integer::from(2u8));
+        let three = x.new_like(&R::Integer::from(3u8));
+
+        assert_eq!(one.clone() + &one, two);
+        assert_eq!(x.clone() + &zero, x);
+        assert_eq!(x.clone() * &one, x);
+        assert_eq!(x.clone() + -x.clone(), zero);
+        assert_eq!(x.clone() * x.invert().unwrap(), one);
+        assert!(zero.invert().is_none().to_bool());
+        assert_eq!(x.pow(&R::Integer::from(3u8)), x.square() * &x);
+        assert_eq!(two.retrieve(), R::Integer::from(2u8));
+
+        // 1 + 2x + 3x^2
+        let p = horner(&[one.clone(), two.clone(), three.clone()], &x);
+        assert_eq!(p, one + two * &x + three * x.square());
+
+        let square = x.square();
+        assert!(square.is_square().to_bool());
+        assert!(zero.is_square().to_bool());
+        assert_eq!(square.sqrt().unwrap().square(), square);
+        assert_eq!(zero.sqrt().unwrap(), zero);
+
+        let non_residue = x.new_like(&R::Integer::from(non_residue));
+        assert!(!non_residue.is_square().to_bool());
+        assert!(non_residue.sqrt().is_none().to_bool());
+    }
+
+    #[test]
+    fn modular_ring() {
+        let x =
+            U256::from_be_hex("44acf6b7e36c1342c2c5897204fe09504e1e2efb1a900377dbc4e7a6a133ec56");
+
+        // 7 is the smallest quadratic non-residue modulo `Modulus1`
+        check_modular_ring(ConstMontyForm::<Modulus1, { Modulus1::LIMBS }>::new(&x), 7);
+        check_modular_ring(MontyForm::new(&x, Modulus1::PARAMS), 7);
+
+        // 2 is a quadratic non-residue modulo 2^255 - 19, since it is 5 mod 8
+        const_special_form_params!(P25519, U256, 255, 19);
+        check_modular_ring(SpecialFormElement::<P25519, { U256::LIMBS }>::new(&x), 2);
+
+        #[cfg(feature = "alloc")]
+        {
+            use crate::{BoxedUint, modular::BoxedMontyForm, modular::BoxedMontyParams};
+
+            let params = BoxedMontyParams::new(Modulus1::PARAMS.modulus.into());
+            check_modular_ring(BoxedMontyForm::new(BoxedUint::from(x), params), 7);
+        }
+    }
 }
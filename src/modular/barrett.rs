@@ -0,0 +1,212 @@
+//! Barrett reduction for integers with a fixed number of limbs.
+
+use crate::{Choice, Limb, NonZero, Uint};
+
+/// Precomputed parameters for reducing integers modulo an arbitrary non-zero modulus using
+/// Barrett reduction.
+///
+/// Unlike [`MontyParams`](super::MontyParams), the modulus does not need to be odd, and values
+/// are kept in their canonical representation, so no conversions are needed before or after
+/// modular multiplications.
+///
+/// This implements Algorithm 14.42 from the "Handbook of Applied Cryptography", by A. Menezes,
+/// P. van Oorschot, and S. Vanstone, CRC Press, 1996, with base `b = 2` and `k = Uint::BITS`.
+/// The modulus is shifted left so that its most significant bit is set, which makes the
+/// reduction independent of its bit length.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct BarrettParams<const LIMBS: usize> {
+    /// The modulus
+    modulus: NonZero<Uint<LIMBS>>,
+    /// `modulus << shift`, which has its most significant bit set
+    normalized: Uint<LIMBS>,
+    /// Low half of `μ = ⌊(4^k - 1) / normalized⌋`, whose bit `k` is always set
+    mu: Uint<LIMBS>,
+    /// Number of leading zeros of the modulus
+    shift: u32,
+}
+
+impl<const LIMBS: usize> BarrettParams<LIMBS> {
+    /// Precompute the Barrett reduction parameters for the given `modulus`.
+    ///
+    /// This method is constant-time with respect to `modulus`.
+    pub const fn new(modulus: NonZero<Uint<LIMBS>>) -> Self {
+        let shift = modulus.as_ref().leading_zeros();
+        let normalized = modulus.as_ref().shl(shift);
+
+        // `4^k - 1 = (2^k + (MAX - normalized)) * 2^k + MAX`, and `MAX - normalized < normalized`
+        // since the top bit of `normalized` is set, so the high half of the quotient is exactly 1
+        // and the low half is computed by a bitwise long division of the remaining `k` bits.
+        let mut rem = Uint::MAX.wrapping_sub(&normalized);
+        let mut mu = Uint::ZERO;
+        let mut i = 0;
+        while i < Uint::<LIMBS>::BITS {
+            let (shifted, carry) = rem.carrying_shl1(Limb::ONE);
+            let (diff, borrow) = shifted.borrowing_sub(&normalized, Limb::ZERO);
+            let ge = carry.is_nonzero().or(borrow.is_nonzero().not());
+            rem = Uint::select(&shifted, &diff, ge);
+            mu = mu.carrying_shl1(Limb::select(Limb::ZERO, Limb::ONE, ge)).0;
+            i += 1;
+        }
+
+        Self {
+            modulus,
+            normalized,
+            mu,
+            shift,
+        }
+    }
+
+    /// Returns the modulus which was used to initialize these parameters.
+    pub const fn modulus(&self) -> &NonZero<Uint<LIMBS>> {
+        &self.modulus
+    }
+
+    /// Computes `x mod modulus`.
+    ///
+    /// This method is constant-time with respect to `x`.
+    pub const fn reduce(&self, x: &Uint<LIMBS>) -> Uint<LIMBS> {
+        self.reduce_wide_bounded(x, &Uint::ZERO)
+    }
+
+    /// Computes `(lo + hi * 2^k) mod modulus`, where `k = Uint::BITS`.
+    ///
+    /// This method is constant-time with respect to the inputs.
+    pub const fn reduce_wide(&self, (lo, hi): (Uint<LIMBS>, Uint<LIMBS>)) -> Uint<LIMBS> {
+        // `lo + hi * 2^k ≡ lo + (hi mod modulus) * 2^k`, which satisfies the bound below
+        let hi = self.reduce(&hi);
+        self.reduce_wide_bounded(&lo, &hi)
+    }
+
+    /// Computes `a * b mod modulus`.
+    ///
+    /// This method is constant-time with respect to the inputs.
+    pub const fn mul_mod(&self, a: &Uint<LIMBS>, b: &Uint<LIMBS>) -> Uint<LIMBS> {
+        self.reduce_wide(a.widening_mul(b))
+    }
+
+    /// Computes `a * a mod modulus`.
+    ///
+    /// This method is constant-time with respect to the input.
+    pub const fn square_mod(&self, a: &Uint<LIMBS>) -> Uint<LIMBS> {
+        self.reduce_wide(a.square_wide())
+    }
+
+    /// Computes `(lo + hi * 2^k) mod modulus` for `hi < modulus`.
+    const fn reduce_wide_bounded(&self, lo: &Uint<LIMBS>, hi: &Uint<LIMBS>) -> Uint<LIMBS> {
+        // Normalize the input along with the modulus: `x < modulus * 2^k`, so the shifted value
+        // `x' < normalized * 2^k` still fits in `2k` bits.
+        let lo_shifted = lo.shl(self.shift);
+        let hi = hi
+            .shl(self.shift)
+            .bitor(&lo.wrapping_shr(Uint::<LIMBS>::BITS - self.shift));
+        let lo = lo_shifted;
+
+        // `q1 = ⌊x' / 2^(k-1)⌋ = c1 * 2^k + q1_lo`
+        let c1 = hi.limbs[LIMBS - 1].shr(Limb::BITS - 1);
+        let q1_lo = hi
+            .overflowing_shl1()
+            .0
+            .bitor(&lo.shr(Uint::<LIMBS>::BITS - 1));
+
+        // `q3 = ⌊q1 * μ / 2^(k+1)⌋ = ⌊(q1 + ⌊q1 * (μ - 2^k) / 2^k⌋) / 2⌋`, where the inner
+        // product is `c1 * mu * 2^k + q1_lo * mu`, with the low half of the latter discarded.
+        let (_, q1_mu_hi) = q1_lo.widening_mul(&self.mu);
+        let c1_mu = self.mu.bitand_limb(Limb::ZERO.wrapping_sub(c1));
+        let (sum, carry1) = q1_lo.carrying_add(&q1_mu_hi, Limb::ZERO);
+        let (sum, carry2) = sum.carrying_add(&c1_mu, Limb::ZERO);
+        // The quotient estimate is at most `⌊x' / normalized⌋ < 2^k`, so only the lowest bit of
+        // the carries ends up in it.
+        let carry = c1.wrapping_add(carry1).wrapping_add(carry2);
+        let mut q3 = sum.shr1();
+        q3.limbs[LIMBS - 1] = q3.limbs[LIMBS - 1].bitor(carry.shl(Limb::BITS - 1));
+
+        // `r = x' - q3 * normalized`, with `0 <= r < 4 * normalized < 2^(k+2)`, so only the
+        // lowest limb of the high half is needed.
+        let (prod_lo, prod_hi) = q3.widening_mul(&self.normalized);
+        let (mut r, borrow) = lo.borrowing_sub(&prod_lo, Limb::ZERO);
+        let (mut r_hi, _) = hi.limbs[0].borrowing_sub(prod_hi.limbs[0], borrow);
+
+        // The quotient estimate is off by at most 3
+        let mut i = 0;
+        while i < 3 {
+            let (diff, borrow) = r.borrowing_sub(&self.normalized, Limb::ZERO);
+            let (diff_hi, borrow) = r_hi.borrowing_sub(Limb::ZERO, borrow);
+            let ge: Choice = borrow.is_nonzero().not();
+            r = Uint::select(&r, &diff, ge);
+            r_hi = Limb::select(r_hi, diff_hi, ge);
+            i += 1;
+        }
+
+        // `r = (x mod modulus) * 2^shift`
+        r.shr(self.shift)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::BarrettParams;
+    use crate::{NonZero, U64, U128, U256, Uint};
+
+    fn params<const LIMBS: usize>(modulus: Uint<LIMBS>) -> BarrettParams<LIMBS> {
+        BarrettParams::new(NonZero::new(modulus).unwrap())
+    }
+
+    #[test]
+    fn small_moduli() {
+        for m in 1u64..64 {
+            let params = params(U64::from_u64(m));
+            for a in 0u64..64 {
+                let a_uint = U64::from_u64(a);
+                assert_eq!(params.reduce(&a_uint), U64::from_u64(a % m));
+                for b in [0u64, 1, 17, 63, u64::MAX] {
+                    let expected = ((a as u128 * b as u128) % m as u128) as u64;
+                    let b = U64::from_u64(b);
+                    assert_eq!(params.mul_mod(&a_uint, &b), U64::from_u64(expected));
+                }
+                assert_eq!(params.square_mod(&a_uint), U64::from_u64(a * a % m));
+            }
+        }
+    }
+
+    #[test]
+    fn even_modulus() {
+        let m =
+            U256::from_be_hex("ffffffff00000001000000000000000000000000fffffffffffffffffffffffe");
+        let params = params(m);
+        let p = NonZero::new(m).unwrap();
+        let a =
+            U256::from_be_hex("14bfae46f4026e97c7a3fcd889b379a5f025719911c994a594fc6c5092ac58b1");
+        let b = U256::MAX;
+
+        assert_eq!(params.reduce(&b), b.rem(&p));
+        assert_eq!(params.mul_mod(&a, &b), a.mul_mod(&b, &p));
+        assert_eq!(params.square_mod(&b), b.square_mod(&p));
+    }
+
+    #[test]
+    fn power_of_two_modulus() {
+        let m = U128::ONE.shl_vartime(77);
+        let params = params(m);
+        let a = U128::MAX;
+        assert_eq!(params.reduce(&a), a.bitand(&m.wrapping_sub(&U128::ONE)));
+        assert_eq!(params.reduce_wide((a, a)), params.reduce(&a));
+    }
+
+    #[test]
+    fn max_modulus() {
+        let params = params(U128::MAX);
+        assert_eq!(params.reduce(&U128::MAX), U128::ZERO);
+        assert_eq!(params.mul_mod(&U128::MAX, &U128::ONE), U128::ZERO);
+        assert_eq!(params.reduce_wide((U128::ONE, U128::ONE)), U128::from_u8(2));
+    }
+
+    #[test]
+    fn const_params() {
+        const PARAMS: BarrettParams<{ U64::LIMBS }> =
+            BarrettParams::new(NonZero::<U64>::new_unwrap(U64::from_u64(1000)));
+        assert_eq!(
+            PARAMS.mul_mod(&U64::from_u64(12345), &U64::from_u64(6789)),
+            U64::from_u64(12345 * 6789 % 1000)
+        );
+    }
+}
@@ -0,0 +1,46 @@
+//! Batch inversion of integers in Montgomery form using Montgomery's trick.
+
+use crate::{Choice, CtEq, CtOption, CtSelect, Invert};
+use alloc::vec::Vec;
+use core::ops::Mul;
+
+/// Inverts all of the `values` in place using a single inversion, returning a mask whose elements
+/// are truthy for the values which were inverted.
+///
+/// Values equal to `zero` are skipped and left unchanged, and so are all of the values if the
+/// product of the non-zero ones is not invertible.
+///
+/// This function is constant-time with respect to the values, but not their number.
+pub(crate) fn batch_invert<T>(values: &mut [T], zero: &T, one: &T) -> Vec<Choice>
+where
+    T: Clone + CtEq + CtSelect + Invert<Output = CtOption<T>>,
+    for<'a> &'a T: Mul<&'a T, Output = T>,
+{
+    // `prefixes[i]` is the product of the non-zero values before `values[i]`
+    let mut prefixes = Vec::with_capacity(values.len());
+    let mut is_nonzero = Vec::with_capacity(values.len());
+    let mut acc = one.clone();
+    for value in values.iter() {
+        let nonzero = value.ct_ne(zero);
+        let product = &acc * value;
+        prefixes.push(acc.clone());
+        acc.ct_assign(&product, nonzero);
+        is_nonzero.push(nonzero);
+    }
+
+    let inverse = acc.invert();
+    let is_invertible = inverse.is_some();
+    let mut acc = inverse.unwrap_or(one.clone());
+
+    // `acc` is now the inverse of the product of the non-zero values up to `values[i]`
+    for (i, value) in values.iter_mut().enumerate().rev() {
+        let is_inverted = is_nonzero[i].and(is_invertible);
+        let value_inverse = &acc * &prefixes[i];
+        let next = &acc * value;
+        acc.ct_assign(&next, is_nonzero[i]);
+        value.ct_assign(&value_inverse, is_inverted);
+        is_nonzero[i] = is_inverted;
+    }
+
+    is_nonzero
+}
@@ -0,0 +1,206 @@
+//! Barrett reduction for heap-allocated integers.
+
+use crate::{BoxedUint, Choice, CtSelect, Limb, NonZero};
+
+/// Precomputed parameters for reducing [`BoxedUint`]s modulo an arbitrary non-zero modulus using
+/// Barrett reduction.
+///
+/// This is the heap-allocated counterpart of [`BarrettParams`](super::BarrettParams). All inputs
+/// are expected to have the same precision as the modulus, or twice that for
+/// [`Self::reduce_wide`].
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct BoxedBarrettParams {
+    /// The modulus
+    modulus: NonZero<BoxedUint>,
+    /// `modulus << shift`, which has its most significant bit set
+    normalized: BoxedUint,
+    /// Low half of `μ = ⌊(4^k - 1) / normalized⌋`, whose bit `k` is always set
+    mu: BoxedUint,
+    /// Number of leading zeros of the modulus
+    shift: u32,
+}
+
+impl BoxedBarrettParams {
+    /// Precompute the Barrett reduction parameters for the given `modulus`.
+    ///
+    /// This method is constant-time with respect to `modulus`, but not its precision.
+    pub fn new(modulus: NonZero<BoxedUint>) -> Self {
+        let bits_precision = modulus.bits_precision();
+        let shift = modulus.leading_zeros();
+        let normalized = modulus.shl(shift);
+
+        // See `BarrettParams::new`: the high half of the quotient is exactly 1, and the low half
+        // is computed by a bitwise long division.
+        let mut rem = BoxedUint::max(bits_precision).wrapping_sub(&normalized);
+        let mut mu = BoxedUint::zero_with_precision(bits_precision);
+        for _ in 0..bits_precision {
+            let (mut shifted, carry) = rem.shl1();
+            shifted.limbs[0] |= Limb::ONE;
+            let (diff, borrow) = shifted.borrowing_sub(&normalized, Limb::ZERO);
+            let ge = carry.is_nonzero().or(borrow.is_nonzero().not());
+            rem = shifted.ct_select(&diff, ge);
+            mu.shl1_assign();
+            mu.limbs[0] |= Limb::select(Limb::ZERO, Limb::ONE, ge);
+        }
+
+        Self {
+            modulus,
+            normalized,
+            mu,
+            shift,
+        }
+    }
+
+    /// Returns the modulus which was used to initialize these parameters.
+    pub fn modulus(&self) -> &NonZero<BoxedUint> {
+        &self.modulus
+    }
+
+    /// Returns the precision of the modulus in bits.
+    pub fn bits_precision(&self) -> u32 {
+        self.modulus.bits_precision()
+    }
+
+    /// Computes `x mod modulus`.
+    ///
+    /// This method is constant-time with respect to `x`.
+    pub fn reduce(&self, x: &BoxedUint) -> BoxedUint {
+        debug_assert_eq!(x.bits_precision(), self.bits_precision());
+        let zero = BoxedUint::zero_with_precision(self.bits_precision());
+        self.reduce_wide_bounded(x, &zero)
+    }
+
+    /// Computes `x mod modulus` for `x` with twice the precision of the modulus.
+    ///
+    /// This method is constant-time with respect to `x`.
+    pub fn reduce_wide(&self, x: &BoxedUint) -> BoxedUint {
+        debug_assert_eq!(x.bits_precision(), 2 * self.bits_precision());
+        let (lo, hi) = x.limbs.split_at(self.modulus.nlimbs());
+        let lo = BoxedUint::from(lo);
+        let hi = BoxedUint::from(hi);
+
+        // `lo + hi * 2^k ≡ lo + (hi mod modulus) * 2^k`, which satisfies the bound below
+        let hi = self.reduce(&hi);
+        self.reduce_wide_bounded(&lo, &hi)
+    }
+
+    /// Computes `a * b mod modulus`.
+    ///
+    /// This method is constant-time with respect to the inputs.
+    pub fn mul_mod(&self, a: &BoxedUint, b: &BoxedUint) -> BoxedUint {
+        debug_assert_eq!(a.bits_precision(), b.bits_precision());
+        self.reduce_wide(&a.mul(b))
+    }
+
+    /// Computes `a * a mod modulus`.
+    ///
+    /// This method is constant-time with respect to the input.
+    pub fn square_mod(&self, a: &BoxedUint) -> BoxedUint {
+        self.reduce_wide(&a.square())
+    }
+
+    /// Computes `(lo + hi * 2^k) mod modulus` for `hi < modulus`.
+    ///
+    /// See `BarrettParams::reduce_wide_bounded` for a description of the steps.
+    fn reduce_wide_bounded(&self, lo: &BoxedUint, hi: &BoxedUint) -> BoxedUint {
+        let bits_precision = self.bits_precision();
+        let nlimbs = self.modulus.nlimbs();
+
+        let lo_shifted = lo.shl(self.shift);
+        let hi = hi
+            .shl(self.shift)
+            .bitor(&lo.wrapping_shr(bits_precision - self.shift));
+        let lo = lo_shifted;
+
+        // `q1 = ⌊x' / 2^(k-1)⌋ = c1 * 2^k + q1_lo`
+        let c1 = hi.limbs[nlimbs - 1].shr(Limb::BITS - 1);
+        let (q1_lo, _) = hi.shl1();
+        let q1_lo = q1_lo.bitor(&lo.shr(bits_precision - 1));
+
+        // `q3 = ⌊(q1 + ⌊q1 * (μ - 2^k) / 2^k⌋) / 2⌋`
+        let q1_mu = q1_lo.mul(&self.mu);
+        let q1_mu_hi = BoxedUint::from(&q1_mu.limbs[nlimbs..]);
+        let zero = BoxedUint::zero_with_precision(bits_precision);
+        let c1_mu = zero.ct_select(&self.mu, c1.is_nonzero());
+        let (sum, carry1) = q1_lo.carrying_add(&q1_mu_hi, Limb::ZERO);
+        let (mut q3, carry2) = sum.carrying_add(&c1_mu, Limb::ZERO);
+        let carry = c1.wrapping_add(carry1).wrapping_add(carry2);
+        q3.shr1_assign();
+        q3.limbs[nlimbs - 1] |= carry.shl(Limb::BITS - 1);
+
+        // `r = x' - q3 * normalized < 2^(k+2)`
+        let prod = q3.mul(&self.normalized);
+        let prod_lo = BoxedUint::from(&prod.limbs[..nlimbs]);
+        let (mut r, borrow) = lo.borrowing_sub(&prod_lo, Limb::ZERO);
+        let (mut r_hi, _) = hi.limbs[0].borrowing_sub(prod.limbs[nlimbs], borrow);
+
+        // The quotient estimate is off by at most 3
+        for _ in 0..3 {
+            let (diff, borrow) = r.borrowing_sub(&self.normalized, Limb::ZERO);
+            let (diff_hi, borrow) = r_hi.borrowing_sub(Limb::ZERO, borrow);
+            let ge: Choice = borrow.is_nonzero().not();
+            r = r.ct_select(&diff, ge);
+            r_hi = Limb::select(r_hi, diff_hi, ge);
+        }
+
+        r.shr(self.shift)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::BoxedBarrettParams;
+    use crate::{BoxedUint, NonZero, Resize};
+
+    fn params(modulus: u64, bits_precision: u32) -> BoxedBarrettParams {
+        let modulus = BoxedUint::from(modulus).resize(bits_precision);
+        BoxedBarrettParams::new(NonZero::new(modulus).unwrap())
+    }
+
+    #[test]
+    fn small_moduli() {
+        for m in 1u64..64 {
+            let params = params(m, 64);
+            for a in 0u64..64 {
+                let a_uint = BoxedUint::from(a);
+                assert_eq!(params.reduce(&a_uint), BoxedUint::from(a % m));
+                for b in [0u64, 1, 17, 63, u64::MAX] {
+                    let expected = ((a as u128 * b as u128) % m as u128) as u64;
+                    let b = BoxedUint::from(b);
+                    assert_eq!(params.mul_mod(&a_uint, &b), BoxedUint::from(expected));
+                }
+                assert_eq!(params.square_mod(&a_uint), BoxedUint::from(a * a % m));
+            }
+        }
+    }
+
+    #[test]
+    fn even_modulus() {
+        let m = BoxedUint::from_be_hex(
+            "ffffffff00000001000000000000000000000000fffffffffffffffffffffffe",
+            256,
+        )
+        .unwrap();
+        let p = NonZero::new(m.clone()).unwrap();
+        let params = BoxedBarrettParams::new(p.clone());
+        let a = BoxedUint::from_be_hex(
+            "14bfae46f4026e97c7a3fcd889b379a5f025719911c994a594fc6c5092ac58b1",
+            256,
+        )
+        .unwrap();
+        let b = BoxedUint::max(256);
+
+        assert_eq!(params.reduce(&b), b.rem(&p));
+        assert_eq!(params.mul_mod(&a, &b), a.mul_mod(&b, &p));
+        assert_eq!(params.square_mod(&b), b.square_mod(&p));
+    }
+
+    #[test]
+    fn small_modulus_in_large_precision() {
+        let params = params(1000, 192);
+        let a = BoxedUint::max(192);
+        let p = NonZero::new(BoxedUint::from(1000u64).resize(192)).unwrap();
+        assert_eq!(params.reduce(&a), a.rem(&p));
+        assert_eq!(params.square_mod(&a), a.square_mod(&p));
+    }
+}
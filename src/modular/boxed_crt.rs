@@ -0,0 +1,181 @@
+//! Chinese Remainder Theorem reconstruction for heap-allocated integers.
+
+use super::{BoxedMontyForm, BoxedMontyParams};
+use crate::{BoxedUint, Choice, CtOption, Odd, Resize};
+use alloc::vec::Vec;
+
+/// Precomputed parameters for reconstructing a [`BoxedUint`] modulo `m_0 * m_1 * ... * m_(k-1)`
+/// from its residues modulo the pairwise coprime, odd moduli `m_i`, using Garner's algorithm.
+///
+/// Unlike [`CrtParams`](super::CrtParams), the moduli may have different precisions, and the
+/// reconstructed value has the sum of their precisions.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct BoxedCrtParams {
+    /// Montgomery parameters for each of the moduli
+    params: Vec<BoxedMontyParams>,
+    /// `m_0 * ... * m_(i-1)` for each `i`, with the precision of `product`
+    partial_products: Vec<BoxedUint>,
+    /// Garner coefficients `(m_0 * ... * m_(i-1))^-1 mod m_i` in Montgomery form
+    coefficients: Vec<BoxedUint>,
+    /// Product of all of the moduli
+    product: BoxedUint,
+}
+
+impl BoxedCrtParams {
+    /// Precompute the Garner coefficients for the given `moduli`.
+    ///
+    /// Returns `None` if the moduli are not pairwise coprime.
+    ///
+    /// This method is constant-time with respect to the moduli, but not their number or
+    /// precisions.
+    ///
+    /// # Panics
+    /// - if `moduli` is empty.
+    pub fn new(moduli: &[Odd<BoxedUint>]) -> CtOption<Self> {
+        assert!(!moduli.is_empty(), "at least one modulus is required");
+
+        let bits_precision = moduli.iter().map(|m| m.bits_precision()).sum();
+        let mut params = Vec::with_capacity(moduli.len());
+        let mut partial_products = Vec::with_capacity(moduli.len());
+        let mut coefficients = Vec::with_capacity(moduli.len());
+        let mut product = BoxedUint::one_with_precision(bits_precision);
+        let mut is_valid = Choice::TRUE;
+
+        for modulus in moduli {
+            let modulus_params = BoxedMontyParams::new(modulus.clone());
+
+            let reduced = product.rem(modulus.as_nz_ref());
+            let inverse = BoxedMontyForm::new(reduced, modulus_params.clone()).invert();
+            is_valid &= inverse.is_some();
+            let zero = BoxedMontyForm::zero(modulus_params.clone());
+            coefficients.push(inverse.unwrap_or(zero).to_montgomery());
+
+            // The product of all moduli fits in `bits_precision`, so this does not overflow
+            let next = product.wrapping_mul(modulus);
+            partial_products.push(product);
+            product = next;
+            params.push(modulus_params);
+        }
+
+        CtOption::new(
+            Self {
+                params,
+                partial_products,
+                coefficients,
+                product,
+            },
+            is_valid,
+        )
+    }
+
+    /// Returns the moduli, in the order they were provided.
+    pub fn moduli(&self) -> impl Iterator<Item = &Odd<BoxedUint>> {
+        self.params.iter().map(BoxedMontyParams::modulus)
+    }
+
+    /// Returns the product of all of the moduli.
+    pub fn product(&self) -> &BoxedUint {
+        &self.product
+    }
+
+    /// Split `value` into its residues modulo each of the moduli.
+    ///
+    /// Each residue has the precision of the corresponding modulus.
+    ///
+    /// This method is constant-time with respect to `value`.
+    pub fn split(&self, value: &BoxedUint) -> Vec<BoxedUint> {
+        let bits_precision = value.bits_precision().max(self.product.bits_precision());
+        let value = value.resize(bits_precision);
+        self.moduli()
+            .map(|modulus| value.rem(modulus.as_nz_ref()))
+            .collect()
+    }
+
+    /// Reconstruct the unique integer modulo [`Self::product`] with the given `residues` modulo each
+    /// of the moduli.
+    ///
+    /// Residues do not need to be reduced, but must have the same precision as the corresponding
+    /// modulus. The result has the precision of [`Self::product`].
+    ///
+    /// This method is constant-time with respect to the residues.
+    ///
+    /// # Panics
+    /// - if the number of residues does not match the number of moduli.
+    pub fn reconstruct(&self, residues: &[BoxedUint]) -> BoxedUint {
+        assert_eq!(
+            residues.len(),
+            self.params.len(),
+            "residue count must match the number of moduli"
+        );
+
+        let mut ret = BoxedUint::zero_with_precision(self.product.bits_precision());
+
+        for (i, residue) in residues.iter().enumerate() {
+            let params = &self.params[i];
+            // t = (r_i - x) * (m_0 * ... * m_(i-1))^-1 mod m_i
+            let ret_reduced =
+                BoxedMontyForm::new(ret.rem(params.modulus().as_nz_ref()), params.clone());
+            let diff = BoxedMontyForm::new(residue.clone(), params.clone()) - ret_reduced;
+            let coefficient =
+                BoxedMontyForm::from_montgomery(self.coefficients[i].clone(), params.clone());
+            let t = (diff * coefficient).retrieve();
+
+            // x + (m_0 * ... * m_(i-1)) * t < m_0 * ... * m_i, so none of these can overflow
+            ret = ret.wrapping_add(&self.partial_products[i].wrapping_mul(&t));
+        }
+
+        ret
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::BoxedCrtParams;
+    use crate::{BoxedUint, Odd, Resize};
+
+    fn odd(n: u64, bits_precision: u32) -> Odd<BoxedUint> {
+        BoxedUint::from(n).resize(bits_precision).to_odd().unwrap()
+    }
+
+    #[test]
+    fn small_moduli() {
+        let crt = BoxedCrtParams::new(&[odd(3, 64), odd(5, 64), odd(7, 128)]).unwrap();
+        assert_eq!(crt.product(), &BoxedUint::from(105u64).resize(256));
+
+        for x in 0..105u64 {
+            let x = BoxedUint::from(x);
+            let residues = crt.split(&x);
+            assert_eq!(residues[2].bits_precision(), 128);
+            assert_eq!(crt.reconstruct(&residues), x.resize(256));
+        }
+    }
+
+    #[test]
+    fn rsa_crt() {
+        // 2^128 - 159 and 2^128 - 173
+        let p = BoxedUint::max(128)
+            .wrapping_sub(&BoxedUint::from(158u64).resize(128))
+            .to_odd()
+            .unwrap();
+        let q = BoxedUint::max(128)
+            .wrapping_sub(&BoxedUint::from(172u64).resize(128))
+            .to_odd()
+            .unwrap();
+        let crt = BoxedCrtParams::new(&[p.clone(), q.clone()]).unwrap();
+        assert_eq!(crt.product(), &p.mul(&q));
+
+        let x = BoxedUint::from_be_hex(
+            "14bfae46f4026e97c7a3fcd889b379a5f025719911c994a594fc6c5092ac58b1",
+            256,
+        )
+        .unwrap();
+        assert_eq!(crt.reconstruct(&crt.split(&x)), x);
+    }
+
+    #[test]
+    fn not_coprime() {
+        assert!(bool::from(
+            BoxedCrtParams::new(&[odd(15, 64), odd(21, 64)]).is_none()
+        ));
+    }
+}
@@ -8,22 +8,30 @@ mod mul;
 mod neg;
 mod pow;
 mod select;
+mod sqrt;
 mod sub;
 
-use super::{MontyParams, Retrieve, div_by_2, reduction::montgomery_retrieve_inner};
-use crate::{BoxedUint, Choice, Limb, Monty, Odd, U64, Word};
+use super::{
+    MontyParams, Retrieve, div_by_2,
+    reduction::montgomery_retrieve_inner,
+    sqrt::{find_non_residue_vartime, two_adicity},
+};
+use crate::{BoxedUint, Choice, CtEq, CtOption, Limb, ModularRing, Monty, Odd, U64, Word};
 use alloc::sync::Arc;
 use mul::BoxedMontyMultiplier;
 
+#[cfg(feature = "serde")]
+use serdect::serde::{Deserialize, Deserializer, Serialize, Serializer, de::Error};
+
 #[cfg(feature = "zeroize")]
 use zeroize::Zeroize;
 
 /// Parameters to efficiently go to/from the Montgomery form for an odd modulus whose size and value
 /// are both chosen at runtime.
-#[derive(Clone, Debug, Eq, PartialEq)]
+#[derive(Clone, Debug)]
 pub struct BoxedMontyParams(Arc<BoxedMontyParamsInner>);
 
-#[derive(Clone, Debug, Eq, PartialEq)]
+#[derive(Clone, Debug)]
 struct BoxedMontyParamsInner {
     /// The constant modulus
     modulus: Odd<BoxedUint>,
@@ -36,6 +44,11 @@ struct BoxedMontyParamsInner {
     mod_inv: U64,
     /// Leading zeros in the modulus, used to choose optimized algorithms
     mod_leading_zeros: u32,
+    /// Largest `s` such that `2^s` divides `modulus - 1`, used to compute square roots
+    two_adicity: u32,
+    /// Quadratic non-residue used to compute square roots when `modulus ≡ 1 (mod 8)`, or zero if
+    /// it was not searched for
+    sqrt_non_residue: Word,
 }
 
 impl BoxedMontyParams {
@@ -59,6 +72,9 @@ impl BoxedMontyParams {
 
         let mod_leading_zeros = modulus.as_ref().leading_zeros().min(Word::BITS - 1);
 
+        // The quadratic non-residue is only searched for by `new_vartime`
+        let two_adicity = two_adicity(modulus.as_uint_ref());
+
         Self(
             BoxedMontyParamsInner {
                 modulus,
@@ -66,6 +82,8 @@ impl BoxedMontyParams {
                 r2,
                 mod_inv,
                 mod_leading_zeros,
+                two_adicity,
+                sqrt_non_residue: 0,
             }
             .into(),
         )
@@ -74,6 +92,9 @@ impl BoxedMontyParams {
     /// Instantiates a new set of [`BoxedMontyParams`] representing the given `modulus`.
     /// This version operates in variable-time with respect to the modulus.
     ///
+    /// Unlike [`BoxedMontyParams::new`], this also precomputes the quadratic non-residue needed by
+    /// [`BoxedMontyForm::sqrt`] when `modulus ≡ 1 (mod 8)`.
+    ///
     /// TODO(tarcieri): DRY out with `MontyParams::new`?
     pub fn new_vartime(modulus: Odd<BoxedUint>) -> Self {
         let bits_precision = modulus.bits_precision();
@@ -92,6 +113,15 @@ impl BoxedMontyParams {
 
         let mod_leading_zeros = modulus.as_ref().leading_zeros().min(Word::BITS - 1);
 
+        // The quadratic non-residue is only needed by the Tonelli-Shanks algorithm, i.e. when
+        // `modulus ≡ 1 (mod 8)`
+        let two_adicity = two_adicity(modulus.as_uint_ref());
+        let sqrt_non_residue = if two_adicity >= 3 {
+            find_non_residue_vartime(modulus.as_uint_ref())
+        } else {
+            0
+        };
+
         Self(
             BoxedMontyParamsInner {
                 modulus,
@@ -99,6 +129,8 @@ impl BoxedMontyParams {
                 r2,
                 mod_inv,
                 mod_leading_zeros,
+                two_adicity,
+                sqrt_non_residue,
             }
             .into(),
         )
@@ -133,8 +165,26 @@ impl BoxedMontyParams {
     pub(crate) fn mod_leading_zeros(&self) -> u32 {
         self.0.mod_leading_zeros
     }
+
+    pub(crate) fn two_adicity(&self) -> u32 {
+        self.0.two_adicity
+    }
+
+    pub(crate) fn sqrt_non_residue(&self) -> Word {
+        self.0.sqrt_non_residue
+    }
 }
 
+/// Compares the moduli, which determine all other parameters, so that parameters created by
+/// [`BoxedMontyParams::new`] and [`BoxedMontyParams::new_vartime`] are equal.
+impl PartialEq for BoxedMontyParams {
+    fn eq(&self, other: &Self) -> bool {
+        self.modulus() == other.modulus()
+    }
+}
+
+impl Eq for BoxedMontyParams {}
+
 impl<const LIMBS: usize> From<&MontyParams<LIMBS>> for BoxedMontyParams {
     fn from(params: &MontyParams<LIMBS>) -> Self {
         Self(
@@ -144,6 +194,8 @@ impl<const LIMBS: usize> From<&MontyParams<LIMBS>> for BoxedMontyParams {
                 r2: params.r2.into(),
                 mod_inv: params.mod_inv,
                 mod_leading_zeros: params.mod_leading_zeros,
+                two_adicity: params.two_adicity,
+                sqrt_non_residue: params.sqrt_non_residue,
             }
             .into(),
         )
@@ -284,6 +336,35 @@ impl Retrieve for BoxedMontyForm {
     }
 }
 
+impl ModularRing for BoxedMontyForm {
+    type Integer = BoxedUint;
+
+    fn zero_like(&self) -> Self {
+        Self::zero(self.params.clone())
+    }
+
+    fn one_like(&self) -> Self {
+        Self::one(self.params.clone())
+    }
+
+    fn new_like(&self, value: &BoxedUint) -> Self {
+        // The remainder has the precision of the modulus
+        let value = value.rem(self.params.modulus().as_nz_ref());
+        Self::new(value, self.params.clone())
+    }
+
+    fn sqrt(&self) -> CtOption<Self> {
+        self.sqrt()
+    }
+
+    fn is_square(&self) -> Choice {
+        // There is no Jacobi symbol for `BoxedUint`, so use Euler's criterion instead:
+        // `self^((p - 1) / 2)` is -1 exactly for the non-residues modulo a prime `p`
+        let exponent = self.params.modulus().as_ref().wrapping_shr_vartime(1);
+        self.pow(&exponent).ct_ne(&-self.one_like())
+    }
+}
+
 impl Monty for BoxedMontyForm {
     type Integer = BoxedUint;
     type Params = BoxedMontyParams;
@@ -341,6 +422,60 @@ impl Monty for BoxedMontyForm {
     }
 }
 
+/// Deserializes the modulus, and recomputes the other parameters from it.
+#[cfg(feature = "serde")]
+impl<'de> Deserialize<'de> for BoxedMontyParams {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        Odd::<BoxedUint>::deserialize(deserializer).map(Self::new)
+    }
+}
+
+/// Serializes the modulus, which is all that is needed to recompute the other parameters.
+#[cfg(feature = "serde")]
+impl Serialize for BoxedMontyParams {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        self.modulus().serialize(serializer)
+    }
+}
+
+/// Deserializes a `(value, modulus)` pair, where the value must be reduced and have the same
+/// precision as the modulus.
+#[cfg(feature = "serde")]
+impl<'de> Deserialize<'de> for BoxedMontyForm {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        let (value, params) = <(BoxedUint, BoxedMontyParams)>::deserialize(deserializer)?;
+        if value.bits_precision() != params.bits_precision() {
+            Err(D::Error::custom(
+                "value must have the same precision as the modulus",
+            ))
+        } else if &value >= params.modulus().as_ref() {
+            Err(D::Error::custom("value must be reduced"))
+        } else {
+            Ok(Self::new(value, params))
+        }
+    }
+}
+
+/// Serializes the canonical value along with the modulus, as a `(value, modulus)` pair.
+#[cfg(feature = "serde")]
+impl Serialize for BoxedMontyForm {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        (self.retrieve(), &self.params).serialize(serializer)
+    }
+}
+
 /// NOTE: This zeroizes the value, but _not_ the associated parameters!
 #[cfg(feature = "zeroize")]
 impl Zeroize for BoxedMontyForm {
@@ -394,4 +529,34 @@ mod tests {
 
         assert_eq!(x, four);
     }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn deserialize_validates() {
+        use crate::{BoxedUint, Odd};
+        use serdect::serde::{
+            Deserialize,
+            de::value::{Error, SeqDeserializer},
+        };
+
+        // Little-endian hex encodings, whose length determines the precision
+        let deserialize = |value: &'static str, modulus: &'static str| {
+            BoxedMontyForm::deserialize(SeqDeserializer::<_, Error>::new(
+                [value, modulus].into_iter(),
+            ))
+        };
+
+        let params = BoxedMontyParams::new(Odd::new(BoxedUint::from(13u64)).unwrap());
+        assert_eq!(
+            deserialize("0500000000000000", "0d00000000000000").unwrap(),
+            BoxedMontyForm::new(BoxedUint::from(5u64), params)
+        );
+
+        // Unreduced value
+        assert!(deserialize("0d00000000000000", "0d00000000000000").is_err());
+        // Even modulus
+        assert!(deserialize("0500000000000000", "0c00000000000000").is_err());
+        // Mismatched precisions
+        assert!(deserialize("05000000000000000000000000000000", "0d00000000000000").is_err());
+    }
 }
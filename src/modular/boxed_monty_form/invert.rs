@@ -1,7 +1,11 @@
 //! Multiplicative inverses of boxed integers in Montgomery form.
 
 use super::{BoxedMontyForm, BoxedMontyParams};
-use crate::{CtOption, Invert, modular::safegcd::boxed::BoxedSafeGcdInverter};
+use crate::{
+    Choice, CtOption, Invert,
+    modular::{batch_invert::batch_invert, safegcd::boxed::BoxedSafeGcdInverter},
+};
+use alloc::vec::Vec;
 
 impl BoxedMontyForm {
     /// Computes `self^-1` representing the multiplicative inverse of `self`,
@@ -36,6 +40,26 @@ impl BoxedMontyForm {
     }
 }
 
+impl BoxedMontyForm {
+    /// Inverts all of the `values` in place using Montgomery's trick, which takes a single modular
+    /// inversion and three multiplications per value.
+    ///
+    /// Returns a mask whose `i`-th element is truthy if `values[i]` was inverted. Zero values are
+    /// left unchanged, as are all of the values if any of them is non-zero but not invertible,
+    /// which can only happen for a composite modulus.
+    ///
+    /// This method is constant-time with respect to the values, but not their number.
+    ///
+    /// All of the values must share the same parameters.
+    pub fn batch_invert(values: &mut [Self]) -> Vec<Choice> {
+        let Some(first) = values.first() else {
+            return Vec::new();
+        };
+        let params = first.params.clone();
+        batch_invert(values, &Self::zero(params.clone()), &Self::one(params))
+    }
+}
+
 impl Invert for BoxedMontyForm {
     type Output = CtOption<Self>;
 
@@ -1,7 +1,11 @@
 //! Modular exponentiation support for [`BoxedMontyForm`].
 
-use super::BoxedMontyForm;
-use crate::{BoxedUint, PowBoundedExp, modular::pow::pow_montgomery_form_amm};
+use super::{BoxedMontyForm, mul::BoxedMontyMultiplier};
+use crate::{
+    BoxedInt, BoxedUint, CtOption, CtSelect, MultiExponentiate, MultiExponentiateBoundedExp, Pow,
+    PowBoundedExp,
+    modular::pow::{Amm, multi_exponentiate_vartime, pow_montgomery_form_amm, pow_vartime},
+};
 
 impl BoxedMontyForm {
     /// Raises to the `exponent` power.
@@ -9,6 +13,34 @@ impl BoxedMontyForm {
         self.pow_bounded_exp(exponent, exponent.bits_precision())
     }
 
+    /// Raises to the signed `exponent` power.
+    ///
+    /// If `exponent` is negative, this computes the inverse of `self` raised to `|exponent|`,
+    /// returning none if `self` is not invertible. Non-negative exponents always succeed.
+    ///
+    /// This method is constant-time with respect to both `self` and `exponent`.
+    pub fn pow_signed(&self, exponent: &BoxedInt) -> CtOption<Self> {
+        let (abs, is_negative) = exponent.abs_sign();
+        let inverse = self.invert();
+        let is_some = inverse.is_some().or(is_negative.not());
+        let base = self.ct_select(inverse.as_inner_unchecked(), is_negative);
+        CtOption::new(base.pow(&abs), is_some)
+    }
+
+    /// Raises to the `exponent` power using sliding windows.
+    ///
+    /// This method is variable-time with respect to `exponent`, and meant for public exponents.
+    pub fn pow_vartime(&self, exponent: &BoxedUint) -> Self {
+        let z = pow_vartime(
+            &self.montgomery_form,
+            exponent.as_uint_ref(),
+            || self.params.one().clone(),
+            &mut Amm(BoxedMontyMultiplier::from(&self.params)),
+        );
+
+        Self::from_amm(z, self.params.clone())
+    }
+
     /// Raises to the `exponent` power,
     /// with `exponent_bits` representing the number of (least significant) bits
     /// to take into account for the exponent.
@@ -22,8 +54,58 @@ impl BoxedMontyForm {
     }
 }
 
+impl Pow<BoxedUint> for BoxedMontyForm {
+    fn pow(&self, exponent: &BoxedUint) -> Self {
+        self.pow(exponent)
+    }
+}
+
 impl PowBoundedExp<BoxedUint> for BoxedMontyForm {
     fn pow_bounded_exp(&self, exponent: &BoxedUint, exponent_bits: u32) -> Self {
         self.pow_bounded_exp(exponent, exponent_bits)
     }
 }
+
+impl MultiExponentiate<BoxedUint, [(Self, BoxedUint)]> for BoxedMontyForm {
+    fn multi_exponentiate(bases_and_exponents: &[(Self, BoxedUint)]) -> Self {
+        let exponent_bits = bases_and_exponents
+            .iter()
+            .map(|(_, exponent)| exponent.bits_precision())
+            .max()
+            .unwrap_or(0);
+        Self::multi_exponentiate_bounded_exp(bases_and_exponents, exponent_bits)
+    }
+
+    fn multi_exponentiate_vartime(bases_and_exponents: &[(Self, BoxedUint)]) -> Self {
+        multi_exponentiate_vartime(bases_and_exponents, u32::MAX)
+    }
+}
+
+impl MultiExponentiateBoundedExp<BoxedUint, [(Self, BoxedUint)]> for BoxedMontyForm {
+    fn multi_exponentiate_bounded_exp(
+        bases_and_exponents: &[(Self, BoxedUint)],
+        exponent_bits: u32,
+    ) -> Self {
+        assert!(
+            !bases_and_exponents.is_empty(),
+            "bases_and_exponents must not be empty"
+        );
+
+        // Each of the exponentiations is constant-time, so their product is too
+        let pow = |(base, exponent): &(Self, BoxedUint)| {
+            base.pow_bounded_exp(exponent, exponent_bits.min(exponent.bits_precision()))
+        };
+        let mut ret = pow(&bases_and_exponents[0]);
+        for base_and_exponent in &bases_and_exponents[1..] {
+            ret *= pow(base_and_exponent);
+        }
+        ret
+    }
+
+    fn multi_exponentiate_bounded_exp_vartime(
+        bases_and_exponents: &[(Self, BoxedUint)],
+        exponent_bits: u32,
+    ) -> Self {
+        multi_exponentiate_vartime(bases_and_exponents, exponent_bits)
+    }
+}
@@ -29,6 +29,10 @@ impl CtSelect for BoxedMontyParamsInner {
             mod_leading_zeros: self
                 .mod_leading_zeros
                 .ct_select(&other.mod_leading_zeros, choice),
+            two_adicity: self.two_adicity.ct_select(&other.two_adicity, choice),
+            sqrt_non_residue: self
+                .sqrt_non_residue
+                .ct_select(&other.sqrt_non_residue, choice),
         }
     }
 }
@@ -0,0 +1,110 @@
+//! Square roots of boxed integers in Montgomery form.
+
+use super::BoxedMontyForm;
+use crate::{
+    CtOption,
+    modular::sqrt::{find_non_residue_vartime, sqrt},
+};
+
+#[cfg(doc)]
+use super::BoxedMontyParams;
+
+impl BoxedMontyForm {
+    /// Compute a square root of `self` modulo the prime modulus, i.e. a value `r` such that
+    /// `r * r == self`.
+    ///
+    /// Returns `None` if `self` is not a quadratic residue.
+    ///
+    /// Uses fast paths when `modulus ≡ 3 (mod 4)` or `modulus ≡ 5 (mod 8)`, and the
+    /// Tonelli-Shanks algorithm otherwise. The latter needs a quadratic non-residue modulo the
+    /// modulus, which is precomputed by [`BoxedMontyParams::new_vartime`], but searched for on
+    /// every call if the parameters were created with the constant-time [`BoxedMontyParams::new`].
+    ///
+    /// This method is constant-time with respect to `self`, but variable-time with respect to
+    /// the modulus. If the modulus is not prime, `Some` results are still valid square roots, but
+    /// `None` may be returned even if a square root exists.
+    ///
+    /// # Panics
+    /// - if `modulus ≡ 1 (mod 8)` and it has no quadratic non-residue below 1024, which can only
+    ///   happen if it is not prime.
+    pub fn sqrt(&self) -> CtOption<Self> {
+        let params = &self.params;
+        let modulus = params.modulus().as_ref();
+        sqrt(self, modulus, params.two_adicity(), || {
+            match params.sqrt_non_residue() {
+                0 => find_non_residue_vartime(modulus.as_uint_ref()),
+                non_residue => non_residue,
+            }
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{
+        BoxedUint, Resize,
+        modular::{BoxedMontyForm, BoxedMontyParams},
+    };
+
+    fn check_sqrt(modulus_hex: &str, x_hex: &str, non_residue: u64) {
+        let modulus = BoxedUint::from_be_hex(modulus_hex, 256)
+            .unwrap()
+            .to_odd()
+            .unwrap();
+
+        // With and without the precomputed quadratic non-residue
+        for params in [
+            BoxedMontyParams::new(modulus.clone()),
+            BoxedMontyParams::new_vartime(modulus),
+        ] {
+            let x =
+                BoxedMontyForm::new(BoxedUint::from_be_hex(x_hex, 256).unwrap(), params.clone());
+
+            let square = x.square();
+            let root = square.sqrt().unwrap();
+            assert_eq!(root.square(), square);
+            assert!(root == x || root == -x.clone());
+
+            let non_residue = BoxedMontyForm::new(BoxedUint::from(non_residue).resize(256), params);
+            assert!(bool::from(non_residue.sqrt().is_none()));
+        }
+    }
+
+    #[test]
+    fn sqrt_p256() {
+        // p ≡ 3 (mod 4)
+        check_sqrt(
+            "ffffffff00000001000000000000000000000000ffffffffffffffffffffffff",
+            "14BFAE46F4026E97C7A3FCD889B379A5F025719911C994A594FC6C5092AC58B1",
+            3,
+        );
+    }
+
+    #[test]
+    fn sqrt_curve25519() {
+        // p ≡ 5 (mod 8)
+        check_sqrt(
+            "7fffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffed",
+            "14BFAE46F4026E97C7A3FCD889B379A5F025719911C994A594FC6C5092AC58B1",
+            2,
+        );
+    }
+
+    #[test]
+    fn sqrt_bls12_381_scalar() {
+        // p ≡ 1 (mod 8), 2-adicity 32
+        check_sqrt(
+            "73eda753299d7d483339d80809a1d80553bda402fffe5bfeffffffff00000001",
+            "14BFAE46F4026E97C7A3FCD889B379A5F025719911C994A594FC6C5092AC58B1",
+            5,
+        );
+    }
+
+    #[test]
+    #[should_panic]
+    fn no_non_residue() {
+        // 9 ≡ 1 (mod 8) is a perfect square, so it has no quadratic non-residues
+        let params = BoxedMontyParams::new_vartime(BoxedUint::from(9u8).to_odd().unwrap());
+        let _ = BoxedMontyForm::new(BoxedUint::from(4u8), params).sqrt();
+    }
+}
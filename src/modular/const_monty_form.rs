@@ -7,16 +7,25 @@ mod mod_symbol;
 mod mul;
 mod neg;
 mod pow;
+#[cfg(feature = "ff")]
+mod prime_field;
+mod prime_params;
 mod reduce;
+mod sqrt;
 mod sub;
 
 use super::{
     MontyParams, Retrieve, div_by_2::div_by_2, mul::mul_montgomery_form,
     reduction::montgomery_retrieve,
 };
-use crate::{ConstOne, ConstZero, CtEq, Odd, One, Uint, Zero};
+use crate::{Choice, ConstOne, ConstZero, CtEq, CtOption, ModularRing, Odd, One, Uint, Zero};
 use core::{fmt::Debug, marker::PhantomData};
 
+pub use prime_params::{ConstPrimeMontyParams, PrimeParams};
+
+#[cfg(feature = "ff")]
+pub use prime_field::PrimeFieldParams;
+
 #[cfg(feature = "rand_core")]
 use crate::{Random, RandomMod, rand_core::TryRngCore};
 
@@ -136,6 +145,12 @@ impl<MOD: ConstMontyParams<LIMBS>, const LIMBS: usize> ConstMontyForm<MOD, LIMBS
     }
 }
 
+impl<MOD: ConstMontyParams<LIMBS>, const LIMBS: usize> From<u64> for ConstMontyForm<MOD, LIMBS> {
+    fn from(n: u64) -> Self {
+        Self::new(&Uint::from_u64(n))
+    }
+}
+
 impl<MOD: ConstMontyParams<LIMBS>, const LIMBS: usize> Default for ConstMontyForm<MOD, LIMBS> {
     fn default() -> Self {
         Self::ZERO
@@ -188,6 +203,30 @@ impl<MOD: ConstMontyParams<LIMBS>, const LIMBS: usize> num_traits::One
     }
 }
 
+impl<MOD: ConstMontyParams<LIMBS>, const LIMBS: usize> ModularRing for ConstMontyForm<MOD, LIMBS> {
+    type Integer = Uint<LIMBS>;
+
+    fn zero_like(&self) -> Self {
+        Self::ZERO
+    }
+
+    fn one_like(&self) -> Self {
+        Self::ONE
+    }
+
+    fn new_like(&self, value: &Uint<LIMBS>) -> Self {
+        Self::new(value)
+    }
+
+    fn sqrt(&self) -> CtOption<Self> {
+        self.sqrt()
+    }
+
+    fn is_square(&self) -> Choice {
+        self.jacobi_symbol().is_minus_one().not()
+    }
+}
+
 #[cfg(feature = "rand_core")]
 impl<MOD, const LIMBS: usize> Random for ConstMontyForm<MOD, LIMBS>
 where
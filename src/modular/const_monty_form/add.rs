@@ -2,7 +2,10 @@
 
 use super::{ConstMontyForm, ConstMontyParams};
 use crate::modular::add::{add_montgomery_form, double_montgomery_form};
-use core::ops::{Add, AddAssign};
+use core::{
+    iter::Sum,
+    ops::{Add, AddAssign},
+};
 
 impl<MOD: ConstMontyParams<LIMBS>, const LIMBS: usize> ConstMontyForm<MOD, LIMBS> {
     /// Adds `rhs`.
@@ -80,6 +83,20 @@ impl<MOD: ConstMontyParams<LIMBS>, const LIMBS: usize> AddAssign<Self>
     }
 }
 
+impl<MOD: ConstMontyParams<LIMBS>, const LIMBS: usize> Sum for ConstMontyForm<MOD, LIMBS> {
+    fn sum<I: Iterator<Item = Self>>(iter: I) -> Self {
+        iter.fold(Self::ZERO, |acc, x| acc + x)
+    }
+}
+
+impl<'a, MOD: ConstMontyParams<LIMBS>, const LIMBS: usize> Sum<&'a Self>
+    for ConstMontyForm<MOD, LIMBS>
+{
+    fn sum<I: Iterator<Item = &'a Self>>(iter: I) -> Self {
+        iter.fold(Self::ZERO, |acc, x| acc + x)
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use crate::{
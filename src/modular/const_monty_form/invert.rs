@@ -4,6 +4,12 @@ use super::{ConstMontyForm, ConstMontyParams};
 use crate::{CtOption, Invert, modular::SafeGcdInverter};
 use core::marker::PhantomData;
 
+#[cfg(feature = "alloc")]
+use {
+    crate::{Choice, modular::batch_invert::batch_invert},
+    alloc::vec::Vec,
+};
+
 impl<MOD: ConstMontyParams<LIMBS>, const LIMBS: usize> ConstMontyForm<MOD, LIMBS> {
     /// Computes `self^-1` representing the multiplicative inverse of `self`,
     /// i.e. `self * self^-1 = 1`.
@@ -76,6 +82,21 @@ impl<MOD: ConstMontyParams<LIMBS>, const LIMBS: usize> ConstMontyForm<MOD, LIMBS
     }
 }
 
+#[cfg(feature = "alloc")]
+impl<MOD: ConstMontyParams<LIMBS>, const LIMBS: usize> ConstMontyForm<MOD, LIMBS> {
+    /// Inverts all of the `values` in place using Montgomery's trick, which takes a single modular
+    /// inversion and three multiplications per value.
+    ///
+    /// Returns a mask whose `i`-th element is truthy if `values[i]` was inverted. Zero values are
+    /// left unchanged, as are all of the values if any of them is non-zero but not invertible,
+    /// which can only happen for a composite modulus.
+    ///
+    /// This method is constant-time with respect to the values, but not their number.
+    pub fn batch_invert(values: &mut [Self]) -> Vec<Choice> {
+        batch_invert(values, &Self::ZERO, &Self::ONE)
+    }
+}
+
 impl<MOD: ConstMontyParams<LIMBS>, const LIMBS: usize> Invert for ConstMontyForm<MOD, LIMBS> {
     type Output = CtOption<Self>;
 
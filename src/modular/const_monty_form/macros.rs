@@ -1,7 +1,9 @@
 //! [`ConstMontyForm`]/[`ConstMontyParams`] support macros.
 
+#[cfg(all(doc, feature = "ff"))]
+use crate::modular::PrimeFieldParams;
 #[cfg(doc)]
-use crate::modular::{ConstMontyForm, ConstMontyParams};
+use crate::modular::{ConstMontyForm, ConstMontyParams, ConstPrimeMontyParams, PrimeParams};
 
 /// Create a type representing a modulus which impls the [`ConstMontyParams`] trait with the given
 /// name, type, value (in big endian hex), and optional documentation string.
@@ -45,6 +47,140 @@ macro_rules! const_monty_params {
     };
 }
 
+/// Create a type representing a prime modulus which impls the [`ConstMontyParams`] and
+/// [`ConstPrimeMontyParams`] traits with the given name, type, value (in big endian hex), and
+/// optional documentation string.
+///
+/// The [`PrimeParams`] of the modulus, such as its 2-adicity and a primitive `2^s`-th root of
+/// unity, are computed at compile time.
+///
+/// # Usage
+///
+/// ```
+/// use crypto_bigint::{
+///     U256, const_prime_monty_params,
+///     modular::{ConstPrimeMontyParams, ConstMontyParams},
+/// };
+///
+/// const_prime_monty_params!(
+///     MyModulus,
+///     U256,
+///     "73eda753299d7d483339d80809a1d80553bda402fffe5bfeffffffff00000001",
+///     "Docs for my modulus"
+/// );
+///
+/// assert_eq!(MyModulus::PRIME_PARAMS.two_adicity(), 32);
+/// ```
+///
+/// The modulus _must_ be odd, and moduli which are found to be composite fail to compile:
+///
+/// ```compile_fail
+/// use crypto_bigint::{U64, const_prime_monty_params};
+///
+/// // 1031 * 1033
+/// const_prime_monty_params!(Composite, U64, "000000000010403F");
+/// ```
+#[macro_export]
+macro_rules! const_prime_monty_params {
+    ($name:ident, $uint_type:ty, $value:expr) => {
+        $crate::const_prime_monty_params!(
+            $name,
+            $uint_type,
+            $value,
+            "Prime modulus which impls `ConstMontyParams` and `ConstPrimeMontyParams`"
+        );
+    };
+    ($name:ident, $uint_type:ty, $value:expr, $doc:expr) => {
+        $crate::const_monty_params!($name, $uint_type, $value, $doc);
+        impl $crate::modular::ConstPrimeMontyParams<{ <$uint_type>::LIMBS }> for $name {
+            const PRIME_PARAMS: $crate::modular::PrimeParams<{ <$uint_type>::LIMBS }> =
+                $crate::modular::PrimeParams::new_vartime(
+                    &<$name as $crate::modular::ConstMontyParams<{ <$uint_type>::LIMBS }>>::PARAMS,
+                );
+        }
+        // Evaluate the constants eagerly, so that composite moduli are rejected at compile time
+        const _: () = {
+            use $crate::modular::ConstPrimeMontyParams;
+            let _ = $name::PRIME_PARAMS;
+        };
+    };
+}
+
+/// Create a type representing a prime modulus which impls the [`ConstMontyParams`],
+/// [`ConstPrimeMontyParams`] and [`PrimeFieldParams`] traits with the given name, type, value (in
+/// big endian hex), multiplicative generator, and optional documentation string.
+///
+/// [`ConstMontyForm`]s using such a modulus implement [`ff::Field`] and [`ff::PrimeField`].
+///
+/// # Usage
+///
+/// ```
+/// use crypto_bigint::{
+///     U256, const_monty_form, const_prime_field_params,
+///     ff::{Field, PrimeField},
+///     modular::ConstMontyParams,
+/// };
+///
+/// const_prime_field_params!(
+///     ScalarModulus,
+///     U256,
+///     "73eda753299d7d483339d80809a1d80553bda402fffe5bfeffffffff00000001",
+///     7,
+///     "BLS12-381 scalar field modulus"
+/// );
+/// const_monty_form!(Scalar, ScalarModulus);
+///
+/// assert_eq!(Scalar::S, 32);
+/// assert_eq!(Scalar::ROOT_OF_UNITY * Scalar::ROOT_OF_UNITY_INV, Scalar::ONE);
+///
+/// let x = Scalar::from(4u64);
+/// assert_eq!(Field::sqrt(&x).unwrap().square(), x);
+/// ```
+///
+/// The modulus _must_ be an odd prime, and the generator must generate its full multiplicative
+/// group, or this will panic. Only quadratic residues and `q`-th powers for small primes `q` are
+/// detected as non-generators.
+///
+/// For instance 5, although a quadratic non-residue modulo the BLS12-381 scalar field modulus, is
+/// a cube, so it is rejected:
+///
+/// ```compile_fail
+/// use crypto_bigint::{
+///     U256, const_monty_form, const_prime_field_params, ff::PrimeField, modular::ConstMontyParams,
+/// };
+///
+/// const_prime_field_params!(
+///     ScalarModulus,
+///     U256,
+///     "73eda753299d7d483339d80809a1d80553bda402fffe5bfeffffffff00000001",
+///     5
+/// );
+/// const_monty_form!(Scalar, ScalarModulus);
+///
+/// let _ = Scalar::MULTIPLICATIVE_GENERATOR;
+/// ```
+#[cfg(feature = "ff")]
+#[macro_export]
+macro_rules! const_prime_field_params {
+    ($name:ident, $uint_type:ty, $value:expr, $generator:expr) => {
+        $crate::const_prime_field_params!(
+            $name,
+            $uint_type,
+            $value,
+            $generator,
+            "Prime modulus which impls `ConstMontyParams`, `ConstPrimeMontyParams` and \
+             `PrimeFieldParams`"
+        );
+    };
+    ($name:ident, $uint_type:ty, $value:expr, $generator:expr, $doc:expr) => {
+        $crate::const_prime_monty_params!($name, $uint_type, $value, $doc);
+        impl $crate::modular::PrimeFieldParams<{ <$uint_type>::LIMBS }> for $name {
+            const MODULUS_HEX: &'static str = $value;
+            const MULTIPLICATIVE_GENERATOR: u64 = $generator;
+        }
+    };
+}
+
 /// Creates a type alias to [`ConstMontyForm`] with the given [`ConstMontyParams`].
 ///
 /// # Usage
@@ -1,6 +1,7 @@
 //! Multiplications between integers in Montgomery form with a constant modulus.
 
 use core::{
+    iter::Product,
     marker::PhantomData,
     ops::{Mul, MulAssign},
 };
@@ -93,6 +94,20 @@ impl<MOD: ConstMontyParams<LIMBS>, const LIMBS: usize> MulAssign<Self>
     }
 }
 
+impl<MOD: ConstMontyParams<LIMBS>, const LIMBS: usize> Product for ConstMontyForm<MOD, LIMBS> {
+    fn product<I: Iterator<Item = Self>>(iter: I) -> Self {
+        iter.fold(Self::ONE, |acc, x| acc * x)
+    }
+}
+
+impl<'a, MOD: ConstMontyParams<LIMBS>, const LIMBS: usize> Product<&'a Self>
+    for ConstMontyForm<MOD, LIMBS>
+{
+    fn product<I: Iterator<Item = &'a Self>>(iter: I) -> Self {
+        iter.fold(Self::ONE, |acc, x| acc * x)
+    }
+}
+
 impl<MOD: ConstMontyParams<LIMBS>, const LIMBS: usize> Square for ConstMontyForm<MOD, LIMBS> {
     fn square(&self) -> Self {
         ConstMontyForm::square(self)
@@ -2,8 +2,8 @@
 
 use super::{ConstMontyForm, ConstMontyParams};
 use crate::{
-    MultiExponentiateBoundedExp, PowBoundedExp, Uint,
-    modular::pow::{multi_exponentiate_montgomery_form_array, pow_montgomery_form},
+    CtOption, Int, MultiExponentiateBoundedExp, PowBoundedExp, Uint,
+    modular::pow::{multi_exponentiate_montgomery_form_array, pow_montgomery_form, pow_vartime},
 };
 
 #[cfg(feature = "alloc")]
@@ -18,6 +18,46 @@ impl<MOD: ConstMontyParams<LIMBS>, const LIMBS: usize> ConstMontyForm<MOD, LIMBS
         self.pow_bounded_exp(exponent, Uint::<RHS_LIMBS>::BITS)
     }
 
+    /// Raises to the signed `exponent` power.
+    ///
+    /// If `exponent` is negative, this computes the inverse of `self` raised to `|exponent|`,
+    /// returning none if `self` is not invertible. Non-negative exponents always succeed.
+    ///
+    /// This method is constant-time with respect to both `self` and `exponent`.
+    pub const fn pow_signed<const RHS_LIMBS: usize>(
+        &self,
+        exponent: &Int<RHS_LIMBS>,
+    ) -> CtOption<Self> {
+        let (abs, is_negative) = exponent.abs_sign();
+        let inverse = self.invert();
+        let base = Self {
+            montgomery_form: Uint::select(
+                &self.montgomery_form,
+                &inverse.as_inner_unchecked().montgomery_form,
+                is_negative,
+            ),
+            phantom: core::marker::PhantomData,
+        };
+        let is_some = inverse.is_some().or(is_negative.not());
+        CtOption::new(base.pow(&abs), is_some)
+    }
+
+    /// Raises to the `exponent` power using sliding windows.
+    ///
+    /// This method is variable-time with respect to `exponent`, and meant for public exponents.
+    pub fn pow_vartime<const RHS_LIMBS: usize>(&self, exponent: &Uint<RHS_LIMBS>) -> Self {
+        let mut params = MOD::PARAMS;
+        Self {
+            montgomery_form: pow_vartime(
+                &self.montgomery_form,
+                exponent.as_uint_ref(),
+                || MOD::PARAMS.one,
+                &mut params,
+            ),
+            phantom: core::marker::PhantomData,
+        }
+    }
+
     /// Raises to the `exponent` power,
     /// with `exponent_bits` representing the number of (least significant) bits
     /// to take into account for the exponent.
@@ -111,7 +151,8 @@ impl<MOD: ConstMontyParams<LIMBS>, const LIMBS: usize, const RHS_LIMBS: usize>
 mod tests {
     use crate::traits::MultiExponentiate;
     use crate::{
-        U256, const_monty_form, const_monty_params, modular::const_monty_form::ConstMontyParams,
+        I256, U256, const_monty_form, const_monty_params,
+        modular::const_monty_form::ConstMontyParams,
     };
 
     const_monty_params!(
@@ -168,6 +209,44 @@ mod tests {
         assert_eq!(res.retrieve(), expected);
     }
 
+    #[test]
+    fn test_pow_vartime() {
+        let base =
+            U256::from_be_hex("3435D18AA8313EBBE4D20002922225B53F75DC4453BB3EEC0378646F79B524A4");
+        let base_mod = Fe::new(&base);
+
+        for exponent in [
+            U256::ZERO,
+            U256::ONE,
+            U256::from(2u8),
+            U256::from(65537u32),
+            U256::from(105u64),
+            U256::MAX,
+            U256::from_be_hex("77117F1273373C26C700D076B3F780074D03339F56DD0EFB60E7F58441FD3685"),
+        ] {
+            assert_eq!(base_mod.pow_vartime(&exponent), base_mod.pow(&exponent));
+        }
+    }
+
+    #[test]
+    fn test_pow_signed() {
+        let base =
+            U256::from_be_hex("3435D18AA8313EBBE4D20002922225B53F75DC4453BB3EEC0378646F79B524A4");
+        let base_mod = Fe::new(&base);
+        let exponent = I256::from_i64(65537);
+
+        let positive = base_mod.pow_signed(&exponent).unwrap();
+        assert_eq!(positive, base_mod.pow(exponent.as_uint()));
+
+        let negative = base_mod.pow_signed(&exponent.wrapping_neg()).unwrap();
+        assert_eq!(negative * positive, Fe::ONE);
+        assert_eq!(negative, base_mod.invert().unwrap().pow(exponent.as_uint()));
+
+        assert_eq!(base_mod.pow_signed(&I256::ZERO).unwrap(), Fe::ONE);
+        assert_eq!(Fe::ZERO.pow_signed(&exponent).unwrap(), Fe::ZERO);
+        assert!(Fe::ZERO.pow_signed(&I256::MINUS_ONE).is_none().to_bool());
+    }
+
     #[test]
     fn test_multi_exp_array() {
         let base = U256::from(2u8);
@@ -0,0 +1,275 @@
+//! Implementations of the [`ff`] traits for [`ConstMontyForm`] with a prime modulus.
+
+use super::{ConstMontyForm, ConstPrimeMontyParams};
+use crate::{
+    CtLt, EncodedUint, Encoding, Uint,
+    primality::{SMALL_PRIME_RECIPROCALS, SMALL_PRIMES},
+};
+use ff::{Field, PrimeField, helpers::sqrt_ratio_generic};
+use ff_rand_core::RngCore;
+use subtle::{Choice, CtOption};
+
+/// Parameters of a prime field with a modulus fixed at compile-time.
+///
+/// When `MOD` implements this trait, [`ConstMontyForm<MOD, LIMBS>`] implements [`ff::Field`] and
+/// [`ff::PrimeField`], with the remaining field constants derived at compile-time. Field elements
+/// are encoded in little endian order.
+///
+/// This trait can be implemented with the
+/// [`const_prime_field_params!`][`crate::const_prime_field_params`] macro.
+pub trait PrimeFieldParams<const LIMBS: usize>: ConstPrimeMontyParams<LIMBS> {
+    /// Modulus of the field in big endian hex, as returned by [`PrimeField::MODULUS`].
+    const MODULUS_HEX: &'static str;
+
+    /// A generator of the multiplicative group of the field, i.e. an element of order
+    /// `modulus - 1`, as required by [`PrimeField::MULTIPLICATIVE_GENERATOR`].
+    ///
+    /// Being a quadratic non-residue is not enough: the derived [`PrimeField::DELTA`] only
+    /// generates the subgroup of odd order `t`, where `modulus - 1 = 2^s * t`, if this generates
+    /// the full group. Only the absence of small prime factors from its order is checked at
+    /// compile time.
+    const MULTIPLICATIVE_GENERATOR: u64;
+}
+
+impl<MOD: PrimeFieldParams<LIMBS>, const LIMBS: usize> ConstMontyForm<MOD, LIMBS> {
+    /// The `t` such that `2^s * t = modulus - 1` with `t` odd.
+    const T: Uint<LIMBS> = MOD::PARAMS
+        .modulus
+        .as_ref()
+        .shr_vartime(MOD::PRIME_PARAMS.two_adicity());
+
+    /// [`PrimeFieldParams::MULTIPLICATIVE_GENERATOR`], checked to be a quadratic non-residue and
+    /// not to be a `q`-th power for any small odd prime `q` dividing `modulus - 1`.
+    const GENERATOR: Self = {
+        let generator = Uint::from_u64(MOD::MULTIPLICATIVE_GENERATOR);
+        let symbol = generator.jacobi_symbol_vartime(&MOD::PARAMS.modulus);
+        assert!(
+            symbol.is_minus_one().to_bool_vartime(),
+            "multiplicative generator must be a quadratic non-residue"
+        );
+        let generator = Self::new(&generator);
+
+        // A generator `g` of the full group has `g^((p - 1) / q) != 1` for every prime `q`
+        // dividing `p - 1`, and the odd ones divide `t`
+        let modulus_minus_one = MOD::PARAMS.modulus.as_ref().wrapping_sub(&Uint::ONE);
+        let mut i = 0;
+        while i < SMALL_PRIMES.len() {
+            let reciprocal = &SMALL_PRIME_RECIPROCALS[i];
+            if Self::T
+                .rem_limb_with_reciprocal(reciprocal)
+                .is_zero()
+                .to_bool_vartime()
+            {
+                let (exponent, _) = modulus_minus_one.div_rem_limb_with_reciprocal(reciprocal);
+                let x = generator.pow(&exponent);
+                assert!(
+                    !Uint::eq(x.as_montgomery(), Self::ONE.as_montgomery()).to_bool_vartime(),
+                    "multiplicative generator must generate the full multiplicative group"
+                );
+            }
+            i += 1;
+        }
+
+        generator
+    };
+
+    /// A primitive `2^s`-th root of unity, `generator^t`.
+    const ROOT_OF_UNITY: Self = Self::GENERATOR.pow(&Self::T);
+
+    /// The generator of the `t`-order multiplicative subgroup, `generator^(2^s)`.
+    const DELTA: Self = {
+        let mut delta = Self::GENERATOR;
+        let mut i = 0;
+        while i < MOD::PRIME_PARAMS.two_adicity() {
+            delta = delta.square();
+            i += 1;
+        }
+        delta
+    };
+}
+
+impl<MOD: PrimeFieldParams<LIMBS>, const LIMBS: usize> Field for ConstMontyForm<MOD, LIMBS> {
+    const ZERO: Self = Self::ZERO;
+    const ONE: Self = Self::ONE;
+
+    fn random(mut rng: impl RngCore) -> Self {
+        let modulus = MOD::PARAMS.modulus.as_ref();
+        let mut bytes = EncodedUint::<LIMBS>::default();
+
+        loop {
+            rng.fill_bytes(bytes.as_mut());
+            let mut n = Uint::from_le_bytes(bytes);
+            n.as_mut_uint_ref().restrict_bits(Self::NUM_BITS);
+
+            if n.ct_lt(modulus).to_bool_vartime() {
+                return Self::new(&n);
+            }
+        }
+    }
+
+    fn square(&self) -> Self {
+        self.square()
+    }
+
+    fn double(&self) -> Self {
+        self.double()
+    }
+
+    fn invert(&self) -> CtOption<Self> {
+        self.invert().into()
+    }
+
+    fn sqrt_ratio(num: &Self, div: &Self) -> (Choice, Self) {
+        sqrt_ratio_generic(num, div)
+    }
+
+    fn sqrt(&self) -> CtOption<Self> {
+        self.sqrt().into()
+    }
+}
+
+impl<MOD: PrimeFieldParams<LIMBS>, const LIMBS: usize> PrimeField for ConstMontyForm<MOD, LIMBS> {
+    type Repr = EncodedUint<LIMBS>;
+
+    fn from_repr(repr: Self::Repr) -> CtOption<Self> {
+        let n = Uint::from_le_bytes(repr);
+        let is_some = n.ct_lt(MOD::PARAMS.modulus.as_ref());
+        CtOption::new(Self::new(&n), is_some.into())
+    }
+
+    fn to_repr(&self) -> Self::Repr {
+        self.retrieve().to_le_bytes()
+    }
+
+    fn is_odd(&self) -> Choice {
+        self.retrieve().is_odd().into()
+    }
+
+    const MODULUS: &'static str = MOD::MODULUS_HEX;
+    const NUM_BITS: u32 = MOD::PARAMS.modulus.as_ref().bits_vartime();
+    const CAPACITY: u32 = Self::NUM_BITS - 1;
+    const TWO_INV: Self = Self::ONE.div_by_2();
+    const MULTIPLICATIVE_GENERATOR: Self = Self::GENERATOR;
+    const S: u32 = MOD::PRIME_PARAMS.two_adicity();
+    const ROOT_OF_UNITY: Self = Self::ROOT_OF_UNITY;
+    const ROOT_OF_UNITY_INV: Self = Self::ROOT_OF_UNITY
+        .invert_vartime()
+        .expect_copied("root of unity is invertible");
+    const DELTA: Self = Self::DELTA;
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{U256, const_monty_form, const_prime_field_params, modular::ConstMontyParams};
+    use ff::{Field, PrimeField};
+    use ff_rand_core::{RngCore, impls};
+
+    // BLS12-381 scalar field
+    const_prime_field_params!(
+        Modulus,
+        U256,
+        "73eda753299d7d483339d80809a1d80553bda402fffe5bfeffffffff00000001",
+        7
+    );
+
+    const_monty_form!(Fe, Modulus);
+
+    /// Minimal xorshift RNG implementing the `rand_core` 0.6 API used by `ff`.
+    struct XorShiftRng(u64);
+
+    impl RngCore for XorShiftRng {
+        fn next_u32(&mut self) -> u32 {
+            self.next_u64() as u32
+        }
+
+        fn next_u64(&mut self) -> u64 {
+            self.0 ^= self.0 << 13;
+            self.0 ^= self.0 >> 7;
+            self.0 ^= self.0 << 17;
+            self.0
+        }
+
+        fn fill_bytes(&mut self, dest: &mut [u8]) {
+            impls::fill_bytes_via_next(self, dest)
+        }
+
+        fn try_fill_bytes(&mut self, dest: &mut [u8]) -> Result<(), ff_rand_core::Error> {
+            self.fill_bytes(dest);
+            Ok(())
+        }
+    }
+
+    #[test]
+    fn constants() {
+        assert_eq!(Fe::NUM_BITS, 255);
+        assert_eq!(Fe::CAPACITY, 254);
+        assert_eq!(Fe::S, 32);
+        assert_eq!(Fe::MULTIPLICATIVE_GENERATOR, Fe::from(7u64));
+        assert_eq!(Fe::TWO_INV.double(), Fe::ONE);
+        assert_eq!(Fe::ROOT_OF_UNITY * Fe::ROOT_OF_UNITY_INV, Fe::ONE);
+
+        let mut root = Fe::ROOT_OF_UNITY;
+        for _ in 0..Fe::S - 1 {
+            root = Field::square(&root);
+            assert_ne!(root, Fe::ONE);
+        }
+        assert_eq!(Field::square(&root), Fe::ONE);
+
+        let mut delta = Fe::MULTIPLICATIVE_GENERATOR;
+        for _ in 0..Fe::S {
+            delta = Field::square(&delta);
+        }
+        assert_eq!(Fe::DELTA, delta);
+    }
+
+    #[test]
+    fn repr_roundtrip() {
+        let x = Fe::from(0x0123_4567_89ab_cdefu64);
+        let repr = x.to_repr();
+        assert_eq!(repr.as_ref()[..8], 0x0123_4567_89ab_cdefu64.to_le_bytes());
+        assert_eq!(Fe::from_repr(repr).unwrap(), x);
+        assert!(bool::from(x.is_odd()));
+        assert!(bool::from((x + Fe::ONE).is_even()));
+    }
+
+    #[test]
+    fn from_repr_rejects_unreduced() {
+        let modulus = U256::from_be_hex(<Fe as PrimeField>::MODULUS);
+        assert!(bool::from(Fe::from_repr(modulus.to_le_bytes()).is_none()));
+        let max = U256::MAX;
+        assert!(bool::from(Fe::from_repr(max.to_le_bytes()).is_none()));
+    }
+
+    #[test]
+    fn sqrt() {
+        let x = Fe::from(9u64);
+        let root = Field::sqrt(&x).unwrap();
+        assert_eq!(Field::square(&root), x);
+        assert!(bool::from(
+            Field::sqrt(&Fe::MULTIPLICATIVE_GENERATOR).is_none()
+        ));
+
+        let (is_square, root) = Fe::sqrt_ratio(&Fe::from(18u64), &Fe::from(2u64));
+        assert!(bool::from(is_square));
+        assert_eq!(Field::square(&root), x);
+
+        let (is_square, _) = Fe::sqrt_ratio(&Fe::MULTIPLICATIVE_GENERATOR, &Fe::ONE);
+        assert!(!bool::from(is_square));
+    }
+
+    #[test]
+    fn random() {
+        let mut rng = XorShiftRng(0x2545_f491_4f6c_dd1d);
+        let a = Fe::random(&mut rng);
+        let b = Fe::random(&mut rng);
+        assert_ne!(a, b);
+        assert_eq!(Fe::from_repr(a.to_repr()).unwrap(), a);
+    }
+
+    #[test]
+    fn sum_product() {
+        let xs = [Fe::from(2u64), Fe::from(3u64), Fe::from(5u64)];
+        assert_eq!(xs.iter().sum::<Fe>(), Fe::from(10u64));
+        assert_eq!(xs.into_iter().product::<Fe>(), Fe::from(30u64));
+    }
+}
@@ -0,0 +1,244 @@
+//! Constants of prime moduli, derived at compile time.
+
+use super::ConstMontyParams;
+use crate::{
+    Uint, Word,
+    modular::{
+        MontyForm, MontyParams,
+        sqrt::{find_non_residue_vartime, two_adicity},
+    },
+    primality::{SMALL_PRIME_RECIPROCALS, SMALL_PRIMES},
+};
+
+/// Trait representing a prime modulus along with its [`PrimeParams`].
+///
+/// To define a type which impls this trait, use the
+/// [`const_prime_monty_params!`][`crate::const_prime_monty_params`] macro, which computes the
+/// constants at compile time and rejects moduli which are obviously composite.
+pub trait ConstPrimeMontyParams<const LIMBS: usize>: ConstMontyParams<LIMBS> {
+    /// Prime field constants of the modulus.
+    const PRIME_PARAMS: PrimeParams<LIMBS>;
+}
+
+/// Constants of an odd prime modulus `p` commonly needed for prime field arithmetic, e.g. to
+/// compute square roots or number-theoretic transforms.
+///
+/// All field elements are stored in canonical form, i.e. not in Montgomery form.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct PrimeParams<const LIMBS: usize> {
+    /// Largest `s` such that `2^s` divides `p - 1`
+    two_adicity: u32,
+    /// Smallest quadratic non-residue modulo `p`
+    non_residue: Uint<LIMBS>,
+    /// Primitive `2^s`-th root of unity, `non_residue^((p - 1) / 2^s)`
+    root_of_unity: Uint<LIMBS>,
+    /// `(p - 1) / 2`
+    modulus_minus_one_div_2: Uint<LIMBS>,
+    /// `(p + 1) / 4`, rounded down
+    modulus_plus_one_div_4: Uint<LIMBS>,
+}
+
+impl<const LIMBS: usize> PrimeParams<LIMBS> {
+    /// Computes the constants of the modulus of `params`.
+    ///
+    /// This performs trial division by the primes below 1024 and a single Euler test with the
+    /// quadratic non-residue, which is enough to reject obviously composite moduli but is not a
+    /// proof of primality.
+    ///
+    /// # Panics
+    /// - if the modulus is found to be composite.
+    /// - if the modulus has no quadratic non-residue below the search bound of 1024.
+    pub const fn new_vartime(params: &MontyParams<LIMBS>) -> Self {
+        let modulus = params.modulus.as_ref();
+        assert!(
+            !has_small_factor(modulus),
+            "modulus is composite: it has a small prime factor"
+        );
+
+        // Perfect squares have no quadratic non-residues, and for primes the smallest one is
+        // almost always tiny
+        let non_residue = find_non_residue_vartime(modulus.as_uint_ref());
+        assert!(
+            non_residue != 0,
+            "no quadratic non-residue below the search bound"
+        );
+        let non_residue = MontyForm::new(&Uint::from_word(non_residue), *params);
+
+        let two_adicity = two_adicity(modulus.as_uint_ref());
+        let t = modulus.shr_vartime(two_adicity);
+        let root_of_unity = non_residue.pow(&t);
+
+        // Euler's criterion: `non_residue^((p - 1) / 2) = root_of_unity^(2^(s - 1))` must be -1
+        // modulo a prime, which also ensures `root_of_unity` is a primitive `2^s`-th root of unity.
+        let mut x = root_of_unity;
+        let mut i = 1;
+        while i < two_adicity {
+            x = x.square();
+            i += 1;
+        }
+        assert!(
+            Uint::eq(&x.neg().retrieve(), &Uint::ONE).to_bool_vartime(),
+            "modulus is composite: it fails Euler's criterion"
+        );
+
+        let modulus_minus_one_div_2 = modulus.shr_vartime(1);
+        let modulus_plus_one_div_4 = modulus_minus_one_div_2
+            .wrapping_add(&Uint::ONE)
+            .shr_vartime(1);
+
+        Self {
+            two_adicity,
+            non_residue: non_residue.retrieve(),
+            root_of_unity: root_of_unity.retrieve(),
+            modulus_minus_one_div_2,
+            modulus_plus_one_div_4,
+        }
+    }
+
+    /// Largest `s` such that `2^s` divides `p - 1`.
+    pub const fn two_adicity(&self) -> u32 {
+        self.two_adicity
+    }
+
+    /// Smallest quadratic non-residue modulo `p`.
+    pub const fn non_residue(&self) -> &Uint<LIMBS> {
+        &self.non_residue
+    }
+
+    /// Primitive `2^s`-th root of unity modulo `p`, where `s` is the
+    /// [two-adicity](Self::two_adicity).
+    pub const fn root_of_unity(&self) -> &Uint<LIMBS> {
+        &self.root_of_unity
+    }
+
+    /// `(p - 1) / 2`, the exponent used by Euler's criterion.
+    pub const fn modulus_minus_one_div_2(&self) -> &Uint<LIMBS> {
+        &self.modulus_minus_one_div_2
+    }
+
+    /// `(p + 1) / 4` rounded down, the exponent computing square roots when `p ≡ 3 (mod 4)`.
+    pub const fn modulus_plus_one_div_4(&self) -> &Uint<LIMBS> {
+        &self.modulus_plus_one_div_4
+    }
+}
+
+/// Returns `true` if `modulus` is divisible by one of the [`SMALL_PRIMES`] other than itself.
+const fn has_small_factor<const LIMBS: usize>(modulus: &Uint<LIMBS>) -> bool {
+    let mut i = 0;
+    while i < SMALL_PRIMES.len() {
+        if modulus
+            .rem_limb_with_reciprocal(&SMALL_PRIME_RECIPROCALS[i])
+            .is_zero()
+            .to_bool_vartime()
+        {
+            let prime = Uint::from_word(SMALL_PRIMES[i] as Word);
+            return !Uint::eq(modulus, &prime).to_bool_vartime();
+        }
+        i += 1;
+    }
+    false
+}
+
+#[cfg(test)]
+mod tests {
+    use super::PrimeParams;
+    use crate::{
+        Odd, U64, U256, const_monty_form, const_prime_monty_params,
+        modular::{ConstMontyParams, ConstPrimeMontyParams, MontyForm, MontyParams},
+    };
+
+    // BLS12-381 scalar field, p ≡ 1 (mod 2^32)
+    const_prime_monty_params!(
+        Bls12381Scalar,
+        U256,
+        "73eda753299d7d483339d80809a1d80553bda402fffe5bfeffffffff00000001"
+    );
+    const_monty_form!(Fe, Bls12381Scalar);
+
+    // secp256k1 base field, p ≡ 3 (mod 4)
+    const_prime_monty_params!(
+        Secp256k1,
+        U256,
+        "fffffffffffffffffffffffffffffffffffffffffffffffffffffffefffffc2f"
+    );
+    const_monty_form!(Fp, Secp256k1);
+
+    fn params(modulus: u64) -> PrimeParams<{ U64::LIMBS }> {
+        PrimeParams::new_vartime(&MontyParams::new_vartime(
+            Odd::new(U64::from_u64(modulus)).unwrap(),
+        ))
+    }
+
+    #[test]
+    fn two_adic_modulus() {
+        let params = Bls12381Scalar::PRIME_PARAMS;
+        assert_eq!(params.two_adicity(), 32);
+        assert_eq!(params.non_residue(), &U256::from(5u8));
+        assert_eq!(
+            params.modulus_minus_one_div_2().shl_vartime(1),
+            Fe::MODULUS.wrapping_sub(&U256::ONE)
+        );
+
+        let root = Fe::new(params.root_of_unity());
+        let mut x = root;
+        for _ in 1..params.two_adicity() {
+            x = x.square();
+        }
+        assert_eq!(x, -Fe::ONE);
+        assert_eq!(x.square(), Fe::ONE);
+
+        let non_residue = Fe::new(params.non_residue());
+        assert_eq!(
+            non_residue.pow_vartime(params.modulus_minus_one_div_2()),
+            -Fe::ONE
+        );
+    }
+
+    #[test]
+    fn modulus_3_mod_4() {
+        let params = Secp256k1::PRIME_PARAMS;
+        assert_eq!(params.two_adicity(), 1);
+        assert_eq!(Fp::new(params.root_of_unity()), -Fp::ONE);
+
+        let x = Fp::new(&U256::from(1234567u32));
+        let root = x.square().pow_vartime(params.modulus_plus_one_div_4());
+        assert!(root == x || root == -x);
+    }
+
+    #[test]
+    fn small_moduli() {
+        for (modulus, two_adicity, non_residue) in [(3, 1, 2), (5, 2, 2), (17, 4, 3), (1021, 2, 2)]
+        {
+            let params = params(modulus);
+            assert_eq!(params.two_adicity(), two_adicity);
+            assert_eq!(params.non_residue(), &U64::from_u64(non_residue));
+            assert_eq!(
+                params.modulus_plus_one_div_4(),
+                &U64::from_u64((modulus + 1) / 4)
+            );
+
+            let monty_params = MontyParams::new_vartime(Odd::new(U64::from_u64(modulus)).unwrap());
+            let root = MontyForm::new(params.root_of_unity(), monty_params);
+            let order = root.pow_vartime(&U64::ONE.shl_vartime(two_adicity));
+            assert_eq!(order.retrieve(), U64::ONE);
+        }
+    }
+
+    #[test]
+    #[should_panic]
+    fn small_factor() {
+        params(3 * 1031);
+    }
+
+    #[test]
+    #[should_panic]
+    fn perfect_square() {
+        params(1031 * 1031);
+    }
+
+    #[test]
+    #[should_panic]
+    fn euler_criterion() {
+        params(1031 * 1033);
+    }
+}
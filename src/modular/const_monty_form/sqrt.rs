@@ -0,0 +1,85 @@
+//! Square roots of integers in Montgomery form with a constant modulus.
+
+use super::{ConstMontyForm, ConstMontyParams};
+use crate::{
+    CtOption, Word,
+    modular::{
+        MontyForm,
+        sqrt::{find_non_residue_vartime, sqrt, two_adicity},
+    },
+};
+
+impl<MOD: ConstMontyParams<LIMBS>, const LIMBS: usize> ConstMontyForm<MOD, LIMBS> {
+    /// Largest `s` such that `2^s` divides `modulus - 1`.
+    const SQRT_TWO_ADICITY: u32 = two_adicity(MOD::PARAMS.modulus.as_ref().as_uint_ref());
+
+    /// Smallest quadratic non-residue modulo `modulus` (zero if none was found).
+    const SQRT_NON_RESIDUE: Word =
+        find_non_residue_vartime(MOD::PARAMS.modulus.as_ref().as_uint_ref());
+
+    /// Compute a square root of `self` modulo the prime modulus, i.e. a value `r` such that
+    /// `r * r == self`.
+    ///
+    /// Returns `None` if `self` is not a quadratic residue.
+    ///
+    /// The 2-adicity of the modulus and the quadratic non-residue used by the Tonelli-Shanks
+    /// algorithm are computed at compile time.
+    ///
+    /// This method is constant-time with respect to `self`.
+    ///
+    /// # Panics
+    /// - if `modulus ≡ 1 (mod 8)` and it has no quadratic non-residue below 1024, which can only
+    ///   happen if it is not prime.
+    pub fn sqrt(&self) -> CtOption<Self> {
+        let value = MontyForm::from(self);
+        sqrt(
+            &value,
+            MOD::PARAMS.modulus.as_ref(),
+            Self::SQRT_TWO_ADICITY,
+            || Self::SQRT_NON_RESIDUE,
+        )
+        .map(|root| Self::from_montgomery(root.to_montgomery()))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::ConstMontyParams;
+    use crate::{U256, const_monty_form, const_monty_params};
+
+    const_monty_params!(
+        Bls12381Scalar,
+        U256,
+        "73eda753299d7d483339d80809a1d80553bda402fffe5bfeffffffff00000001"
+    );
+    const_monty_form!(Fe, Bls12381Scalar);
+
+    const_monty_params!(
+        Curve25519,
+        U256,
+        "7fffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffed"
+    );
+    const_monty_form!(Fe25519, Curve25519);
+
+    #[test]
+    fn sqrt_1_mod_8() {
+        let x = Fe::new(&U256::from_be_hex(
+            "14BFAE46F4026E97C7A3FCD889B379A5F025719911C994A594FC6C5092AC58B1",
+        ));
+        let square = x.square();
+        let root = square.sqrt().unwrap();
+        assert!(root == x || root == -x);
+        assert!(bool::from(Fe::new(&U256::from(5u8)).sqrt().is_none()));
+    }
+
+    #[test]
+    fn sqrt_5_mod_8() {
+        let x = Fe25519::new(&U256::from_be_hex(
+            "14BFAE46F4026E97C7A3FCD889B379A5F025719911C994A594FC6C5092AC58B1",
+        ));
+        let square = x.square();
+        let root = square.sqrt().unwrap();
+        assert!(root == x || root == -x);
+        assert!(bool::from(Fe25519::new(&U256::from(2u8)).sqrt().is_none()));
+    }
+}
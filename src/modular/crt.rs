@@ -0,0 +1,176 @@
+//! Chinese Remainder Theorem reconstruction for integers with a fixed number of limbs.
+
+use super::{MontyForm, MontyParams};
+use crate::{Choice, CtOption, Odd, Uint};
+
+/// Precomputed parameters for reconstructing an integer modulo `m_0 * m_1 * ... * m_(N-1)` from
+/// its residues modulo the pairwise coprime, odd moduli `m_i`, using Garner's algorithm.
+///
+/// The moduli and their product all share the same `Uint<LIMBS>` type, so e.g. RSA-CRT with
+/// a 2048-bit modulus uses `CrtParams<{ U2048::LIMBS }, 2>` with the two 1024-bit primes.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct CrtParams<const LIMBS: usize, const N: usize> {
+    /// Montgomery parameters for each of the moduli
+    params: [MontyParams<LIMBS>; N],
+    /// `m_0 * ... * m_(i-1)` for each `i`
+    partial_products: [Uint<LIMBS>; N],
+    /// Garner coefficients `(m_0 * ... * m_(i-1))^-1 mod m_i` in Montgomery form
+    coefficients: [Uint<LIMBS>; N],
+    /// Product of all of the moduli
+    product: Uint<LIMBS>,
+}
+
+impl<const LIMBS: usize, const N: usize> CrtParams<LIMBS, N> {
+    /// Precompute the Garner coefficients for the given `moduli`.
+    ///
+    /// Returns `None` if the moduli are not pairwise coprime, or if their product does not fit in
+    /// `Uint<LIMBS>`.
+    ///
+    /// This method is constant-time with respect to the moduli.
+    pub fn new(moduli: [Odd<Uint<LIMBS>>; N]) -> CtOption<Self> {
+        let params = moduli.map(MontyParams::new);
+        let mut partial_products = [Uint::ONE; N];
+        let mut coefficients = [Uint::ZERO; N];
+        let mut product = Uint::ONE;
+        let mut is_valid = Choice::TRUE;
+
+        for i in 0..N {
+            partial_products[i] = product;
+
+            let inverse = MontyForm::new(&product, params[i]).invert();
+            is_valid &= inverse.is_some();
+            coefficients[i] = inverse
+                .unwrap_or(MontyForm::zero(params[i]))
+                .to_montgomery();
+
+            let (lo, hi) = product.widening_mul(moduli[i].as_ref());
+            is_valid &= hi.is_zero();
+            product = lo;
+        }
+
+        CtOption::new(
+            Self {
+                params,
+                partial_products,
+                coefficients,
+                product,
+            },
+            is_valid,
+        )
+    }
+
+    /// Returns the `i`-th modulus.
+    ///
+    /// # Panics
+    /// - if `i >= N`.
+    pub const fn modulus(&self, i: usize) -> &Odd<Uint<LIMBS>> {
+        self.params[i].modulus()
+    }
+
+    /// Returns the product of all of the moduli.
+    pub const fn product(&self) -> &Uint<LIMBS> {
+        &self.product
+    }
+
+    /// Split `value` into its residues modulo each of the moduli.
+    ///
+    /// This method is constant-time with respect to `value`.
+    pub fn split(&self, value: &Uint<LIMBS>) -> [Uint<LIMBS>; N] {
+        self.params
+            .map(|params| value.rem(params.modulus().as_nz_ref()))
+    }
+
+    /// Reconstruct the unique integer modulo [`Self::product`] with the given `residues` modulo each
+    /// of the moduli.
+    ///
+    /// Residues do not need to be reduced.
+    ///
+    /// This method is constant-time with respect to the residues.
+    pub fn reconstruct(&self, residues: &[Uint<LIMBS>; N]) -> Uint<LIMBS> {
+        let mut ret = Uint::ZERO;
+
+        for (i, residue) in residues.iter().enumerate() {
+            let params = self.params[i];
+            // t = (r_i - x) * (m_0 * ... * m_(i-1))^-1 mod m_i
+            let diff = MontyForm::new(residue, params) - MontyForm::new(&ret, params);
+            let t = diff * MontyForm::from_montgomery(self.coefficients[i], params);
+
+            // x + (m_0 * ... * m_(i-1)) * t < m_0 * ... * m_i, so none of these can overflow
+            ret = ret.wrapping_add(&self.partial_products[i].wrapping_mul(&t.retrieve()));
+        }
+
+        ret
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::CrtParams;
+    use crate::{
+        Odd, U64, U128, U256, Uint,
+        modular::{MontyForm, MontyParams},
+    };
+
+    fn odd<const LIMBS: usize>(n: u64) -> Odd<Uint<LIMBS>> {
+        Odd::new(Uint::from_u64(n)).unwrap()
+    }
+
+    #[test]
+    fn small_moduli() {
+        let crt = CrtParams::<{ U64::LIMBS }, 3>::new([odd(3), odd(5), odd(7)]).unwrap();
+        assert_eq!(crt.product(), &U64::from_u64(105));
+
+        for x in 0..105 {
+            let x = U64::from_u64(x);
+            let residues = crt.split(&x);
+            assert_eq!(residues[1], x.rem_vartime(crt.modulus(1).as_nz_ref()));
+            assert_eq!(crt.reconstruct(&residues), x);
+        }
+
+        // Unreduced residues
+        let residues = [U64::from_u64(5), U64::from_u64(8), U64::from_u64(100)];
+        assert_eq!(crt.reconstruct(&residues), U64::from_u64(23));
+    }
+
+    #[test]
+    fn rsa_crt() {
+        // 2^128 - 159 and 2^128 - 173
+        let p = Odd::<U256>::from_be_hex(
+            "00000000000000000000000000000000ffffffffffffffffffffffffffffff61",
+        );
+        let q = Odd::<U256>::from_be_hex(
+            "00000000000000000000000000000000ffffffffffffffffffffffffffffff53",
+        );
+        let crt = CrtParams::new([p, q]).unwrap();
+        let n = p.as_ref().wrapping_mul(q.as_ref());
+        assert_eq!(crt.product(), &n);
+
+        let x =
+            U256::from_be_hex("14bfae46f4026e97c7a3fcd889b379a5f025719911c994a594fc6c5092ac58b1");
+        let [xp, xq] = crt.split(&x);
+        assert_eq!(crt.reconstruct(&[xp, xq]), x);
+
+        // Exponentiation modulo each prime, as done for RSA-CRT decryption
+        let e = U256::from_u32(65537);
+        let yp = MontyForm::new(&xp, MontyParams::new(p)).pow(&e).retrieve();
+        let yq = MontyForm::new(&xq, MontyParams::new(q)).pow(&e).retrieve();
+        let expected = MontyForm::new(&x, MontyParams::new(n.to_odd().unwrap()))
+            .pow(&e)
+            .retrieve();
+        assert_eq!(crt.reconstruct(&[yp, yq]), expected);
+    }
+
+    #[test]
+    fn invalid_moduli() {
+        // Not coprime
+        assert!(bool::from(
+            CrtParams::<{ U64::LIMBS }, 2>::new([odd(15), odd(21)]).is_none()
+        ));
+
+        // Product overflows
+        let big = Odd::new(U128::MAX).unwrap();
+        assert!(bool::from(
+            CrtParams::new([big, Odd::new(U128::from_u8(3)).unwrap()]).is_none()
+        ));
+    }
+}
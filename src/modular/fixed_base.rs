@@ -0,0 +1,293 @@
+//! Fixed-base exponentiation using precomputed comb tables.
+
+use crate::{BitOps, Choice, Monty, MontyMultiplier};
+use alloc::vec::Vec;
+
+/// Largest supported window size.
+const MAX_WINDOW: u32 = 16;
+
+/// Approximate cost of a constant-time lookup of a single table entry, in multiplications.
+const LOOKUP_COST: u64 = 16;
+
+/// Precomputed tables for raising a fixed base to many different exponents, using the Lim-Lee
+/// comb method.
+///
+/// An exponent of `n` bits is split into `window` rows of `d = ⌈n / window⌉` bits each, whose
+/// columns are used to index a table of the `2^window` products of `base^(2^(j * d))`. The columns
+/// are further split into `tables` blocks of `b = ⌈d / tables⌉` columns, each with their own table,
+/// so that an exponentiation only takes `b` squarings and `tables * b` multiplications, instead of
+/// the `n` squarings and `n / 4` multiplications of [`PowBoundedExp`](crate::PowBoundedExp).
+///
+/// This works for any Montgomery form implementing [`Monty`], i.e. both
+/// [`MontyForm`](super::MontyForm) and [`BoxedMontyForm`](super::BoxedMontyForm).
+///
+/// See: C.H. Lim, P.J. Lee, "More Flexible Exponentiation with Precomputation", CRYPTO '94,
+/// <https://doi.org/10.1007/3-540-48658-5_11>
+#[derive(Clone, Debug)]
+pub struct FixedBaseTable<M: Monty> {
+    /// `tables[k][i]` is the product of `base^(2^(j * spacing + k * block))` for the bits `j`
+    /// set in `i`
+    tables: Vec<Vec<M>>,
+    /// Number of exponent bits handled by the tables
+    exponent_bits: u32,
+    /// Number of rows the exponent is split into
+    window: u32,
+    /// Number of bits in each row
+    spacing: u32,
+    /// Number of columns handled by each table
+    block: u32,
+}
+
+impl<M: Monty> FixedBaseTable<M> {
+    /// Precompute a single comb table of `2^window` entries for raising `base` to exponents of up
+    /// to `exponent_bits` bits.
+    ///
+    /// # Panics
+    /// - if `window` is zero or greater than 16.
+    pub fn new(base: &M, exponent_bits: u32, window: u32) -> Self {
+        Self::with_tables(base, exponent_bits, window, 1)
+    }
+
+    /// Precompute `tables` comb tables of `2^window` entries each for raising `base` to exponents
+    /// of up to `exponent_bits` bits.
+    ///
+    /// Each additional table reduces the number of squarings performed by an exponentiation.
+    ///
+    /// # Panics
+    /// - if `window` is zero or greater than 16;
+    /// - if `tables` is zero.
+    pub fn with_tables(base: &M, exponent_bits: u32, window: u32, tables: u32) -> Self {
+        assert!(
+            window > 0 && window <= MAX_WINDOW,
+            "window must be between 1 and {MAX_WINDOW}"
+        );
+        assert!(tables > 0, "at least one table is required");
+
+        let spacing = exponent_bits.div_ceil(window).max(1);
+        let block = spacing.div_ceil(tables.min(spacing));
+        let tables = spacing.div_ceil(block);
+
+        // `powers[j][k] = base^(2^(j * spacing + k * block))`
+        let mut powers = Vec::with_capacity(window as usize);
+        let mut power = base.clone();
+        for _ in 0..window {
+            let mut row = Vec::with_capacity(tables as usize);
+            for k in 0..tables {
+                row.push(power.clone());
+                let squarings = if k + 1 < tables {
+                    block
+                } else {
+                    spacing - k * block
+                };
+                for _ in 0..squarings {
+                    power = power.square();
+                }
+            }
+            powers.push(row);
+        }
+
+        let one = M::one(base.params().clone());
+        let tables = (0..tables as usize)
+            .map(|k| {
+                let mut table = Vec::with_capacity(1 << window);
+                table.push(one.clone());
+                for i in 1..(1usize << window) {
+                    // Add the highest set bit of `i` to the entry without it
+                    let top = (usize::BITS - 1 - i.leading_zeros()) as usize;
+                    let entry = table[i ^ (1 << top)].clone() * &powers[top][k];
+                    table.push(entry);
+                }
+                table
+            })
+            .collect();
+
+        Self {
+            tables,
+            exponent_bits,
+            window,
+            spacing,
+            block,
+        }
+    }
+
+    /// Precompute tables for raising `base` to exponents of up to `exponent_bits` bits, choosing
+    /// the window size and number of tables which minimize the cost of an exponentiation while
+    /// storing at most `max_entries` precomputed values.
+    ///
+    /// # Panics
+    /// - if `max_entries` is less than 2.
+    pub fn with_memory_budget(base: &M, exponent_bits: u32, max_entries: usize) -> Self {
+        assert!(max_entries >= 2, "at least two entries are required");
+
+        let mut best = (u64::MAX, 1, 1);
+        for window in 1..=MAX_WINDOW {
+            let entries = 1usize << window;
+            if entries > max_entries {
+                break;
+            }
+
+            let spacing = exponent_bits.div_ceil(window).max(1);
+            let tables = (max_entries / entries).min(spacing as usize) as u32;
+            let block = spacing.div_ceil(tables);
+
+            // Squarings, multiplications and constant-time lookups
+            let lookups = u64::from(block) * u64::from(tables);
+            let cost = u64::from(block) + lookups + lookups * entries as u64 / LOOKUP_COST;
+            if cost < best.0 {
+                best = (cost, window, tables);
+            }
+        }
+
+        Self::with_tables(base, exponent_bits, best.1, best.2)
+    }
+
+    /// Returns the maximum number of exponent bits supported by these tables.
+    pub fn exponent_bits(&self) -> u32 {
+        self.exponent_bits
+    }
+
+    /// Returns the number of precomputed values stored in these tables.
+    pub fn num_entries(&self) -> usize {
+        self.tables.len() << self.window
+    }
+
+    /// Raises the base to the `exponent` power.
+    ///
+    /// Only the [`Self::exponent_bits`] least significant bits of the exponent are taken into
+    /// account.
+    ///
+    /// This method is constant-time with respect to `exponent`.
+    pub fn pow(&self, exponent: &M::Integer) -> M {
+        let one = &self.tables[0][0];
+        let mut multiplier = M::Multiplier::from(one.params());
+        let mut ret = one.clone();
+        let mut entry = one.clone();
+
+        for column in (0..self.block).rev() {
+            if column + 1 < self.block {
+                multiplier.square_assign(&mut ret);
+            }
+
+            for (k, table) in self.tables.iter().enumerate() {
+                let offset = k as u32 * self.block + column;
+                if offset >= self.spacing {
+                    continue;
+                }
+
+                let mut idx = 0;
+                for j in 0..self.window {
+                    let bit_index = j * self.spacing + offset;
+                    if bit_index < self.exponent_bits {
+                        idx |= exponent.bit(bit_index).select_u32(0, 1 << j);
+                    }
+                }
+
+                // Constant-time lookup in the table
+                entry.copy_montgomery_from(&table[0]);
+                for (i, candidate) in table.iter().enumerate().skip(1) {
+                    entry.ct_assign(candidate, Choice::from_u32_eq(i as u32, idx));
+                }
+
+                multiplier.mul_assign(&mut ret, &entry);
+            }
+        }
+
+        ret
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::FixedBaseTable;
+    use crate::{
+        U256,
+        modular::{MontyForm, MontyParams},
+    };
+
+    fn base() -> MontyForm<{ U256::LIMBS }> {
+        let modulus =
+            U256::from_be_hex("ffffffff00000000ffffffffffffffffbce6faada7179e84f3b9cac2fc632551")
+                .to_odd()
+                .unwrap();
+        MontyForm::new(&U256::from_u8(7), MontyParams::new(modulus))
+    }
+
+    #[test]
+    fn matches_pow() {
+        let base = base();
+        let exponents = [
+            U256::ZERO,
+            U256::ONE,
+            U256::from_u64(0xdead_beef),
+            U256::MAX,
+            U256::from_be_hex("14bfae46f4026e97c7a3fcd889b379a5f025719911c994a594fc6c5092ac58b1"),
+        ];
+
+        for (window, tables) in [(1, 1), (3, 1), (4, 2), (5, 7), (8, 4), (6, 100)] {
+            let table = FixedBaseTable::with_tables(&base, 256, window, tables);
+            for exponent in &exponents {
+                assert_eq!(
+                    table.pow(exponent),
+                    base.pow(exponent),
+                    "window = {window}, tables = {tables}"
+                );
+            }
+        }
+    }
+
+    #[test]
+    fn bounded_exponent() {
+        let base = base();
+        let table = FixedBaseTable::new(&base, 13, 4);
+        assert_eq!(table.exponent_bits(), 13);
+        assert_eq!(table.num_entries(), 16);
+
+        let exponent = U256::from_u64(0b1_0110_1001_1101);
+        assert_eq!(table.pow(&exponent), base.pow(&exponent));
+        // Higher bits are ignored
+        assert_eq!(
+            table.pow(&U256::MAX),
+            base.pow(&U256::from_u64((1 << 13) - 1))
+        );
+    }
+
+    #[test]
+    fn memory_budget() {
+        let base = base();
+        let exponent =
+            U256::from_be_hex("14bfae46f4026e97c7a3fcd889b379a5f025719911c994a594fc6c5092ac58b1");
+
+        for budget in [2, 3, 16, 100, 1024] {
+            let table = FixedBaseTable::with_memory_budget(&base, 256, budget);
+            assert!(table.num_entries() <= budget);
+            assert_eq!(table.pow(&exponent), base.pow(&exponent));
+        }
+    }
+
+    #[cfg(feature = "alloc")]
+    #[test]
+    fn boxed() {
+        use crate::{
+            BoxedUint, Resize,
+            modular::{BoxedMontyForm, BoxedMontyParams},
+        };
+
+        let modulus = BoxedUint::from_be_hex(
+            "ffffffff00000000ffffffffffffffffbce6faada7179e84f3b9cac2fc632551",
+            256,
+        )
+        .unwrap()
+        .to_odd()
+        .unwrap();
+        let params = BoxedMontyParams::new(modulus);
+        let base = BoxedMontyForm::new(BoxedUint::from(7u8).resize(256), params);
+        let exponent = BoxedUint::from_be_hex(
+            "14bfae46f4026e97c7a3fcd889b379a5f025719911c994a594fc6c5092ac58b1",
+            256,
+        )
+        .unwrap();
+
+        let table = FixedBaseTable::with_tables(&base, 256, 4, 4);
+        assert_eq!(table.pow(&exponent), base.pow(&exponent));
+    }
+}
@@ -9,6 +9,7 @@ mod mul;
 mod neg;
 mod pow;
 mod select;
+mod sqrt;
 mod sub;
 
 use super::{
@@ -17,12 +18,20 @@ use super::{
     div_by_2::div_by_2,
     mul::mul_montgomery_form,
     reduction::montgomery_retrieve,
+    sqrt::{find_non_residue_vartime, two_adicity},
 };
-use crate::{Choice, Limb, Monty, Odd, U64, Uint, Word};
+use crate::{Choice, CtOption, Limb, ModularRing, Monty, Odd, U64, Uint, Word};
 use mul::DynMontyMultiplier;
 
+#[cfg(feature = "serde")]
+use {
+    crate::Encoding,
+    serdect::serde::de::Error,
+    serdect::serde::{Deserialize, Deserializer, Serialize, Serializer},
+};
+
 /// Parameters to efficiently go to/from the Montgomery form for an odd modulus provided at runtime.
-#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+#[derive(Clone, Copy, Debug)]
 pub struct MontyParams<const LIMBS: usize> {
     /// The constant modulus
     pub(super) modulus: Odd<Uint<LIMBS>>,
@@ -35,6 +44,11 @@ pub struct MontyParams<const LIMBS: usize> {
     pub(super) mod_inv: U64,
     /// Leading zeros in the modulus, used to choose optimized algorithms
     pub(super) mod_leading_zeros: u32,
+    /// Largest `s` such that `2^s` divides `modulus - 1`, used to compute square roots
+    pub(super) two_adicity: u32,
+    /// Quadratic non-residue used to compute square roots when `modulus ≡ 1 (mod 8)`, or zero if
+    /// it was not searched for
+    pub(super) sqrt_non_residue: Word,
 }
 
 impl<const LIMBS: usize> MontyParams<LIMBS> {
@@ -56,18 +70,26 @@ impl<const LIMBS: usize> MontyParams<LIMBS> {
         let mod_leading_zeros = Choice::from_u32_lt(mod_leading_zeros, Word::BITS - 1)
             .select_u32(Word::BITS - 1, mod_leading_zeros);
 
+        // The quadratic non-residue is only searched for by `new_vartime`
+        let two_adicity = two_adicity(modulus.as_ref().as_uint_ref());
+
         Self {
             modulus,
             one,
             r2,
             mod_inv,
             mod_leading_zeros,
+            two_adicity,
+            sqrt_non_residue: 0,
         }
     }
 }
 
 impl<const LIMBS: usize> MontyParams<LIMBS> {
     /// Instantiates a new set of `MontyParams` representing the given odd `modulus`.
+    ///
+    /// Unlike [`MontyParams::new`], this also precomputes the quadratic non-residue needed by
+    /// [`MontyForm::sqrt`] when `modulus ≡ 1 (mod 8)`.
     pub const fn new_vartime(modulus: Odd<Uint<LIMBS>>) -> Self {
         // `R mod modulus` where `R = 2^BITS`.
         // Represents 1 in Montgomery form.
@@ -88,12 +110,23 @@ impl<const LIMBS: usize> MontyParams<LIMBS> {
             Word::BITS - 1
         };
 
+        // The quadratic non-residue is only needed by the Tonelli-Shanks algorithm, i.e. when
+        // `modulus ≡ 1 (mod 8)`
+        let two_adicity = two_adicity(modulus.as_ref().as_uint_ref());
+        let sqrt_non_residue = if two_adicity >= 3 {
+            find_non_residue_vartime(modulus.as_ref().as_uint_ref())
+        } else {
+            0
+        };
+
         Self {
             modulus,
             one,
             r2,
             mod_inv,
             mod_leading_zeros,
+            two_adicity,
+            sqrt_non_residue,
         }
     }
 
@@ -127,9 +160,21 @@ impl<const LIMBS: usize> zeroize::Zeroize for MontyParams<LIMBS> {
         self.r2.zeroize();
         self.mod_inv.zeroize();
         self.mod_leading_zeros.zeroize();
+        self.two_adicity.zeroize();
+        self.sqrt_non_residue.zeroize();
     }
 }
 
+/// Compares the moduli, which determine all other parameters, so that parameters created by
+/// [`MontyParams::new`] and [`MontyParams::new_vartime`] are equal.
+impl<const LIMBS: usize> PartialEq for MontyParams<LIMBS> {
+    fn eq(&self, other: &Self) -> bool {
+        self.modulus == other.modulus
+    }
+}
+
+impl<const LIMBS: usize> Eq for MontyParams<LIMBS> {}
+
 /// An integer in Montgomery form represented using `LIMBS` limbs.
 /// The odd modulus is set at runtime.
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
@@ -218,6 +263,30 @@ impl<const LIMBS: usize> Retrieve for MontyForm<LIMBS> {
     }
 }
 
+impl<const LIMBS: usize> ModularRing for MontyForm<LIMBS> {
+    type Integer = Uint<LIMBS>;
+
+    fn zero_like(&self) -> Self {
+        Self::zero(self.params)
+    }
+
+    fn one_like(&self) -> Self {
+        Self::one(self.params)
+    }
+
+    fn new_like(&self, value: &Uint<LIMBS>) -> Self {
+        Self::new(value, self.params)
+    }
+
+    fn sqrt(&self) -> CtOption<Self> {
+        self.sqrt()
+    }
+
+    fn is_square(&self) -> Choice {
+        self.jacobi_symbol().is_minus_one().not()
+    }
+}
+
 impl<const LIMBS: usize> Monty for MontyForm<LIMBS> {
     type Integer = Uint<LIMBS>;
     type Params = MontyParams<LIMBS>;
@@ -276,6 +345,67 @@ impl<const LIMBS: usize, P: ConstMontyParams<LIMBS>> From<&ConstMontyForm<P, LIM
     }
 }
 
+/// Deserializes the modulus, and recomputes the other parameters from it.
+#[cfg(feature = "serde")]
+impl<'de, const LIMBS: usize> Deserialize<'de> for MontyParams<LIMBS>
+where
+    Uint<LIMBS>: Encoding,
+{
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        Odd::<Uint<LIMBS>>::deserialize(deserializer).map(Self::new)
+    }
+}
+
+/// Serializes the modulus, which is all that is needed to recompute the other parameters.
+#[cfg(feature = "serde")]
+impl<const LIMBS: usize> Serialize for MontyParams<LIMBS>
+where
+    Uint<LIMBS>: Encoding,
+{
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        self.modulus.serialize(serializer)
+    }
+}
+
+/// Deserializes a `(value, modulus)` pair, where the value must be reduced.
+#[cfg(feature = "serde")]
+impl<'de, const LIMBS: usize> Deserialize<'de> for MontyForm<LIMBS>
+where
+    Uint<LIMBS>: Encoding,
+{
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        let (value, params) = <(Uint<LIMBS>, MontyParams<LIMBS>)>::deserialize(deserializer)?;
+        if value < params.modulus.0 {
+            Ok(Self::new(&value, params))
+        } else {
+            Err(D::Error::custom("value must be reduced"))
+        }
+    }
+}
+
+/// Serializes the canonical value along with the modulus, as a `(value, modulus)` pair.
+#[cfg(feature = "serde")]
+impl<const LIMBS: usize> Serialize for MontyForm<LIMBS>
+where
+    Uint<LIMBS>: Encoding,
+{
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        (self.retrieve(), &self.params).serialize(serializer)
+    }
+}
+
 #[cfg(feature = "zeroize")]
 impl<const LIMBS: usize> zeroize::Zeroize for MontyForm<LIMBS> {
     fn zeroize(&mut self) {
@@ -296,4 +426,38 @@ mod tests {
 
         assert_eq!(params.mod_leading_zeros, Limb::BITS - 2);
     }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn deserialize_validates() {
+        use super::MontyForm;
+        use crate::U64;
+        use serdect::serde::{
+            Deserialize,
+            de::{
+                IntoDeserializer,
+                value::{Error, SeqDeserializer},
+            },
+        };
+
+        // Little-endian hex encodings of `U64` values
+        let deserialize = |value: &'static str, modulus: &'static str| {
+            MontyForm::<{ U64::LIMBS }>::deserialize(SeqDeserializer::<_, Error>::new(
+                [value, modulus].into_iter(),
+            ))
+        };
+
+        let params = MontyParams::new(Odd::new(U64::from_u8(13)).unwrap());
+        assert_eq!(
+            deserialize("0500000000000000", "0d00000000000000").unwrap(),
+            MontyForm::new(&U64::from_u8(5), params)
+        );
+        let modulus = IntoDeserializer::<Error>::into_deserializer("0d00000000000000");
+        assert_eq!(MontyParams::deserialize(modulus).unwrap(), params);
+
+        // Unreduced value
+        assert!(deserialize("0d00000000000000", "0d00000000000000").is_err());
+        // Even modulus
+        assert!(deserialize("0500000000000000", "0c00000000000000").is_err());
+    }
 }
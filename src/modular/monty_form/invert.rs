@@ -3,6 +3,12 @@
 use super::{MontyForm, MontyParams};
 use crate::{CtOption, modular::SafeGcdInverter, traits::Invert};
 
+#[cfg(feature = "alloc")]
+use {
+    crate::{Choice, modular::batch_invert::batch_invert},
+    alloc::vec::Vec,
+};
+
 impl<const LIMBS: usize> MontyForm<LIMBS> {
     /// Computes `self^-1` representing the multiplicative inverse of `self`.
     /// i.e. `self * self^-1 = 1`.
@@ -65,6 +71,27 @@ impl<const LIMBS: usize> MontyForm<LIMBS> {
     }
 }
 
+#[cfg(feature = "alloc")]
+impl<const LIMBS: usize> MontyForm<LIMBS> {
+    /// Inverts all of the `values` in place using Montgomery's trick, which takes a single modular
+    /// inversion and three multiplications per value.
+    ///
+    /// Returns a mask whose `i`-th element is truthy if `values[i]` was inverted. Zero values are
+    /// left unchanged, as are all of the values if any of them is non-zero but not invertible,
+    /// which can only happen for a composite modulus.
+    ///
+    /// This method is constant-time with respect to the values, but not their number.
+    ///
+    /// All of the values must share the same parameters.
+    pub fn batch_invert(values: &mut [Self]) -> Vec<Choice> {
+        let Some(first) = values.first() else {
+            return Vec::new();
+        };
+        let params = first.params;
+        batch_invert(values, &Self::zero(params), &Self::one(params))
+    }
+}
+
 impl<const LIMBS: usize> Invert for MontyForm<LIMBS> {
     type Output = CtOption<Self>;
 
@@ -107,4 +134,31 @@ mod tests {
 
         assert_eq!(res.retrieve(), U256::ONE);
     }
+
+    #[cfg(feature = "alloc")]
+    #[test]
+    fn test_batch_invert() {
+        let params = params();
+        let x =
+            U256::from_be_hex("77117F1273373C26C700D076B3F780074D03339F56DD0EFB60E7F58441FD3685");
+        let x_monty = MontyForm::new(&x, params);
+        let y_monty = x_monty.square();
+        let zero = MontyForm::zero(params);
+
+        let mut values = [x_monty, zero, y_monty, zero];
+        let mask = MontyForm::batch_invert(&mut values);
+
+        assert_eq!(
+            mask.iter()
+                .map(|c| c.to_bool_vartime())
+                .collect::<alloc::vec::Vec<_>>(),
+            [true, false, true, false]
+        );
+        assert_eq!(values[0], x_monty.invert().unwrap());
+        assert_eq!(values[1], zero);
+        assert_eq!(values[2], y_monty.invert().unwrap());
+        assert_eq!(values[3], zero);
+
+        assert!(MontyForm::<{ U256::LIMBS }>::batch_invert(&mut []).is_empty());
+    }
 }
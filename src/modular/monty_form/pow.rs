@@ -1,13 +1,22 @@
 //! Exponentiation of integers in Montgomery form with a modulus set at runtime.
 
-use super::MontyForm;
+use super::{MontyForm, MontyParams};
 use crate::{
-    MultiExponentiateBoundedExp, PowBoundedExp, Uint,
-    modular::pow::{multi_exponentiate_montgomery_form_array, pow_montgomery_form},
+    CtOption, Int, MultiExponentiateBoundedExp, PowBoundedExp, Uint,
+    modular::{
+        mul::{mul_montgomery_form, square_montgomery_form},
+        pow::{
+            PowMultiplier, multi_exponentiate_montgomery_form_array, pow_montgomery_form,
+            pow_vartime,
+        },
+    },
 };
 
 #[cfg(feature = "alloc")]
-use {crate::modular::pow::multi_exponentiate_montgomery_form_slice, alloc::vec::Vec};
+use {
+    crate::modular::pow::{multi_exponentiate_montgomery_form_slice, multi_exponentiate_vartime},
+    alloc::vec::Vec,
+};
 
 impl<const LIMBS: usize> MontyForm<LIMBS> {
     /// Raises to the `exponent` power.
@@ -18,6 +27,46 @@ impl<const LIMBS: usize> MontyForm<LIMBS> {
         self.pow_bounded_exp(exponent, Uint::<RHS_LIMBS>::BITS)
     }
 
+    /// Raises to the signed `exponent` power.
+    ///
+    /// If `exponent` is negative, this computes the inverse of `self` raised to `|exponent|`,
+    /// returning none if `self` is not invertible. Non-negative exponents always succeed.
+    ///
+    /// This method is constant-time with respect to both `self` and `exponent`.
+    pub const fn pow_signed<const RHS_LIMBS: usize>(
+        &self,
+        exponent: &Int<RHS_LIMBS>,
+    ) -> CtOption<Self> {
+        let (abs, is_negative) = exponent.abs_sign();
+        let inverse = self.invert();
+        let base = Self {
+            montgomery_form: Uint::select(
+                &self.montgomery_form,
+                &inverse.as_inner_unchecked().montgomery_form,
+                is_negative,
+            ),
+            params: self.params,
+        };
+        let is_some = inverse.is_some().or(is_negative.not());
+        CtOption::new(base.pow(&abs), is_some)
+    }
+
+    /// Raises to the `exponent` power using sliding windows.
+    ///
+    /// This method is variable-time with respect to `exponent`, and meant for public exponents.
+    pub fn pow_vartime<const RHS_LIMBS: usize>(&self, exponent: &Uint<RHS_LIMBS>) -> Self {
+        let mut params = self.params;
+        Self {
+            montgomery_form: pow_vartime(
+                &self.montgomery_form,
+                exponent.as_uint_ref(),
+                || self.params.one,
+                &mut params,
+            ),
+            params: self.params,
+        }
+    }
+
     /// Raises to the `exponent` power,
     /// with `exponent_bits` representing the number of (least significant) bits
     /// to take into account for the exponent.
@@ -42,6 +91,18 @@ impl<const LIMBS: usize> MontyForm<LIMBS> {
     }
 }
 
+/// Montgomery multiplication of values in Montgomery form, used by the generic exponentiation
+/// routines.
+impl<const LIMBS: usize> PowMultiplier<Uint<LIMBS>> for MontyParams<LIMBS> {
+    fn mul_assign(&mut self, lhs: &mut Uint<LIMBS>, rhs: &Uint<LIMBS>) {
+        *lhs = mul_montgomery_form(lhs, rhs, &self.modulus, self.mod_neg_inv());
+    }
+
+    fn square_assign(&mut self, lhs: &mut Uint<LIMBS>) {
+        *lhs = square_montgomery_form(lhs, &self.modulus, self.mod_neg_inv());
+    }
+}
+
 impl<const LIMBS: usize, const RHS_LIMBS: usize> PowBoundedExp<Uint<RHS_LIMBS>>
     for MontyForm<LIMBS>
 {
@@ -82,6 +143,14 @@ impl<const N: usize, const LIMBS: usize, const RHS_LIMBS: usize>
             params,
         }
     }
+
+    #[cfg(feature = "alloc")]
+    fn multi_exponentiate_bounded_exp_vartime(
+        bases_and_exponents: &[(Self, Uint<RHS_LIMBS>); N],
+        exponent_bits: u32,
+    ) -> Self {
+        multi_exponentiate_vartime(bases_and_exponents, exponent_bits)
+    }
 }
 
 #[cfg(feature = "alloc")]
@@ -113,4 +182,11 @@ impl<const LIMBS: usize, const RHS_LIMBS: usize>
             params,
         }
     }
+
+    fn multi_exponentiate_bounded_exp_vartime(
+        bases_and_exponents: &[(Self, Uint<RHS_LIMBS>)],
+        exponent_bits: u32,
+    ) -> Self {
+        multi_exponentiate_vartime(bases_and_exponents, exponent_bits)
+    }
 }
@@ -1,6 +1,6 @@
 use super::MontyParams;
 use crate::modular::MontyForm;
-use crate::{Choice, CtSelect, Odd, U64, Uint};
+use crate::{Choice, CtSelect, Odd, U64, Uint, Word};
 
 impl<const LIMBS: usize> CtSelect for MontyForm<LIMBS> {
     fn ct_select(&self, other: &Self, choice: Choice) -> Self {
@@ -23,6 +23,12 @@ impl<const LIMBS: usize> CtSelect for MontyParams<LIMBS> {
                 &other.mod_leading_zeros,
                 choice,
             ),
+            two_adicity: u32::ct_select(&self.two_adicity, &other.two_adicity, choice),
+            sqrt_non_residue: Word::ct_select(
+                &self.sqrt_non_residue,
+                &other.sqrt_non_residue,
+                choice,
+            ),
         }
     }
 }
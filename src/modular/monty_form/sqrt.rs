@@ -0,0 +1,124 @@
+//! Square roots of integers in Montgomery form with a modulus set at runtime.
+
+use super::MontyForm;
+use crate::{
+    CtOption,
+    modular::sqrt::{find_non_residue_vartime, sqrt},
+};
+
+#[cfg(doc)]
+use crate::modular::MontyParams;
+
+impl<const LIMBS: usize> MontyForm<LIMBS> {
+    /// Compute a square root of `self` modulo the prime modulus, i.e. a value `r` such that
+    /// `r * r == self`.
+    ///
+    /// Returns `None` if `self` is not a quadratic residue.
+    ///
+    /// Uses fast paths when `modulus ≡ 3 (mod 4)` or `modulus ≡ 5 (mod 8)`, and the
+    /// Tonelli-Shanks algorithm otherwise. The latter needs a quadratic non-residue modulo the
+    /// modulus, which is precomputed by [`MontyParams::new_vartime`], but searched for on every
+    /// call if the parameters were created with the constant-time [`MontyParams::new`].
+    ///
+    /// This method is constant-time with respect to `self`, but variable-time with respect to
+    /// the modulus. If the modulus is not prime, `Some` results are still valid square roots, but
+    /// `None` may be returned even if a square root exists.
+    ///
+    /// # Panics
+    /// - if `modulus ≡ 1 (mod 8)` and it has no quadratic non-residue below 1024, which can only
+    ///   happen if it is not prime.
+    pub fn sqrt(&self) -> CtOption<Self> {
+        let params = &self.params;
+        let modulus = params.modulus.as_ref();
+        sqrt(self, modulus, params.two_adicity, || {
+            match params.sqrt_non_residue {
+                0 => find_non_residue_vartime(modulus.as_uint_ref()),
+                non_residue => non_residue,
+            }
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{
+        Odd, U64, U256,
+        modular::{MontyForm, MontyParams},
+    };
+
+    /// Check that every value modulo `p` which has a square root yields a correct one, and
+    /// that quadratic non-residues are rejected.
+    fn check_small_prime(p: u64) {
+        let params = MontyParams::new_vartime(Odd::new(U64::from_u64(p)).unwrap());
+        let mut squares = 0;
+
+        for i in 0..p {
+            let x = MontyForm::new(&U64::from_u64(i), params);
+            let is_square = (0..p).any(|r| (r * r) % p == i);
+            let root = x.sqrt();
+            assert_eq!(bool::from(root.is_some()), is_square, "p = {p}, x = {i}");
+
+            if is_square {
+                assert_eq!(root.unwrap().square(), x);
+                squares += 1;
+            }
+        }
+
+        assert_eq!(squares, p.div_ceil(2));
+    }
+
+    #[test]
+    fn sqrt_3_mod_4() {
+        check_small_prime(7);
+        check_small_prime(103);
+    }
+
+    #[test]
+    fn sqrt_5_mod_8() {
+        check_small_prime(13);
+        check_small_prime(101);
+    }
+
+    #[test]
+    fn sqrt_1_mod_8() {
+        check_small_prime(17);
+        check_small_prime(41);
+        check_small_prime(97);
+        check_small_prime(257);
+    }
+
+    #[test]
+    fn sqrt_bls12_381_scalar() {
+        // 2-adicity 32
+        let params = MontyParams::new(Odd::<U256>::from_be_hex(
+            "73eda753299d7d483339d80809a1d80553bda402fffe5bfeffffffff00000001",
+        ));
+        let x = MontyForm::new(
+            &U256::from_be_hex("14BFAE46F4026E97C7A3FCD889B379A5F025719911C994A594FC6C5092AC58B1"),
+            params,
+        );
+        let square = x.square();
+        let root = square.sqrt().unwrap();
+        assert_eq!(root.square(), square);
+        assert!(root == x || root == -x);
+
+        let non_residue = MontyForm::new(&U256::from(5u8), params);
+        assert!(bool::from(non_residue.sqrt().is_none()));
+    }
+
+    #[test]
+    fn non_residue_precomputed() {
+        let modulus = Odd::new(U64::from_u64(41)).unwrap();
+        assert_eq!(MontyParams::new_vartime(modulus).sqrt_non_residue, 3);
+        assert_eq!(MontyParams::new(modulus).sqrt_non_residue, 0);
+        assert_eq!(MontyParams::new(modulus), MontyParams::new_vartime(modulus));
+    }
+
+    #[test]
+    #[should_panic]
+    fn no_non_residue() {
+        // 9 ≡ 1 (mod 8) is a perfect square, so it has no quadratic non-residues
+        let params = MontyParams::new_vartime(Odd::new(U64::from_u64(9)).unwrap());
+        let _ = MontyForm::new(&U64::from_u64(4), params).sqrt();
+    }
+}
@@ -0,0 +1,382 @@
+//! Number-theoretic transform over word-sized prime moduli.
+
+use super::{
+    MontyForm, MontyParams, add::add_montgomery_form, mul::mul_montgomery_form,
+    sub::sub_montgomery_form,
+};
+use crate::{Limb, Odd, U64, Uint};
+
+/// Precomputed parameters for the negacyclic number-theoretic transform (NTT) of length `N` over
+/// an NTT-friendly prime `p`, i.e. one with a primitive `2N`-th root of unity `psi`.
+///
+/// The NTT maps a polynomial in `Z_p[X]/(X^N + 1)` to its evaluations at the odd powers of `psi`,
+/// so that products of polynomials, as used by lattice-based schemes such as Kyber and Dilithium,
+/// can be computed with `N` pointwise multiplications instead of `N^2`.
+///
+/// Coefficients are single [`Limb`]s, which must be reduced modulo `p`. They are kept in their
+/// canonical representation, with the Montgomery factor folded into the precomputed twiddle
+/// tables, and all of the butterflies are Montgomery multiplications, so that every operation is
+/// constant-time with respect to the coefficients.
+///
+/// The transforms follow Algorithms 1 and 2 of P. Longa, M. Naehrig, "Speeding up the Number
+/// Theoretic Transform for Faster Ideal Lattice-Based Cryptography",
+/// <https://eprint.iacr.org/2016/504>: the forward transform uses Cooley-Tukey butterflies and
+/// produces its output in bit-reversed order, which the Gentleman-Sande butterflies of the inverse
+/// transform take as input.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct NttParams<const N: usize> {
+    /// Montgomery parameters for `p`
+    params: MontyParams<1>,
+    /// `twiddles[k] = psi^bitrev(k)` in Montgomery form
+    twiddles: [Uint<1>; N],
+    /// `inv_twiddles[k] = psi^-bitrev(k)` in Montgomery form
+    inv_twiddles: [Uint<1>; N],
+    /// `N^-1 mod p` in Montgomery form
+    n_inv: Uint<1>,
+    /// `N^-1 * R mod p` in Montgomery form, which also cancels the factor of `R^-1` left by a
+    /// single Montgomery multiplication of transformed values
+    n_inv_r: Uint<1>,
+}
+
+impl<const N: usize> NttParams<N> {
+    /// Precompute the twiddle tables for the prime `modulus` and the primitive `2N`-th root of
+    /// unity `psi` modulo it.
+    ///
+    /// Returns `None` if `modulus` is not odd, or if `psi^N != -1 mod modulus`, which for a prime
+    /// modulus means `psi` is not a primitive `2N`-th root of unity. The primality of `modulus` is
+    /// not checked.
+    ///
+    /// This method is variable-time with respect to `modulus` and `psi`.
+    ///
+    /// # Panics
+    /// - if `N` is not a power of two, or is less than 2.
+    pub const fn new_vartime(modulus: Limb, psi: Limb) -> Option<Self> {
+        assert!(
+            N >= 2 && N.is_power_of_two(),
+            "NTT length must be a power of two"
+        );
+
+        if modulus.0 & 1 == 0 || modulus.0 == 1 || psi.0 >= modulus.0 {
+            return None;
+        }
+
+        let params = MontyParams::new_vartime(Odd(Uint::new([modulus])));
+        let psi = MontyForm::new(&Uint::new([psi]), params);
+        let minus_one = MontyForm::one(params).neg();
+        if !Uint::eq(
+            psi.pow(&U64::from_u64(N as u64)).as_montgomery(),
+            minus_one.as_montgomery(),
+        )
+        .to_bool_vartime()
+        {
+            return None;
+        }
+
+        // `powers[i] = psi^i`, so that `psi^-i = -psi^(N - i)` since `psi^N = -1`
+        let mut powers = [Uint::ZERO; N];
+        powers[0] = params.one;
+        let mut i = 1;
+        while i < N {
+            powers[i] = psi
+                .mul(&MontyForm::from_montgomery(powers[i - 1], params))
+                .to_montgomery();
+            i += 1;
+        }
+
+        let log_n = N.trailing_zeros();
+        let mut twiddles = [Uint::ZERO; N];
+        let mut inv_twiddles = [Uint::ZERO; N];
+        let mut k = 0;
+        while k < N {
+            let e = k.reverse_bits() >> (usize::BITS - log_n);
+            twiddles[k] = powers[e];
+            inv_twiddles[k] = if e == 0 {
+                params.one
+            } else {
+                MontyForm::from_montgomery(powers[N - e], params)
+                    .neg()
+                    .to_montgomery()
+            };
+            k += 1;
+        }
+
+        // `N` divides `p - 1`, so it's invertible
+        let n = MontyForm::new(&Uint::from_u64(N as u64), params);
+        let n_inv = n.invert_vartime().expect_copied("N is coprime to p");
+        let n_inv_r = MontyForm::new(n_inv.as_montgomery(), params).to_montgomery();
+
+        Some(Self {
+            params,
+            twiddles,
+            inv_twiddles,
+            n_inv: n_inv.to_montgomery(),
+            n_inv_r,
+        })
+    }
+
+    /// Returns the prime modulus `p`.
+    pub const fn modulus(&self) -> Limb {
+        self.params.modulus.0.limbs[0]
+    }
+
+    /// Computes the forward NTT of the polynomial with the given coefficients in place.
+    ///
+    /// The output is in bit-reversed order.
+    pub const fn forward(&self, coeffs: &mut [Limb; N]) {
+        let modulus = &self.params.modulus;
+        let mod_neg_inv = self.params.mod_neg_inv();
+
+        let mut t = N;
+        let mut m = 1;
+        while m < N {
+            t /= 2;
+            let mut i = 0;
+            while i < m {
+                let s = &self.twiddles[m + i];
+                let mut j = 2 * i * t;
+                while j < 2 * i * t + t {
+                    let u = Uint::new([coeffs[j]]);
+                    // `x * (y * R) * R^-1 = x * y`, so the coefficients stay in canonical form
+                    let v =
+                        mul_montgomery_form(&Uint::new([coeffs[j + t]]), s, modulus, mod_neg_inv);
+                    coeffs[j] = add_montgomery_form(&u, &v, modulus).limbs[0];
+                    coeffs[j + t] = sub_montgomery_form(&u, &v, modulus).limbs[0];
+                    j += 1;
+                }
+                i += 1;
+            }
+            m *= 2;
+        }
+    }
+
+    /// Computes the inverse NTT in place, taking values in bit-reversed order as produced by
+    /// [`NttParams::forward`], and producing the coefficients of the polynomial.
+    pub const fn inverse(&self, values: &mut [Limb; N]) {
+        self.inverse_scaled(values, &self.n_inv);
+    }
+
+    /// Computes the inverse NTT in place, with the final scaling by `N^-1` done by a Montgomery
+    /// multiplication with `n_inv`.
+    const fn inverse_scaled(&self, values: &mut [Limb; N], n_inv: &Uint<1>) {
+        let modulus = &self.params.modulus;
+        let mod_neg_inv = self.params.mod_neg_inv();
+
+        let mut t = 1;
+        let mut m = N;
+        while m > 1 {
+            let h = m / 2;
+            let mut i = 0;
+            while i < h {
+                let s = &self.inv_twiddles[h + i];
+                let mut j = 2 * i * t;
+                while j < 2 * i * t + t {
+                    let u = Uint::new([values[j]]);
+                    let v = Uint::new([values[j + t]]);
+                    values[j] = add_montgomery_form(&u, &v, modulus).limbs[0];
+                    let diff = sub_montgomery_form(&u, &v, modulus);
+                    values[j + t] = mul_montgomery_form(&diff, s, modulus, mod_neg_inv).limbs[0];
+                    j += 1;
+                }
+                i += 1;
+            }
+            t *= 2;
+            m = h;
+        }
+
+        let mut j = 0;
+        while j < N {
+            let v = Uint::new([values[j]]);
+            values[j] = mul_montgomery_form(&v, n_inv, modulus, mod_neg_inv).limbs[0];
+            j += 1;
+        }
+    }
+
+    /// Computes the pointwise product of two transformed polynomials.
+    ///
+    /// This needs two Montgomery multiplications per coefficient, the second one cancelling the
+    /// factor of `R^-1` left by the first, so that the products are in the same form as the
+    /// output of [`NttParams::forward`]. [`NttParams::negacyclic_mul`] folds that factor into the
+    /// scaling of the inverse transform instead.
+    pub const fn pointwise_mul(&self, a: &[Limb; N], b: &[Limb; N]) -> [Limb; N] {
+        let modulus = &self.params.modulus;
+        let mod_neg_inv = self.params.mod_neg_inv();
+
+        let mut ret = self.pointwise_mul_montgomery(a, b);
+        let mut i = 0;
+        while i < N {
+            let product = Uint::new([ret[i]]);
+            ret[i] = mul_montgomery_form(&product, &self.params.r2, modulus, mod_neg_inv).limbs[0];
+            i += 1;
+        }
+        ret
+    }
+
+    /// Computes the pointwise Montgomery products `a * b * R^-1` of two transformed polynomials.
+    const fn pointwise_mul_montgomery(&self, a: &[Limb; N], b: &[Limb; N]) -> [Limb; N] {
+        let modulus = &self.params.modulus;
+        let mod_neg_inv = self.params.mod_neg_inv();
+
+        let mut ret = [Limb::ZERO; N];
+        let mut i = 0;
+        while i < N {
+            ret[i] =
+                mul_montgomery_form(&Uint::new([a[i]]), &Uint::new([b[i]]), modulus, mod_neg_inv)
+                    .limbs[0];
+            i += 1;
+        }
+        ret
+    }
+
+    /// Computes the product of two polynomials in `Z_p[X]/(X^N + 1)`, given their coefficients.
+    pub const fn negacyclic_mul(&self, a: &[Limb; N], b: &[Limb; N]) -> [Limb; N] {
+        let mut a = *a;
+        let mut b = *b;
+        self.forward(&mut a);
+        self.forward(&mut b);
+        let mut ret = self.pointwise_mul_montgomery(&a, &b);
+        self.inverse_scaled(&mut ret, &self.n_inv_r);
+        ret
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::NttParams;
+    use crate::{Limb, WideWord, Word};
+
+    /// Dilithium's modulus `q = 2^23 - 2^13 + 1`, and its primitive 512-th root of unity.
+    const DILITHIUM_Q: Word = 8380417;
+    const DILITHIUM_PSI: Word = 1753;
+
+    /// Kyber's modulus `q = 3329`, which has a primitive 256-th root of unity but not 512-th.
+    const KYBER_Q: Word = 3329;
+    const KYBER_PSI: Word = 17;
+
+    /// Schoolbook multiplication in `Z_q[X]/(X^N + 1)`.
+    fn schoolbook<const N: usize>(q: Word, a: &[Limb; N], b: &[Limb; N]) -> [Limb; N] {
+        let mut ret = [0; N];
+        for (i, ai) in a.iter().enumerate() {
+            for (j, bj) in b.iter().enumerate() {
+                let product = mul_mod(ai.0, bj.0, q);
+                let k = (i + j) % N;
+                ret[k] = if i + j < N {
+                    (ret[k] + product) % q
+                } else {
+                    (ret[k] + q - product) % q
+                };
+            }
+        }
+        ret.map(Limb)
+    }
+
+    /// Deterministic pseudorandom coefficients modulo `q`.
+    fn coeffs<const N: usize>(q: Word, seed: u64) -> [Limb; N] {
+        let mut state = seed;
+        core::array::from_fn(|_| {
+            state = state
+                .wrapping_mul(6364136223846793005)
+                .wrapping_add(1442695040888963407);
+            Limb(Word::try_from(state >> 33).unwrap() % q)
+        })
+    }
+
+    fn mul_mod(a: Word, b: Word, q: Word) -> Word {
+        (WideWord::from(a) * WideWord::from(b) % WideWord::from(q)) as Word
+    }
+
+    fn pow_mod(mut base: Word, mut exp: Word, q: Word) -> Word {
+        let mut ret = 1;
+        while exp > 0 {
+            if exp & 1 == 1 {
+                ret = mul_mod(ret, base, q);
+            }
+            base = mul_mod(base, base, q);
+            exp >>= 1;
+        }
+        ret
+    }
+
+    fn check_params<const N: usize>(ntt: &NttParams<N>, q: Word) {
+        for seed in 0..4 {
+            let a = coeffs::<N>(q, seed);
+            let b = coeffs::<N>(q, seed + 100);
+
+            let mut transformed = a;
+            ntt.forward(&mut transformed);
+            assert_ne!(transformed, a);
+            ntt.inverse(&mut transformed);
+            assert_eq!(transformed, a);
+
+            let expected = schoolbook(q, &a, &b);
+            assert_eq!(ntt.negacyclic_mul(&a, &b), expected);
+
+            let mut a_hat = a;
+            let mut b_hat = b;
+            ntt.forward(&mut a_hat);
+            ntt.forward(&mut b_hat);
+            let mut product = ntt.pointwise_mul(&a_hat, &b_hat);
+            ntt.inverse(&mut product);
+            assert_eq!(product, expected);
+        }
+    }
+
+    #[test]
+    fn dilithium() {
+        let ntt = NttParams::<256>::new_vartime(Limb(DILITHIUM_Q), Limb(DILITHIUM_PSI)).unwrap();
+        assert_eq!(ntt.modulus(), Limb(DILITHIUM_Q));
+        check_params(&ntt, DILITHIUM_Q);
+    }
+
+    #[test]
+    fn kyber() {
+        let ntt = NttParams::<128>::new_vartime(Limb(KYBER_Q), Limb(KYBER_PSI)).unwrap();
+        check_params(&ntt, KYBER_Q);
+
+        // There is no primitive 512-th root of unity modulo 3329
+        assert!(NttParams::<256>::new_vartime(Limb(KYBER_Q), Limb(KYBER_PSI)).is_none());
+    }
+
+    #[test]
+    fn large_prime() {
+        // `15 * 2^27 + 1`, which has the primitive root 31
+        const Q: Word = 2013265921;
+        let psi = pow_mod(31, (Q - 1) / 1024, Q);
+        let ntt = NttParams::<512>::new_vartime(Limb(Q), Limb(psi)).unwrap();
+        check_params(&ntt, Q);
+    }
+
+    #[test]
+    fn monomials() {
+        let ntt = NttParams::<8>::new_vartime(Limb(KYBER_Q), Limb(pow_mod(KYBER_PSI, 16, KYBER_Q)))
+            .unwrap();
+
+        // `X^7 * X = X^8 = -1`
+        let mut x = [Limb::ZERO; 8];
+        x[1] = Limb::ONE;
+        let mut x7 = [Limb::ZERO; 8];
+        x7[7] = Limb::ONE;
+        let mut expected = [Limb::ZERO; 8];
+        expected[0] = Limb(KYBER_Q - 1);
+        assert_eq!(ntt.negacyclic_mul(&x, &x7), expected);
+    }
+
+    #[test]
+    fn invalid_params() {
+        assert!(
+            NttParams::<256>::new_vartime(Limb(DILITHIUM_Q + 1), Limb(DILITHIUM_PSI)).is_none()
+        );
+        assert!(NttParams::<256>::new_vartime(Limb(DILITHIUM_Q), Limb(2)).is_none());
+        assert!(NttParams::<256>::new_vartime(Limb(DILITHIUM_Q), Limb(DILITHIUM_Q)).is_none());
+
+        // `psi^2` is a primitive 256-th root of unity
+        let psi2 = mul_mod(DILITHIUM_PSI, DILITHIUM_PSI, DILITHIUM_Q);
+        assert!(NttParams::<256>::new_vartime(Limb(DILITHIUM_Q), Limb(psi2)).is_none());
+        assert!(NttParams::<128>::new_vartime(Limb(DILITHIUM_Q), Limb(psi2)).is_some());
+    }
+
+    #[test]
+    #[should_panic]
+    fn non_power_of_two_length() {
+        let _ = NttParams::<12>::new_vartime(Limb(DILITHIUM_Q), Limb(DILITHIUM_PSI));
+    }
+}
@@ -1,13 +1,23 @@
 use super::mul::{mul_montgomery_form, square_montgomery_form};
-use crate::{AmmMultiplier, CtEq, Limb, Monty, Odd, Uint, Unsigned, Word, word};
+use crate::{AmmMultiplier, CtEq, CtSelect, Limb, Monty, Odd, Uint, UintRef, Unsigned, Word, word};
+
+#[cfg(feature = "alloc")]
+use crate::{MontyMultiplier, Zero};
 use core::{array, mem};
 
 #[cfg(feature = "alloc")]
-use alloc::vec::Vec;
+use alloc::{vec, vec::Vec};
 
 const WINDOW: u32 = 4;
 const WINDOW_MASK: Word = (1 << WINDOW) - 1;
 
+/// Largest sliding window size used by variable-time exponentiation.
+pub(crate) const MAX_VARTIME_WINDOW: u32 = 6;
+
+/// Largest bucket width used by variable-time multi-exponentiation.
+#[cfg(feature = "alloc")]
+const MAX_PIPPENGER_WINDOW: u32 = 16;
+
 /// Performs modular exponentiation using Montgomery's ladder.
 /// `exponent_bits` represents the number of bits to take into account for the exponent.
 ///
@@ -29,6 +39,282 @@ pub const fn pow_montgomery_form<const LIMBS: usize, const RHS_LIMBS: usize>(
     )
 }
 
+/// Multiplication in the ring in which an exponentiation is performed, e.g. Montgomery
+/// multiplication with a given modulus.
+pub(crate) trait PowMultiplier<T> {
+    /// Assigns `lhs * rhs` to `lhs`.
+    fn mul_assign(&mut self, lhs: &mut T, rhs: &T);
+
+    /// Assigns `lhs * lhs` to `lhs`.
+    fn square_assign(&mut self, lhs: &mut T);
+}
+
+/// [`PowMultiplier`] performing "Almost Montgomery Multiplications" with an [`AmmMultiplier`].
+///
+/// NOTE: the results are reduced to the *bit length* of the modulus, but not fully reduced.
+pub(crate) struct Amm<M>(pub(crate) M);
+
+impl<'a, M: AmmMultiplier<'a>> PowMultiplier<<M::Monty as Monty>::Integer> for Amm<M> {
+    fn mul_assign(
+        &mut self,
+        lhs: &mut <M::Monty as Monty>::Integer,
+        rhs: &<M::Monty as Monty>::Integer,
+    ) {
+        self.0.mul_amm_assign(lhs, rhs);
+    }
+
+    fn square_assign(&mut self, lhs: &mut <M::Monty as Monty>::Integer) {
+        self.0.square_amm_assign(lhs);
+    }
+}
+
+/// Performs variable-time exponentiation using sliding windows, with the products computed by
+/// `multiplier`. `one` is only called for a zero exponent.
+///
+/// NOTE: the exponent is leaked in the time pattern.
+pub(crate) fn pow_vartime<T: Clone, M: PowMultiplier<T>>(
+    x: &T,
+    exponent: &UintRef,
+    one: impl FnOnce() -> T,
+    multiplier: &mut M,
+) -> T {
+    let exponent_bits = exponent.bits_vartime();
+    let exponent = exponent.as_slice();
+    if exponent_bits == 0 {
+        return one();
+    }
+
+    let window = vartime_window_size(exponent, exponent_bits);
+
+    // powers[i] contains x^(2i + 1), the entries beyond the window are unused
+    let mut x2 = x.clone();
+    if window > 1 {
+        multiplier.square_assign(&mut x2);
+    }
+    let mut power = x.clone();
+    let powers: [T; 1 << (MAX_VARTIME_WINDOW - 1)] = array::from_fn(|i| {
+        if i > 0 && i < 1 << (window - 1) {
+            multiplier.mul_assign(&mut power, &x2);
+        }
+        power.clone()
+    });
+
+    // The most significant bit is set, so the first window is never zero
+    let (len, value) = next_vartime_window(exponent, exponent_bits, window);
+    let mut z = powers[(value >> 1) as usize].clone();
+    let mut bit = exponent_bits - len;
+
+    while bit > 0 {
+        let (len, value) = next_vartime_window(exponent, bit, window);
+        for _ in 0..len {
+            multiplier.square_assign(&mut z);
+        }
+        if value != 0 {
+            multiplier.mul_assign(&mut z, &powers[(value >> 1) as usize]);
+        }
+        bit -= len;
+    }
+
+    z
+}
+
+/// Performs variable-time modular multi-exponentiation using Pippenger's bucket method, for any
+/// [`Monty`] type.
+///
+/// Each window of `w` bits of the exponents is handled by sorting the bases into `2^w - 1`
+/// buckets according to their digits, then combining the buckets with about `2^(w+1)`
+/// multiplications, so that the cost per base is a single multiplication per window.
+/// `exponent_bits` represents the number of (least significant) bits to take into account for
+/// the exponents.
+///
+/// See: Pippenger, N. On the evaluation of powers and monomials. SIAM Journal on Computing 9.2
+/// (1980), 230–250.
+///
+/// NOTE: the exponents are leaked in the time pattern.
+///
+/// # Panics
+/// - if `bases_and_exponents` is empty.
+#[cfg(feature = "alloc")]
+pub(crate) fn multi_exponentiate_vartime<M: Monty, E: AsRef<[Limb]>>(
+    bases_and_exponents: &[(M, E)],
+    exponent_bits: u32,
+) -> M {
+    assert!(
+        !bases_and_exponents.is_empty(),
+        "bases_and_exponents must not be empty"
+    );
+    let params = bases_and_exponents[0].0.params();
+    let mut multiplier = M::Multiplier::from(params);
+
+    // Skip the leading zero bits shared by all of the exponents
+    let exponent_bits = bases_and_exponents
+        .iter()
+        .map(|(_, exponent)| UintRef::new(exponent.as_ref()).bits_vartime())
+        .max()
+        .unwrap_or(0)
+        .min(exponent_bits);
+    if exponent_bits == 0 {
+        // Go through `pow_bounded_exp` so that `one` is reduced for the trivial modulus
+        let base = &bases_and_exponents[0].0;
+        return base.pow_bounded_exp(&Zero::zero_like(&base.retrieve()), 0);
+    }
+
+    let window = pippenger_window_size(bases_and_exponents.len(), exponent_bits);
+
+    // `buckets[i]` contains the product of the bases whose current digit is `i + 1`, with `None`
+    // standing for the empty product
+    let mut buckets: Vec<Option<M>> = vec![None; (1 << window) - 1];
+    let mut z: Option<M> = None;
+
+    let mut bit = exponent_bits.div_ceil(window) * window;
+    while bit > 0 {
+        bit -= window;
+
+        if let Some(z) = z.as_mut() {
+            for _ in 0..window {
+                multiplier.square_assign(z);
+            }
+        }
+
+        for (base, exponent) in bases_and_exponents {
+            let digit = pippenger_digit(exponent.as_ref(), bit, window, exponent_bits);
+            if digit != 0 {
+                mul_assign_or_set(&mut multiplier, &mut buckets[digit - 1], base);
+            }
+        }
+
+        // Compute the product of `buckets[i]^(i + 1)` from the top bucket down, as the product of
+        // the running products of the buckets
+        let mut running = None;
+        let mut sum = None;
+        for bucket in buckets.iter_mut().rev() {
+            if let Some(bucket) = bucket.take() {
+                mul_assign_or_set(&mut multiplier, &mut running, &bucket);
+            }
+            if let Some(running) = &running {
+                mul_assign_or_set(&mut multiplier, &mut sum, running);
+            }
+        }
+
+        if let Some(sum) = &sum {
+            mul_assign_or_set(&mut multiplier, &mut z, sum);
+        }
+    }
+
+    z.unwrap_or_else(|| M::one(params.clone()))
+}
+
+/// Select the bucket width which minimizes the number of multiplications needed by Pippenger's
+/// method for `count` exponents which are `exponent_bits` long.
+///
+/// Every window takes one multiplication per base to fill the buckets, and about two per bucket to
+/// combine them. The number of squarings doesn't depend on the width.
+#[cfg(feature = "alloc")]
+pub(crate) const fn pippenger_window_size(count: usize, exponent_bits: u32) -> u32 {
+    let mut best_window = 1;
+    let mut best_cost = u64::MAX;
+    let mut window = 1;
+    while window <= MAX_PIPPENGER_WINDOW {
+        let windows = exponent_bits.div_ceil(window) as u64;
+        let cost = windows * (count as u64 + (1 << (window + 1)));
+        if cost < best_cost {
+            best_cost = cost;
+            best_window = window;
+        }
+        window += 1;
+    }
+
+    best_window
+}
+
+/// Returns the digit made of the `window` bits of `exponent` starting at `bit`, ignoring the bits
+/// at and above `exponent_bits`.
+#[cfg(feature = "alloc")]
+fn pippenger_digit(exponent: &[Limb], bit: u32, window: u32, exponent_bits: u32) -> usize {
+    let len = window.min(exponent_bits - bit);
+    let limb = (bit / Limb::BITS) as usize;
+    let shift = bit % Limb::BITS;
+
+    let mut digit = exponent.get(limb).map_or(0, |limb| limb.0 >> shift);
+    if shift + len > Limb::BITS {
+        digit |= exponent
+            .get(limb + 1)
+            .map_or(0, |limb| limb.0 << (Limb::BITS - shift));
+    }
+
+    (digit & ((1 << len) - 1)) as usize
+}
+
+/// Multiplies `lhs` by `rhs`, where `None` stands for one.
+#[cfg(feature = "alloc")]
+fn mul_assign_or_set<'a, M: Monty>(
+    multiplier: &mut M::Multiplier<'a>,
+    lhs: &mut Option<M>,
+    rhs: &M,
+) {
+    match lhs {
+        Some(lhs) => multiplier.mul_assign(lhs, rhs),
+        None => *lhs = Some(rhs.clone()),
+    }
+}
+
+/// Select the sliding window size which minimizes the number of multiplications needed to raise
+/// to `exponent`, whose bit length is `exponent_bits`.
+///
+/// Plain square-and-multiply needs no precomputation and one multiplication for each set bit but
+/// the first, so it is selected for sparse exponents such as 65537.
+pub(crate) const fn vartime_window_size(exponent: &[Limb], exponent_bits: u32) -> u32 {
+    let mut ones = 0;
+    let mut i = 0;
+    while i < exponent.len() {
+        ones += exponent[i].0.count_ones();
+        i += 1;
+    }
+
+    let mut best_window = 1;
+    let mut best_cost = ones.saturating_sub(1);
+    let mut window = 2;
+    while window <= MAX_VARTIME_WINDOW {
+        // Precomputing the odd powers, then about one multiplication every `window + 1` bits
+        let cost = (1 << (window - 1)) + exponent_bits / (window + 1);
+        if cost < best_cost {
+            best_cost = cost;
+            best_window = window;
+        }
+        window += 1;
+    }
+
+    best_window
+}
+
+/// Returns the length and value of the next window of a sliding-window exponentiation, made of
+/// the bits of `exponent` right below `bit`.
+///
+/// The value is either zero for a single zero bit, or odd and less than `2^window`.
+pub(crate) const fn next_vartime_window(exponent: &[Limb], bit: u32, window: u32) -> (u32, Word) {
+    let top = bit - 1;
+    if (exponent[(top / Limb::BITS) as usize].0 >> (top % Limb::BITS)) & 1 == 0 {
+        return (1, 0);
+    }
+
+    let mut len = if window < bit { window } else { bit };
+    let mut value = 0;
+    let mut i = 0;
+    while i < len {
+        let index = top - i;
+        let b = (exponent[(index / Limb::BITS) as usize].0 >> (index % Limb::BITS)) & 1;
+        value = (value << 1) | b;
+        i += 1;
+    }
+
+    // Trailing zero bits are handled by squarings in the next windows
+    let zeros = value.trailing_zeros();
+    value >>= zeros;
+    len -= zeros;
+
+    (len, value)
+}
+
 /// Performs modular exponentiation using "Almost Montgomery Multiplication".
 ///
 /// NOTE: the resulting output will be reduced to the *bit length* of the modulus, but not fully
@@ -42,7 +328,6 @@ pub const fn pow_montgomery_form<const LIMBS: usize, const RHS_LIMBS: usize>(
 /// NOTE: `exponent_bits` is leaked in the time pattern.
 // NOTE: this function is intended to work without alloc, so we `allow(dead_code)` to ensure such
 #[cfg_attr(not(feature = "alloc"), allow(dead_code))] // TODO(tarcieri): use w\ `MontyForm`
-#[allow(clippy::needless_range_loop)]
 pub fn pow_montgomery_form_amm<'a, U>(
     x: &U,
     exponent: &U,
@@ -54,26 +339,45 @@ where
     <U::Monty as Monty>::Multiplier<'a>: AmmMultiplier<'a>,
 {
     let one = U::Monty::one(params.clone()).as_montgomery().clone();
+    let mut multiplier = Amm(<U::Monty as Monty>::Multiplier::from(params));
 
+    pow_bounded_exp(
+        x,
+        UintRef::new(exponent.as_limbs()),
+        exponent_bits,
+        &one,
+        &mut multiplier,
+    )
+}
+
+/// Performs exponentiation with a fixed window, with the products computed by `multiplier`.
+/// `exponent_bits` represents the number of (least significant) bits to take into account for the
+/// exponent.
+///
+/// NOTE: `exponent_bits` is leaked in the time pattern.
+#[allow(clippy::needless_range_loop)]
+pub(crate) fn pow_bounded_exp<T: Clone + CtSelect, M: PowMultiplier<T>>(
+    x: &T,
+    exponent: &UintRef,
+    exponent_bits: u32,
+    one: &T,
+    multiplier: &mut M,
+) -> T {
     if exponent_bits == 0 {
-        return one.clone(); // 1 in Montgomery form
+        return one.clone();
     }
 
-    const WINDOW: u32 = 4;
-    const WINDOW_MASK: Word = (1 << WINDOW) - 1;
-
-    let mut multiplier = <U::Monty as Monty>::Multiplier::from(params);
     let mut power = x.clone();
 
     // powers[i] contains x^i
-    let powers: [U; 1 << WINDOW] = array::from_fn(|n| {
+    let powers: [T; 1 << WINDOW] = array::from_fn(|n| {
         if n == 0 {
             one.clone()
         } else if n == (1 << WINDOW) - 1 {
             power.clone()
         } else {
             let mut new_power = power.clone();
-            multiplier.mul_amm_assign(&mut new_power, x);
+            multiplier.mul_assign(&mut new_power, x);
 
             mem::swap(&mut power, &mut new_power);
             new_power
@@ -85,11 +389,10 @@ where
     let starting_window = starting_bit_in_limb / WINDOW;
     let starting_window_mask = (1 << (starting_bit_in_limb % WINDOW + 1)) - 1;
 
-    let mut z = one.clone(); // 1 in Montgomery form
-    let mut power = powers[0].clone();
+    let mut z = one.clone();
 
     for limb_num in (0..=starting_limb).rev() {
-        let w = exponent.as_limbs()[limb_num].0;
+        let w = exponent.as_slice()[limb_num].0;
 
         let mut window_num = if limb_num == starting_limb {
             starting_window + 1
@@ -106,17 +409,16 @@ where
                 idx &= starting_window_mask;
             } else {
                 for _ in 1..=WINDOW {
-                    multiplier.square_amm_assign(&mut z);
+                    multiplier.square_assign(&mut z);
                 }
             }
 
-            // Constant-time lookup in the array of powers
-            power.as_mut_limbs().copy_from_slice(powers[0].as_limbs());
-            for i in 1..(1 << WINDOW) {
+            // Constant-time lookup in the array of powers, exactly one of which is selected
+            for i in 0..(1 << WINDOW) {
                 power.ct_assign(&powers[i], (i as Word).ct_eq(&idx));
             }
 
-            multiplier.mul_amm_assign(&mut z, &power);
+            multiplier.mul_assign(&mut z, &power);
         }
     }
 
@@ -0,0 +1,211 @@
+//! Arithmetic modulo powers `m^k` of an odd modulus `m`.
+
+use super::{BoxedMontyForm, BoxedMontyParams};
+use crate::{CtEq, CtOption, Odd, Resize};
+use alloc::vec::Vec;
+
+/// Montgomery parameters for the powers `m, m^2, ..., m^K` of an odd modulus `m`, as used by
+/// e.g. the Paillier (`K = 2`) and Damgård–Jurik (`K = s + 1`) cryptosystems.
+///
+/// The parameters for `m^k` have `k` times the precision of those for `m`, so that the exponent
+/// of the modulus of a [`BoxedMontyForm`] is determined by its precision. Residues can be moved
+/// between consecutive powers with [`Self::lift`] and [`Self::reduce`], and inverses modulo `m^k`
+/// can be lifted to `m^(k+1)` by Hensel lifting with [`Self::lift_inverse`].
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct PowerModulus {
+    /// `params[i]` are the Montgomery parameters for `m^(i+1)`
+    params: Vec<BoxedMontyParams>,
+}
+
+impl PowerModulus {
+    /// Compute the Montgomery parameters for the powers of the modulus of `params` up to
+    /// `m^max_exponent`.
+    ///
+    /// This method is constant-time with respect to the modulus, but not `max_exponent`.
+    ///
+    /// # Panics
+    /// - if `max_exponent` is zero.
+    pub fn new(params: BoxedMontyParams, max_exponent: u32) -> Self {
+        assert!(max_exponent > 0, "max_exponent must be non-zero");
+
+        let modulus = params.modulus().as_ref().clone();
+        let bits_precision = params.bits_precision();
+        let mut power = modulus.clone();
+
+        let mut powers = Vec::with_capacity(max_exponent as usize);
+        powers.push(params);
+        for k in 2..=max_exponent {
+            // `m^k < 2^(k * bits_precision)`, so this can't overflow
+            let precision = k * bits_precision;
+            power = power
+                .resize(precision)
+                .wrapping_mul(&(&modulus).resize(precision));
+            powers.push(BoxedMontyParams::new(Odd(power.clone())));
+        }
+
+        Self { params: powers }
+    }
+
+    /// Returns the largest exponent `K` for which parameters are available.
+    pub fn max_exponent(&self) -> u32 {
+        self.params.len() as u32
+    }
+
+    /// Returns the Montgomery parameters for `m^k`.
+    ///
+    /// # Panics
+    /// - if `k` is zero or greater than [`Self::max_exponent`].
+    pub fn params(&self, k: u32) -> &BoxedMontyParams {
+        assert!(
+            k > 0 && k <= self.max_exponent(),
+            "exponent must be between 1 and max_exponent"
+        );
+        &self.params[k as usize - 1]
+    }
+
+    /// Returns the exponent `k` such that `x` is a residue modulo `m^k`.
+    ///
+    /// # Panics
+    /// - if `x` is not a residue modulo any of the powers of `m`.
+    pub fn exponent_of(&self, x: &BoxedMontyForm) -> u32 {
+        let k = x.bits_precision() / self.params[0].bits_precision();
+        assert!(
+            k > 0 && k <= self.max_exponent() && x.params() == self.params(k),
+            "x is not a residue modulo a power of m"
+        );
+        k
+    }
+
+    /// Maps a residue `x mod m^k` to the residue modulo `m^(k+1)` with the same canonical value.
+    ///
+    /// # Panics
+    /// - if `x` is not a residue modulo `m^k` for some `k < K`.
+    pub fn lift(&self, x: &BoxedMontyForm) -> BoxedMontyForm {
+        let params = self.params(self.exponent_of(x) + 1);
+        BoxedMontyForm::new(x.retrieve().resize(params.bits_precision()), params.clone())
+    }
+
+    /// Reduces a residue `x mod m^(k+1)` to the residue `x mod m^k`.
+    ///
+    /// # Panics
+    /// - if `x` is not a residue modulo `m^(k+1)` for some `k > 0`.
+    pub fn reduce(&self, x: &BoxedMontyForm) -> BoxedMontyForm {
+        let k = self.exponent_of(x) - 1;
+        let params = self.params(k);
+        let value = x.retrieve();
+        let modulus = params.modulus().resize(value.bits_precision());
+        let reduced = value.rem(modulus.as_nz_ref());
+        BoxedMontyForm::new(
+            reduced.resize_unchecked(params.bits_precision()),
+            params.clone(),
+        )
+    }
+
+    /// Lifts the inverse `inverse = x^-1 mod m^k` of `x mod m^(k+1)` to `x^-1 mod m^(k+1)`, using
+    /// a Hensel (Newton) step `y' = y * (2 - x * y)`.
+    ///
+    /// The Newton step takes two multiplications, plus a third one to check the result, instead of
+    /// a full inversion modulo `m^(k+1)`.
+    ///
+    /// Returns none if `inverse` was not the inverse of `x mod m^k`.
+    ///
+    /// This method is constant-time with respect to the values of `x` and `inverse`.
+    ///
+    /// # Panics
+    /// - if `x` is not a residue modulo `m^(k+1)`, or `inverse` is not one modulo `m^k`.
+    pub fn lift_inverse(
+        &self,
+        x: &BoxedMontyForm,
+        inverse: &BoxedMontyForm,
+    ) -> CtOption<BoxedMontyForm> {
+        assert_eq!(
+            self.exponent_of(x),
+            self.exponent_of(inverse) + 1,
+            "x and inverse must be residues modulo m^(k+1) and m^k"
+        );
+
+        let y = self.lift(inverse);
+        let one = BoxedMontyForm::one(x.params().clone());
+        let ret = &y * &(one.double() - x * &y);
+        let is_some = (x * &ret).ct_eq(&one);
+        CtOption::new(ret, is_some)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::PowerModulus;
+    use crate::{
+        BoxedUint, Odd, Resize,
+        modular::{BoxedMontyForm, BoxedMontyParams},
+    };
+
+    fn power_modulus() -> PowerModulus {
+        // Product of the 64-bit primes 2^64 - 59 and 2^64 - 83, as a Paillier modulus
+        let n = BoxedUint::from_be_hex("ffffffffffffff720000000000001321", 128).unwrap();
+        PowerModulus::new(BoxedMontyParams::new(Odd::new(n).unwrap()), 3)
+    }
+
+    #[test]
+    fn powers() {
+        let pm = power_modulus();
+        let n = pm.params(1).modulus().as_ref().clone();
+        assert_eq!(pm.max_exponent(), 3);
+
+        let n2 = pm.params(2).modulus();
+        assert_eq!(n2.bits_precision(), 256);
+        assert_eq!(n2.as_ref(), &n.mul(&n));
+
+        let n3 = pm.params(3).modulus();
+        assert_eq!(n3.bits_precision(), 384);
+        assert_eq!(
+            n3.as_ref(),
+            &n.mul(&n).resize(384).wrapping_mul(&n.resize(384))
+        );
+    }
+
+    #[test]
+    fn lift_and_reduce() {
+        let pm = power_modulus();
+        let x = BoxedMontyForm::new(BoxedUint::from(12345u64).resize(128), pm.params(1).clone());
+
+        let lifted = pm.lift(&x);
+        assert_eq!(pm.exponent_of(&lifted), 2);
+        assert_eq!(lifted.retrieve(), x.retrieve().resize(256));
+        assert_eq!(pm.reduce(&lifted), x);
+
+        // `(1 + n)^a = 1 + a * n mod n^2`, as used for Paillier encryption
+        let n = pm.params(1).modulus().as_ref().resize(256);
+        let g = BoxedMontyForm::new(n.wrapping_add(&BoxedUint::one()), pm.params(2).clone());
+        let a = BoxedUint::from(777u64).resize(256);
+        let expected = a.wrapping_mul(&n).wrapping_add(&BoxedUint::one());
+        assert_eq!(g.pow(&a).retrieve(), expected);
+        assert_eq!(
+            pm.reduce(&g.pow(&a)).retrieve(),
+            BoxedUint::one().resize(128)
+        );
+    }
+
+    #[test]
+    fn lift_inverse() {
+        let pm = power_modulus();
+        let value = BoxedUint::from_be_hex(
+            "0123456789abcdef0123456789abcdef0123456789abcdef0123456789abcdef",
+            256,
+        )
+        .unwrap();
+
+        let x = BoxedMontyForm::new(value, pm.params(2).clone());
+        let inverse = pm.reduce(&x).invert().unwrap();
+        let lifted = pm.lift_inverse(&x, &inverse).unwrap();
+        assert_eq!(lifted, x.invert().unwrap());
+
+        let x3 = pm.lift(&x);
+        let lifted3 = pm.lift_inverse(&x3, &lifted).unwrap();
+        assert_eq!(lifted3, x3.invert().unwrap());
+
+        // Not an inverse
+        let wrong = BoxedMontyForm::one(pm.params(1).clone()).double();
+        assert!(bool::from(pm.lift_inverse(&x, &wrong).is_none()));
+    }
+}
@@ -0,0 +1,243 @@
+//! Reduction modulo special-form moduli, i.e. pseudo-Mersenne primes.
+
+mod element;
+#[macro_use]
+mod macros;
+
+pub use element::{SpecialFormElement, SpecialFormParams};
+
+use super::pow::PowMultiplier;
+use crate::{Limb, Odd, Uint, Word, uint::mul_mod::mac_by_limb};
+
+/// Special form `p = 2^k - c` of a modulus, where `c` is a small odd constant which fits in a
+/// single word, such as `2^255 - 19` or the secp256k1 prime `2^256 - 2^32 - 977`.
+///
+/// Since `2^k ≡ c (mod p)`, the high limbs of a product can be folded back onto the low limbs by
+/// multiplying them by a single word, which takes far fewer limb multiplications than Montgomery
+/// reduction. Generalized Mersenne (Solinas) primes such as the NIST P-256 modulus, whose `c` is
+/// much larger, do not benefit from this and are not supported: use
+/// [`ConstMontyForm`](crate::modular::ConstMontyForm) for them instead.
+///
+/// Use the [`const_special_form_params!`][`crate::const_special_form_params`] macro to define a
+/// [`SpecialFormParams`] type for a special-form modulus, whose [`SpecialFormElement`]s reduce
+/// products modulo it.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct SpecialForm {
+    /// Bit length of the modulus
+    k: u32,
+    /// Difference between `2^k` and the modulus
+    c: Word,
+}
+
+impl SpecialForm {
+    /// Create the special form `2^k - c`.
+    ///
+    /// # Panics
+    /// - if `c` is even, as the modulus must be odd.
+    pub const fn new(k: u32, c: Word) -> Self {
+        assert!(c & 1 == 1, "c must be odd");
+        Self { k, c }
+    }
+
+    /// Returns the bit length `k` of the modulus.
+    pub const fn k(&self) -> u32 {
+        self.k
+    }
+
+    /// Returns the constant `c`.
+    pub const fn c(&self) -> Word {
+        self.c
+    }
+
+    /// Compute the modulus `2^k - c`.
+    ///
+    /// # Panics
+    /// - if `Uint<LIMBS>` has less than two limbs;
+    /// - if `k` doesn't fall within the most significant limb of `Uint<LIMBS>`;
+    /// - if `c * 2^(BITS - k)` is not below `2^(Limb::BITS - 1)`.
+    pub const fn modulus<const LIMBS: usize>(&self) -> Odd<Uint<LIMBS>> {
+        assert!(LIMBS >= 2, "special form requires at least two limbs");
+        assert!(
+            self.k <= Uint::<LIMBS>::BITS && self.k > Uint::<LIMBS>::BITS - Limb::BITS,
+            "k must fall within the most significant limb"
+        );
+        let shift = Uint::<LIMBS>::BITS - self.k;
+        assert!(
+            self.c >> (Limb::BITS - 1 - shift) == 0,
+            "c * 2^(BITS - k) must be below 2^(Limb::BITS - 1)"
+        );
+
+        // `2^k` wraps around to zero when `k` is the full precision, but the modulus does not
+        let modulus = Uint::ONE
+            .wrapping_shl_vartime(self.k)
+            .wrapping_sub(&Uint::from_word(self.c));
+        Odd(modulus)
+    }
+
+    /// Computes `a * b mod modulus` for `a, b < modulus`.
+    const fn mul<const LIMBS: usize>(
+        &self,
+        a: &Uint<LIMBS>,
+        b: &Uint<LIMBS>,
+        modulus: &Uint<LIMBS>,
+    ) -> Uint<LIMBS> {
+        self.reduce_wide(a.widening_mul(b), modulus)
+    }
+
+    /// Computes `a * a mod modulus` for `a < modulus`.
+    const fn square<const LIMBS: usize>(
+        &self,
+        a: &Uint<LIMBS>,
+        modulus: &Uint<LIMBS>,
+    ) -> Uint<LIMBS> {
+        self.reduce_wide(a.square_wide(), modulus)
+    }
+
+    /// Computes `x mod modulus` for any `x`.
+    const fn reduce<const LIMBS: usize>(
+        &self,
+        x: &Uint<LIMBS>,
+        modulus: &Uint<LIMBS>,
+    ) -> Uint<LIMBS> {
+        self.reduce_wide((*x, Uint::ZERO), modulus)
+    }
+
+    /// Computes `(lo + hi * 2^BITS) mod modulus` for any `lo` and `hi`.
+    ///
+    /// This generalizes Algorithm 14.47 from the "Handbook of Applied Cryptography", as used by
+    /// [`Uint::mul_mod_special`], to moduli below `2^BITS`.
+    const fn reduce_wide<const LIMBS: usize>(
+        &self,
+        (lo, hi): (Uint<LIMBS>, Uint<LIMBS>),
+        modulus: &Uint<LIMBS>,
+    ) -> Uint<LIMBS> {
+        // `2^BITS = 2^shift * 2^k ≡ 2^shift * c`, which `SpecialForm::modulus` checks is below
+        // `2^(Limb::BITS - 1)`
+        let shift = Uint::<LIMBS>::BITS - self.k;
+        let fold = Limb(self.c << shift);
+
+        // `lo + hi * fold`, leaving a carry limb of at most `fold`
+        let (mut ret, mut carry) = mac_by_limb(&lo, &hi, fold, Limb::ZERO);
+
+        // Fold the carry limb in again. The product is below `2^(2 * Limb::BITS - 2)`, so this
+        // can only carry out if the result wraps around to a value below that, and folding in the
+        // final carry bit then can't overflow.
+        (ret.limbs[0], carry) = carry.carrying_mul_add(fold, ret.limbs[0], Limb::ZERO);
+        let mut i = 1;
+        while i < LIMBS {
+            (ret.limbs[i], carry) = ret.limbs[i].overflowing_add(carry);
+            i += 1;
+        }
+        (ret, _) = ret.overflowing_add_limb(Limb::select(Limb::ZERO, fold, carry.is_nonzero()));
+
+        // Fold the bits above `k` into the bottom, leaving `ret < 2^k + fold`
+        if shift > 0 {
+            let top = ret.limbs[LIMBS - 1].0 >> (Limb::BITS - shift);
+            ret.limbs[LIMBS - 1] = Limb(ret.limbs[LIMBS - 1].0 & (Word::MAX >> shift));
+            (ret, _) = ret.overflowing_add_limb(Limb(top * self.c));
+        }
+
+        // `ret < 2 * modulus`, since `fold + 2c < 2^k`
+        let (diff, borrow) = ret.borrowing_sub(modulus, Limb::ZERO);
+        Uint::select(&diff, &ret, borrow.is_nonzero())
+    }
+}
+
+/// Multiplication modulo a [`SpecialForm`] modulus, used by the generic exponentiation routines.
+struct SpecialFormMultiplier<const LIMBS: usize> {
+    form: SpecialForm,
+    modulus: Odd<Uint<LIMBS>>,
+}
+
+impl<const LIMBS: usize> PowMultiplier<Uint<LIMBS>> for SpecialFormMultiplier<LIMBS> {
+    fn mul_assign(&mut self, lhs: &mut Uint<LIMBS>, rhs: &Uint<LIMBS>) {
+        *lhs = self.form.mul(lhs, rhs, self.modulus.as_ref());
+    }
+
+    fn square_assign(&mut self, lhs: &mut Uint<LIMBS>) {
+        *lhs = self.form.square(lhs, self.modulus.as_ref());
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::SpecialForm;
+    use crate::{Limb, NonZero, U128, U256, Uint};
+
+    const P25519: SpecialForm = SpecialForm::new(255, 19);
+
+    /// Check reduction of extreme values and products modulo `form`.
+    fn check_reduce<const LIMBS: usize>(form: SpecialForm) {
+        let p = form.modulus::<LIMBS>();
+        let nz = p.as_nz_ref();
+        let max = Uint::<LIMBS>::MAX;
+        let a = Uint::<LIMBS>::from_u64(0x14bfae46f4026e97)
+            .wrapping_mul(&max.shr1())
+            .rem(nz);
+        let b = p.wrapping_sub(&Uint::ONE);
+
+        assert_eq!(form.reduce(&max, &p), max.rem(nz));
+        assert_eq!(form.reduce(&p, &p), Uint::ZERO);
+        assert_eq!(
+            form.reduce_wide((max, max), &p),
+            Uint::rem_wide_vartime((max, max), nz)
+        );
+        assert_eq!(form.mul(&a, &b, &p), a.mul_mod(&b, nz));
+        assert_eq!(form.mul(&b, &b, &p), Uint::ONE);
+        assert_eq!(form.square(&b, &p), Uint::ONE);
+        assert_eq!(form.square(&a, &p), a.square_mod(nz));
+    }
+
+    #[test]
+    fn modulus() {
+        assert_eq!(
+            P25519.modulus::<{ U256::LIMBS }>().get(),
+            U256::from_be_hex("7fffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffed")
+        );
+        assert_eq!(
+            SpecialForm::new(127, 1).modulus::<{ U128::LIMBS }>().get(),
+            U128::MAX.shr1()
+        );
+    }
+
+    #[test]
+    #[should_panic]
+    fn even_c() {
+        SpecialForm::new(255, 18);
+    }
+
+    #[test]
+    #[should_panic]
+    fn k_below_top_limb() {
+        SpecialForm::new(127, 1).modulus::<{ U256::LIMBS }>();
+    }
+
+    #[test]
+    #[should_panic]
+    fn c_too_large() {
+        SpecialForm::new(255, 1 << (Limb::BITS - 2)).modulus::<{ U256::LIMBS }>();
+    }
+
+    #[test]
+    fn reduce() {
+        check_reduce::<{ U256::LIMBS }>(P25519);
+        check_reduce::<{ U128::LIMBS }>(SpecialForm::new(127, 1));
+        check_reduce::<{ U128::LIMBS }>(SpecialForm::new(128, 159));
+
+        // 2^521 - 1
+        #[cfg(target_pointer_width = "64")]
+        check_reduce::<{ crate::U576::LIMBS }>(SpecialForm::new(521, 1));
+    }
+
+    #[test]
+    fn reduce_large_c() {
+        // The largest `c` for which `2^256 - c` is supported
+        let form = SpecialForm::new(256, (1 << (Limb::BITS - 1)) - 1);
+        check_reduce::<{ U256::LIMBS }>(form);
+
+        let p = form.modulus::<{ U256::LIMBS }>();
+        let nz = NonZero::new(p.get()).unwrap();
+        let x = p.wrapping_sub(&U256::from_u8(2));
+        assert_eq!(form.square(&x, &p), x.square_mod(&nz));
+    }
+}
@@ -0,0 +1,450 @@
+//! Integers modulo a constant special-form modulus.
+
+use super::{SpecialForm, SpecialFormMultiplier};
+use crate::{
+    Choice, CtEq, CtOption, CtSelect, Invert, ModularRing, Odd, PowBoundedExp, Square, Uint, Word,
+    modular::{
+        Retrieve,
+        add::{add_montgomery_form, double_montgomery_form},
+        pow::{pow_bounded_exp, pow_vartime},
+        safegcd::invert_odd_mod,
+        sqrt::{find_non_residue_vartime, sqrt, two_adicity},
+        sub::sub_montgomery_form,
+    },
+};
+use core::{
+    fmt::Debug,
+    marker::PhantomData,
+    ops::{Add, AddAssign, Mul, MulAssign, Neg, Sub, SubAssign},
+};
+
+#[cfg(feature = "rand_core")]
+use crate::{Random, RandomMod, rand_core::TryRngCore};
+
+/// Trait representing a modulus with a [`SpecialForm`].
+///
+/// To define a type which impls this trait, use the
+/// [`const_special_form_params!`][`crate::const_special_form_params`] macro.
+pub trait SpecialFormParams<const LIMBS: usize>:
+    Copy + Debug + Default + Eq + Send + Sync + 'static
+{
+    /// Number of limbs required to encode the modulus
+    const LIMBS: usize;
+
+    /// Special form `2^k - c` of the modulus.
+    const FORM: SpecialForm;
+}
+
+/// An integer modulo `MOD`, whose modulus has a [`SpecialForm`], represented using `LIMBS` limbs.
+/// The modulus is constant, so it cannot be set at runtime.
+///
+/// Unlike [`ConstMontyForm`](crate::modular::ConstMontyForm), the value is stored as is, and
+/// products are reduced by folding their high limbs onto the low ones, multiplied by the single
+/// word `c` of the special form, rather than with Montgomery reduction. It provides the same
+/// operations as [`ConstMontyForm`](crate::modular::ConstMontyForm), such as `pow`, `invert`,
+/// `sqrt` and `lincomb`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct SpecialFormElement<MOD: SpecialFormParams<LIMBS>, const LIMBS: usize> {
+    value: Uint<LIMBS>,
+    phantom: PhantomData<MOD>,
+}
+
+#[cfg(feature = "zeroize")]
+impl<MOD: SpecialFormParams<LIMBS>, const LIMBS: usize> zeroize::DefaultIsZeroes
+    for SpecialFormElement<MOD, LIMBS>
+{
+}
+
+impl<MOD: SpecialFormParams<LIMBS>, const LIMBS: usize> SpecialFormElement<MOD, LIMBS> {
+    /// The representation of 0 mod `MOD`.
+    pub const ZERO: Self = Self::from_reduced(Uint::ZERO);
+
+    /// The representation of 1 mod `MOD`.
+    pub const ONE: Self = Self::from_reduced(Uint::ONE);
+
+    /// Modulus as an unsigned integer.
+    pub const MODULUS: Odd<Uint<LIMBS>> = MOD::FORM.modulus();
+
+    /// Instantiates a new [`SpecialFormElement`] that represents this `integer` mod `MOD`.
+    pub const fn new(integer: &Uint<LIMBS>) -> Self {
+        Self::from_reduced(MOD::FORM.reduce(integer, Self::MODULUS.as_ref()))
+    }
+
+    /// Retrieves the integer currently encoded in this [`SpecialFormElement`], guaranteed to be
+    /// reduced.
+    pub const fn retrieve(&self) -> Uint<LIMBS> {
+        self.value
+    }
+
+    /// Adds `rhs`.
+    pub const fn add(&self, rhs: &Self) -> Self {
+        Self::from_reduced(add_montgomery_form(&self.value, &rhs.value, &Self::MODULUS))
+    }
+
+    /// Double `self`.
+    pub const fn double(&self) -> Self {
+        Self::from_reduced(double_montgomery_form(&self.value, &Self::MODULUS))
+    }
+
+    /// Subtracts `rhs`.
+    pub const fn sub(&self, rhs: &Self) -> Self {
+        Self::from_reduced(sub_montgomery_form(&self.value, &rhs.value, &Self::MODULUS))
+    }
+
+    /// Negates the number.
+    pub const fn neg(&self) -> Self {
+        Self::from_reduced(self.value.neg_mod(Self::MODULUS.as_nz_ref()))
+    }
+
+    /// Multiplies by `rhs`.
+    pub const fn mul(&self, rhs: &Self) -> Self {
+        Self::from_reduced(MOD::FORM.mul(&self.value, &rhs.value, Self::MODULUS.as_ref()))
+    }
+
+    /// Computes the (reduced) square.
+    pub const fn square(&self) -> Self {
+        Self::from_reduced(MOD::FORM.square(&self.value, Self::MODULUS.as_ref()))
+    }
+
+    /// Calculate the sum of products of pairs `(a, b)` in `products`.
+    pub const fn lincomb(products: &[(Self, Self)]) -> Self {
+        let mut ret = Self::ZERO;
+        let mut i = 0;
+        while i < products.len() {
+            ret = Self::add(&ret, &Self::mul(&products[i].0, &products[i].1));
+            i += 1;
+        }
+        ret
+    }
+
+    /// Raises to the `exponent` power.
+    pub fn pow<const RHS_LIMBS: usize>(&self, exponent: &Uint<RHS_LIMBS>) -> Self {
+        self.pow_bounded_exp(exponent, Uint::<RHS_LIMBS>::BITS)
+    }
+
+    /// Raises to the `exponent` power,
+    /// with `exponent_bits` representing the number of (least significant) bits
+    /// to take into account for the exponent.
+    ///
+    /// NOTE: `exponent_bits` may be leaked in the time pattern.
+    pub fn pow_bounded_exp<const RHS_LIMBS: usize>(
+        &self,
+        exponent: &Uint<RHS_LIMBS>,
+        exponent_bits: u32,
+    ) -> Self {
+        Self::from_reduced(pow_bounded_exp(
+            &self.value,
+            exponent.as_uint_ref(),
+            exponent_bits,
+            &Uint::ONE,
+            &mut Self::multiplier(),
+        ))
+    }
+
+    /// Raises to the `exponent` power using sliding windows.
+    ///
+    /// This method is variable-time with respect to `exponent`, and meant for public exponents.
+    pub fn pow_vartime<const RHS_LIMBS: usize>(&self, exponent: &Uint<RHS_LIMBS>) -> Self {
+        Self::from_reduced(pow_vartime(
+            &self.value,
+            exponent.as_uint_ref(),
+            || Uint::ONE,
+            &mut Self::multiplier(),
+        ))
+    }
+
+    /// Computes `self^-1` representing the multiplicative inverse of `self`,
+    /// i.e. `self * self^-1 = 1`.
+    ///
+    /// If the number was invertible, the second element of the tuple is the truthy value,
+    /// otherwise it is the falsy value (in which case the first element's value is unspecified).
+    pub const fn invert(&self) -> CtOption<Self> {
+        let maybe_inverse = invert_odd_mod::<LIMBS, false>(&self.value, &Self::MODULUS);
+        CtOption::new(
+            Self::from_reduced(maybe_inverse.to_inner_unchecked()),
+            maybe_inverse.is_some(),
+        )
+    }
+
+    /// Computes `self^-1` representing the multiplicative inverse of `self`,
+    /// i.e. `self * self^-1 = 1`.
+    ///
+    /// This version is variable-time with respect to the value of `self`, but constant-time with
+    /// respect to `MOD`.
+    pub const fn invert_vartime(&self) -> CtOption<Self> {
+        let maybe_inverse = invert_odd_mod::<LIMBS, true>(&self.value, &Self::MODULUS);
+        CtOption::new(
+            Self::from_reduced(maybe_inverse.to_inner_unchecked()),
+            maybe_inverse.is_some(),
+        )
+    }
+
+    /// Compute a square root of `self` modulo the prime modulus, i.e. a value `r` such that
+    /// `r * r == self`.
+    ///
+    /// Returns `None` if `self` is not a quadratic residue.
+    ///
+    /// The 2-adicity of the modulus and the quadratic non-residue used by the Tonelli-Shanks
+    /// algorithm are computed at compile time.
+    ///
+    /// This method is constant-time with respect to `self`.
+    ///
+    /// # Panics
+    /// - if `modulus ≡ 1 (mod 8)` and it has no quadratic non-residue below 1024, which can only
+    ///   happen if it is not prime.
+    pub fn sqrt(&self) -> CtOption<Self> {
+        sqrt(self, Self::MODULUS.as_ref(), Self::SQRT_TWO_ADICITY, || {
+            Self::SQRT_NON_RESIDUE
+        })
+    }
+
+    /// Largest `s` such that `2^s` divides `modulus - 1`.
+    const SQRT_TWO_ADICITY: u32 = two_adicity(Self::MODULUS.as_ref().as_uint_ref());
+
+    /// Smallest quadratic non-residue modulo `modulus` (zero if none was found).
+    const SQRT_NON_RESIDUE: Word = find_non_residue_vartime(Self::MODULUS.as_ref().as_uint_ref());
+
+    /// Wraps a value which is already reduced modulo `MOD`.
+    const fn from_reduced(value: Uint<LIMBS>) -> Self {
+        Self {
+            value,
+            phantom: PhantomData,
+        }
+    }
+
+    /// Multiplier used by the exponentiation routines.
+    const fn multiplier() -> SpecialFormMultiplier<LIMBS> {
+        SpecialFormMultiplier {
+            form: MOD::FORM,
+            modulus: Self::MODULUS,
+        }
+    }
+}
+
+impl<MOD: SpecialFormParams<LIMBS>, const LIMBS: usize> Default for SpecialFormElement<MOD, LIMBS> {
+    fn default() -> Self {
+        Self::ZERO
+    }
+}
+
+impl<MOD: SpecialFormParams<LIMBS>, const LIMBS: usize> CtEq for SpecialFormElement<MOD, LIMBS> {
+    fn ct_eq(&self, other: &Self) -> Choice {
+        CtEq::ct_eq(&self.value, &other.value)
+    }
+}
+
+impl<MOD: SpecialFormParams<LIMBS>, const LIMBS: usize> CtSelect
+    for SpecialFormElement<MOD, LIMBS>
+{
+    fn ct_select(&self, other: &Self, choice: Choice) -> Self {
+        Self::from_reduced(Uint::ct_select(&self.value, &other.value, choice))
+    }
+}
+
+impl<MOD: SpecialFormParams<LIMBS>, const LIMBS: usize> Retrieve
+    for SpecialFormElement<MOD, LIMBS>
+{
+    type Output = Uint<LIMBS>;
+    fn retrieve(&self) -> Self::Output {
+        self.retrieve()
+    }
+}
+
+#[cfg(feature = "rand_core")]
+impl<MOD: SpecialFormParams<LIMBS>, const LIMBS: usize> Random for SpecialFormElement<MOD, LIMBS> {
+    #[inline]
+    fn try_random_from_rng<R: TryRngCore + ?Sized>(rng: &mut R) -> Result<Self, R::Error> {
+        Ok(Self::from_reduced(Uint::try_random_mod_vartime(
+            rng,
+            Self::MODULUS.as_nz_ref(),
+        )?))
+    }
+}
+
+impl<MOD: SpecialFormParams<LIMBS>, const LIMBS: usize> Invert for SpecialFormElement<MOD, LIMBS> {
+    type Output = CtOption<Self>;
+
+    fn invert(&self) -> Self::Output {
+        self.invert()
+    }
+
+    fn invert_vartime(&self) -> Self::Output {
+        self.invert_vartime()
+    }
+}
+
+impl<MOD: SpecialFormParams<LIMBS>, const LIMBS: usize, const RHS_LIMBS: usize>
+    PowBoundedExp<Uint<RHS_LIMBS>> for SpecialFormElement<MOD, LIMBS>
+{
+    fn pow_bounded_exp(&self, exponent: &Uint<RHS_LIMBS>, exponent_bits: u32) -> Self {
+        self.pow_bounded_exp(exponent, exponent_bits)
+    }
+}
+
+impl<MOD: SpecialFormParams<LIMBS>, const LIMBS: usize> ModularRing
+    for SpecialFormElement<MOD, LIMBS>
+{
+    type Integer = Uint<LIMBS>;
+
+    fn zero_like(&self) -> Self {
+        Self::ZERO
+    }
+
+    fn one_like(&self) -> Self {
+        Self::ONE
+    }
+
+    fn new_like(&self, value: &Uint<LIMBS>) -> Self {
+        Self::new(value)
+    }
+
+    fn sqrt(&self) -> CtOption<Self> {
+        self.sqrt()
+    }
+}
+
+impl<MOD: SpecialFormParams<LIMBS>, const LIMBS: usize> Square for SpecialFormElement<MOD, LIMBS> {
+    fn square(&self) -> Self {
+        SpecialFormElement::square(self)
+    }
+}
+
+impl<MOD: SpecialFormParams<LIMBS>, const LIMBS: usize> Neg for SpecialFormElement<MOD, LIMBS> {
+    type Output = Self;
+    fn neg(self) -> Self {
+        SpecialFormElement::neg(&self)
+    }
+}
+
+impl<MOD: SpecialFormParams<LIMBS>, const LIMBS: usize> Neg for &SpecialFormElement<MOD, LIMBS> {
+    type Output = SpecialFormElement<MOD, LIMBS>;
+    fn neg(self) -> SpecialFormElement<MOD, LIMBS> {
+        SpecialFormElement::neg(self)
+    }
+}
+
+/// Implements a binary operator and its assigning counterpart for all combinations of owned and
+/// borrowed operands, in terms of the inherent method of the same name.
+macro_rules! impl_special_form_op {
+    ($op:ident, $method:ident, $op_assign:ident, $method_assign:ident) => {
+        impl<MOD: SpecialFormParams<LIMBS>, const LIMBS: usize> $op<&SpecialFormElement<MOD, LIMBS>>
+            for &SpecialFormElement<MOD, LIMBS>
+        {
+            type Output = SpecialFormElement<MOD, LIMBS>;
+            fn $method(self, rhs: &SpecialFormElement<MOD, LIMBS>) -> Self::Output {
+                SpecialFormElement::$method(self, rhs)
+            }
+        }
+
+        impl<MOD: SpecialFormParams<LIMBS>, const LIMBS: usize> $op<SpecialFormElement<MOD, LIMBS>>
+            for &SpecialFormElement<MOD, LIMBS>
+        {
+            type Output = SpecialFormElement<MOD, LIMBS>;
+            fn $method(self, rhs: SpecialFormElement<MOD, LIMBS>) -> Self::Output {
+                SpecialFormElement::$method(self, &rhs)
+            }
+        }
+
+        impl<MOD: SpecialFormParams<LIMBS>, const LIMBS: usize> $op<&SpecialFormElement<MOD, LIMBS>>
+            for SpecialFormElement<MOD, LIMBS>
+        {
+            type Output = SpecialFormElement<MOD, LIMBS>;
+            fn $method(self, rhs: &SpecialFormElement<MOD, LIMBS>) -> Self::Output {
+                SpecialFormElement::$method(&self, rhs)
+            }
+        }
+
+        impl<MOD: SpecialFormParams<LIMBS>, const LIMBS: usize> $op<SpecialFormElement<MOD, LIMBS>>
+            for SpecialFormElement<MOD, LIMBS>
+        {
+            type Output = SpecialFormElement<MOD, LIMBS>;
+            fn $method(self, rhs: SpecialFormElement<MOD, LIMBS>) -> Self::Output {
+                SpecialFormElement::$method(&self, &rhs)
+            }
+        }
+
+        impl<MOD: SpecialFormParams<LIMBS>, const LIMBS: usize> $op_assign<&Self>
+            for SpecialFormElement<MOD, LIMBS>
+        {
+            fn $method_assign(&mut self, rhs: &Self) {
+                *self = SpecialFormElement::$method(self, rhs);
+            }
+        }
+
+        impl<MOD: SpecialFormParams<LIMBS>, const LIMBS: usize> $op_assign<Self>
+            for SpecialFormElement<MOD, LIMBS>
+        {
+            fn $method_assign(&mut self, rhs: Self) {
+                *self = SpecialFormElement::$method(self, &rhs);
+            }
+        }
+    };
+}
+
+impl_special_form_op!(Add, add, AddAssign, add_assign);
+impl_special_form_op!(Sub, sub, SubAssign, sub_assign);
+impl_special_form_op!(Mul, mul, MulAssign, mul_assign);
+
+#[cfg(test)]
+mod tests {
+    use crate::{U128, U256, const_special_form_params, modular::SpecialFormElement};
+
+    const_special_form_params!(P25519, U256, 255, 19);
+    const_special_form_params!(P127, U128, 127, 1);
+
+    type Fe = SpecialFormElement<P25519, { U256::LIMBS }>;
+    type Fe127 = SpecialFormElement<P127, { U128::LIMBS }>;
+
+    #[test]
+    fn arithmetic() {
+        let p = Fe::MODULUS.get();
+        let a = Fe::new(&p.wrapping_sub(&U256::ONE));
+        let b = Fe::new(&U256::from_u8(5));
+
+        assert_eq!(Fe::new(&p), Fe::ZERO);
+        assert_eq!(a + Fe::ONE, Fe::ZERO);
+        assert_eq!(Fe::ZERO - Fe::ONE, a);
+        assert_eq!(-Fe::ONE, a);
+        assert_eq!(a * a, Fe::ONE);
+        assert_eq!(b.square(), Fe::new(&U256::from_u8(25)));
+        assert_eq!(b.double(), Fe::new(&U256::from_u8(10)));
+        assert_eq!(Fe::lincomb(&[(a, b), (b, b)]), Fe::new(&U256::from_u8(20)));
+    }
+
+    #[test]
+    fn invert() {
+        let a = Fe127::new(&U128::from_u64(1234567));
+        let inverse = a.invert().unwrap();
+        assert_eq!(a * inverse, Fe127::ONE);
+        assert_eq!(a.invert_vartime().unwrap(), inverse);
+        assert!(bool::from(Fe127::ZERO.invert().is_none()));
+    }
+
+    #[test]
+    fn pow() {
+        let a = Fe::new(&U256::from_u8(3));
+        let exponent = Fe::MODULUS.wrapping_sub(&U256::from_u8(2));
+
+        // Fermat's little theorem
+        assert_eq!(a.pow(&exponent), a.invert().unwrap());
+        assert_eq!(a.pow_vartime(&exponent), a.invert().unwrap());
+        assert_eq!(a.pow(&U256::ZERO), Fe::ONE);
+        assert_eq!(a.pow_vartime(&U256::ZERO), Fe::ONE);
+        assert_eq!(
+            a.pow_bounded_exp(&U256::from_u8(5), 3),
+            a.square().square() * a
+        );
+    }
+
+    #[test]
+    fn sqrt() {
+        // 2^127 - 1 is 3 mod 4, and 2^255 - 19 is 5 mod 8
+        let a = Fe127::new(&U128::from_u64(1234567));
+        assert_eq!(a.square().sqrt().unwrap().square(), a.square());
+        assert!(bool::from((-Fe127::ONE).sqrt().is_none()));
+
+        let b = Fe::new(&U256::from_u64(1234567));
+        assert_eq!(b.square().sqrt().unwrap().square(), b.square());
+        assert!(bool::from(Fe::new(&U256::from_u8(2)).sqrt().is_none()));
+    }
+}
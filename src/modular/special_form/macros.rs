@@ -0,0 +1,54 @@
+//! [`SpecialFormParams`] support macros.
+
+#[cfg(doc)]
+use crate::modular::{SpecialForm, SpecialFormElement, SpecialFormParams};
+
+/// Create a type representing a modulus with a [`SpecialForm`] which impls the
+/// [`SpecialFormParams`] trait with the given name, type, bit length `k`, word-sized constant `c`
+/// and optional documentation string.
+///
+/// [`SpecialFormElement`]s using such a modulus reduce modulo `2^k - c` by multiplying the high
+/// limbs of a product by a single word instead of using Montgomery reduction.
+///
+/// # Usage
+///
+/// ```
+/// use crypto_bigint::{U128, U256, const_special_form_params, modular::SpecialFormElement};
+///
+/// // 2^255 - 19
+/// const_special_form_params!(Curve25519Modulus, U256, 255, 19, "Curve25519 base field modulus");
+///
+/// // 2^127 - 1
+/// const_special_form_params!(Mersenne127, U128, 127, 1);
+///
+/// type Fe = SpecialFormElement<Curve25519Modulus, { U256::LIMBS }>;
+/// assert_eq!(-Fe::ONE, Fe::new(&Fe::MODULUS.wrapping_sub(&U256::ONE)));
+/// ```
+///
+/// `c` must be odd, `k` must fall within the most significant limb of the integer type, and
+/// `c * 2^(BITS - k)` must be below `2^(Limb::BITS - 1)`, or this will fail to compile.
+#[macro_export]
+macro_rules! const_special_form_params {
+    ($name:ident, $uint_type:ty, $k:expr, $c:expr) => {
+        $crate::const_special_form_params!(
+            $name,
+            $uint_type,
+            $k,
+            $c,
+            "Special-form modulus which impls `SpecialFormParams`"
+        );
+    };
+    ($name:ident, $uint_type:ty, $k:expr, $c:expr, $doc:expr) => {
+        #[doc = $doc]
+        #[derive(Clone, Copy, Debug, Default, Eq, PartialEq)]
+        pub struct $name;
+        impl $crate::modular::SpecialFormParams<{ <$uint_type>::LIMBS }> for $name {
+            const LIMBS: usize = <$uint_type>::LIMBS;
+            const FORM: $crate::modular::SpecialForm = $crate::modular::SpecialForm::new($k, $c);
+        }
+        // Evaluate the modulus eagerly, so that invalid special forms are rejected at compile time
+        const _: () = {
+            let _ = $crate::modular::SpecialFormElement::<$name, { <$uint_type>::LIMBS }>::MODULUS;
+        };
+    };
+}
@@ -0,0 +1,192 @@
+//! Modular square roots of elements of a [`ModularRing`].
+
+use crate::{
+    BitOps, Choice, CtOption, Limb, ModularRing, One, PowBoundedExp, ShrVartime, UintRef, Unsigned,
+    Word, WrappingAdd, jacobi::jacobi_symbol_word_vartime,
+};
+
+/// Exclusive upper bound on the candidates tried when searching for a quadratic non-residue.
+///
+/// For a prime modulus, the probability of all integers below this bound being quadratic
+/// residues is negligible.
+const NON_RESIDUE_SEARCH_BOUND: Word = 1024;
+
+/// Compute the 2-adicity of `modulus - 1` for an odd `modulus`, i.e. the largest `s` such that
+/// `2^s` divides `modulus - 1`.
+pub(crate) const fn two_adicity(modulus: &UintRef) -> u32 {
+    // `modulus` is odd, so `modulus - 1` only differs in the least significant bit, which is
+    // skipped here.
+    let mut i = 0;
+    let mut ret = 0;
+    let mut done = Choice::FALSE;
+    while i < modulus.nlimbs() {
+        let mut limb = modulus.0[i];
+        if i == 0 {
+            limb = limb.bitxor(Limb::ONE);
+        }
+        let tz = limb.trailing_zeros();
+        ret += done.not().select_u32(0, tz);
+        done = done.or(limb.is_nonzero());
+        i += 1;
+    }
+    ret
+}
+
+/// Find the smallest quadratic non-residue modulo the odd `modulus`, that is, the smallest
+/// positive integer whose Jacobi symbol with respect to `modulus` is `-1`.
+///
+/// Returns zero if no such integer is smaller than [`NON_RESIDUE_SEARCH_BOUND`].
+///
+/// This method is variable-time with respect to `modulus`.
+pub(crate) const fn find_non_residue_vartime(modulus: &UintRef) -> Word {
+    let mut candidate = 2;
+    while candidate < NON_RESIDUE_SEARCH_BOUND {
+        if jacobi_symbol_word_vartime(candidate, modulus) == -1 {
+            return candidate;
+        }
+        candidate += 1;
+    }
+    0
+}
+
+/// Compute a square root of `value` modulo the prime `modulus`.
+///
+/// `two_adicity` is the largest `s` such that `2^s` divides `modulus - 1`, and `non_residue` returns
+/// a quadratic non-residue modulo `modulus`. The latter is only called in the general case, i.e.
+/// when `modulus ≡ 1 (mod 8)`, so that the search for it is skipped when it is not needed.
+///
+/// Returns `None` if `value` is not a square. If `modulus` is not prime, the result is
+/// unspecified, though it is still verified to be a square root of `value` if `Some`.
+///
+/// # Panics
+/// - if `non_residue` is called and returns zero, i.e. no quadratic non-residue was found.
+///
+/// This method is constant-time with respect to `value`, but variable-time with respect to the
+/// modulus and its 2-adicity.
+pub(crate) fn sqrt<M: ModularRing + PowBoundedExp<M::Integer>>(
+    value: &M,
+    modulus: &M::Integer,
+    two_adicity: u32,
+    non_residue: impl FnOnce() -> Word,
+) -> CtOption<M> {
+    let root = match two_adicity {
+        // p ≡ 3 (mod 4): r = a^((p + 1) / 4)
+        1 => {
+            let one = M::Integer::one_like(modulus);
+            let exp = modulus.wrapping_shr_vartime(2).wrapping_add(&one);
+            value.pow_bounded_exp(&exp, modulus.bits_precision())
+        }
+        // p ≡ 5 (mod 8), using Atkin's algorithm:
+        // t = (2a)^((p - 5) / 8), i = 2at^2, r = at(i - 1)
+        2 => {
+            let exp = modulus.wrapping_shr_vartime(3);
+            let double = value.clone() + value;
+            let t = double.pow_bounded_exp(&exp, modulus.bits_precision());
+            let i = double * &t.square();
+            value.clone() * &t * &(i - &value.one_like())
+        }
+        _ => {
+            let non_residue = non_residue();
+            assert!(
+                non_residue != 0,
+                "no quadratic non-residue below the search bound"
+            );
+            let non_residue = M::Integer::from_limb_like(Limb(non_residue), modulus);
+            let non_residue = value.new_like(&non_residue);
+            tonelli_shanks(value, modulus, two_adicity, &non_residue)
+        }
+    };
+
+    let is_root = root.square().ct_eq(value);
+    CtOption::new(root, is_root)
+}
+
+/// Constant-time Tonelli-Shanks algorithm, computing a candidate square root of `value`.
+///
+/// This follows "Algorithm 5" from <https://eprint.iacr.org/2012/685.pdf>, where every loop
+/// iteration is executed regardless of the value being operated on.
+fn tonelli_shanks<M: ModularRing + PowBoundedExp<M::Integer>>(
+    value: &M,
+    modulus: &M::Integer,
+    two_adicity: u32,
+    non_residue: &M,
+) -> M {
+    let bits_precision = modulus.bits_precision();
+    let one = value.one_like();
+
+    // p - 1 = 2^s * q where q is odd, and (q - 1) / 2 = floor(p / 2^(s + 1))
+    let q = modulus.wrapping_shr_vartime(two_adicity);
+    let q_minus_1_over_2 = modulus.wrapping_shr_vartime(two_adicity + 1);
+
+    // z is a primitive 2^s-th root of unity
+    let mut z = non_residue.pow_bounded_exp(&q, bits_precision);
+
+    // w = a^((q - 1) / 2), x = a^((q + 1) / 2), b = a^q
+    let w = value.pow_bounded_exp(&q_minus_1_over_2, bits_precision);
+    let mut x = w.clone() * value;
+    let mut b = x.clone() * &w;
+    let mut v = two_adicity;
+
+    for max_v in (1..=two_adicity).rev() {
+        let mut k = 1;
+        let mut tmp = b.square();
+        let mut j_less_than_v = Choice::TRUE;
+
+        for j in 2..max_v {
+            let tmp_is_one = tmp.ct_eq(&one);
+            let squared = tmp.ct_select(&z, tmp_is_one).square();
+            tmp = squared.ct_select(&tmp, tmp_is_one);
+            let new_z = z.ct_select(&squared, tmp_is_one);
+            j_less_than_v &= Choice::from_u32_eq(j, v).not();
+            k = tmp_is_one.select_u32(j, k);
+            z = z.ct_select(&new_z, j_less_than_v);
+        }
+
+        let result = x.clone() * &z;
+        x = result.ct_select(&x, b.ct_eq(&one));
+        z = z.square();
+        b *= &z;
+        v = k;
+    }
+
+    x
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{find_non_residue_vartime, two_adicity};
+    use crate::{U128, U256};
+
+    #[test]
+    fn two_adicity_of_modulus() {
+        assert_eq!(two_adicity(U128::from(3u8).as_uint_ref()), 1);
+        assert_eq!(two_adicity(U128::from(5u8).as_uint_ref()), 2);
+        assert_eq!(two_adicity(U128::from(17u8).as_uint_ref()), 4);
+
+        // 2^128 + 1 does not fit; use 2^96 + 1 instead
+        let n = U128::ONE.shl_vartime(96).wrapping_add(&U128::ONE);
+        assert_eq!(two_adicity(n.as_uint_ref()), 96);
+
+        // BLS12-381 scalar field modulus has 2-adicity 32
+        let r =
+            U256::from_be_hex("73eda753299d7d483339d80809a1d80553bda402fffe5bfeffffffff00000001");
+        assert_eq!(two_adicity(r.as_uint_ref()), 32);
+    }
+
+    #[test]
+    fn non_residue() {
+        assert_eq!(find_non_residue_vartime(U128::from(3u8).as_uint_ref()), 2);
+        assert_eq!(find_non_residue_vartime(U128::from(7u8).as_uint_ref()), 3);
+        assert_eq!(find_non_residue_vartime(U128::from(17u8).as_uint_ref()), 3);
+        assert_eq!(find_non_residue_vartime(U128::from(41u8).as_uint_ref()), 3);
+
+        // The BLS12-381 scalar field uses 7 as its multiplicative generator, and 5 is the
+        // smallest non-residue.
+        let r =
+            U256::from_be_hex("73eda753299d7d483339d80809a1d80553bda402fffe5bfeffffffff00000001");
+        assert_eq!(find_non_residue_vartime(r.as_uint_ref()), 5);
+
+        // Perfect squares have no non-residues.
+        assert_eq!(find_non_residue_vartime(U128::from(9u8).as_uint_ref()), 0);
+    }
+}
@@ -11,7 +11,7 @@ use core::{
 };
 
 #[cfg(feature = "alloc")]
-use crate::BoxedUint;
+use crate::{BoxedInt, BoxedUint};
 
 #[cfg(feature = "hybrid-array")]
 use crate::{ArrayEncoding, ByteArray};
@@ -35,6 +35,10 @@ pub type NonZeroInt<const LIMBS: usize> = NonZero<Int<LIMBS>>;
 #[cfg(feature = "alloc")]
 pub type NonZeroBoxedUint = NonZero<BoxedUint>;
 
+/// Non-zero boxed signed integer.
+#[cfg(feature = "alloc")]
+pub type NonZeroBoxedInt = NonZero<BoxedInt>;
+
 /// Wrapper type for non-zero integers.
 #[derive(Clone, Copy, Debug, Eq, Hash, PartialEq, PartialOrd, Ord)]
 #[repr(transparent)]
@@ -278,6 +282,21 @@ impl<const LIMBS: usize> NonZeroInt<LIMBS> {
     }
 }
 
+#[cfg(feature = "alloc")]
+impl NonZeroBoxedInt {
+    /// The sign and magnitude of this [`NonZeroBoxedInt`].
+    pub fn abs_sign(&self) -> (NonZero<BoxedUint>, Choice) {
+        let (abs, sign) = self.0.abs_sign();
+        // Absolute value of a non-zero value is non-zero
+        (NonZero(abs), sign)
+    }
+
+    /// The magnitude of this [`NonZeroBoxedInt`].
+    pub fn abs(&self) -> NonZero<BoxedUint> {
+        self.abs_sign().0
+    }
+}
+
 #[cfg(feature = "hybrid-array")]
 impl<T> NonZero<T>
 where
@@ -7,7 +7,7 @@ use crate::{
 use core::{cmp::Ordering, fmt, ops::Deref};
 
 #[cfg(feature = "alloc")]
-use crate::{BoxedUint, Resize};
+use crate::{BoxedInt, BoxedUint, Resize};
 
 #[cfg(feature = "rand_core")]
 use crate::{Random, rand_core::TryRngCore};
@@ -33,6 +33,10 @@ pub type OddInt<const LIMBS: usize> = Odd<Int<LIMBS>>;
 #[cfg(feature = "alloc")]
 pub type OddBoxedUint = Odd<BoxedUint>;
 
+/// Odd boxed signed integer.
+#[cfg(feature = "alloc")]
+pub type OddBoxedInt = Odd<BoxedInt>;
+
 /// Wrapper type for odd integers.
 ///
 /// These are frequently used in cryptography, e.g. as a modulus.
@@ -0,0 +1,222 @@
+//! Primality testing.
+//!
+//! This module provides the [Miller-Rabin](MillerRabin) and [strong Lucas](lucas_test) probable
+//! prime tests, as well as their combination, the Baillie-PSW test ([`is_prime_vartime`]), for
+//! which no composite counterexample is known.
+//!
+//! All tests are generic over [`Unsigned`], and so work for both [`Uint`](crate::Uint) and
+//! [`BoxedUint`](crate::BoxedUint).
+//!
+//! # Constant-time properties
+//!
+//! Primality tests are intended to be run on public values, or on candidates which are discarded
+//! if they turn out to be composite, so all of them are **variable-time** with respect to the
+//! candidate being tested: they return as soon as the outcome is known.
+//!
+//! The only exception is the modular exponentiation performed by [`MillerRabin::test`], which is
+//! constant-time with respect to the base.
+
+#[cfg(feature = "rand_core")]
+mod generate;
+mod lucas;
+mod miller_rabin;
+
+pub use self::{lucas::lucas_test, miller_rabin::MillerRabin};
+
+#[cfg(feature = "rand_core")]
+pub use self::generate::{generate_prime, generate_safe_prime};
+
+use crate::{Limb, NonZero, Odd, Reciprocal, Unsigned, Word};
+
+#[cfg(feature = "rand_core")]
+use {crate::RandomMod, rand_core::RngCore};
+
+/// Odd primes below 1024, used for trial division.
+pub(crate) const SMALL_PRIMES: [u16; 171] = [
+    3, 5, 7, 11, 13, 17, 19, 23, 29, 31, 37, 41, 43, 47, 53, 59, 61, 67, 71, 73, 79, 83, 89, 97,
+    101, 103, 107, 109, 113, 127, 131, 137, 139, 149, 151, 157, 163, 167, 173, 179, 181, 191, 193,
+    197, 199, 211, 223, 227, 229, 233, 239, 241, 251, 257, 263, 269, 271, 277, 281, 283, 293, 307,
+    311, 313, 317, 331, 337, 347, 349, 353, 359, 367, 373, 379, 383, 389, 397, 401, 409, 419, 421,
+    431, 433, 439, 443, 449, 457, 461, 463, 467, 479, 487, 491, 499, 503, 509, 521, 523, 541, 547,
+    557, 563, 569, 571, 577, 587, 593, 599, 601, 607, 613, 617, 619, 631, 641, 643, 647, 653, 659,
+    661, 673, 677, 683, 691, 701, 709, 719, 727, 733, 739, 743, 751, 757, 761, 769, 773, 787, 797,
+    809, 811, 821, 823, 827, 829, 839, 853, 857, 859, 863, 877, 881, 883, 887, 907, 911, 919, 929,
+    937, 941, 947, 953, 967, 971, 977, 983, 991, 997, 1009, 1013, 1019, 1021,
+];
+
+/// Precomputed reciprocals of the [`SMALL_PRIMES`], for fast remainder computation.
+pub(crate) const SMALL_PRIME_RECIPROCALS: [Reciprocal; SMALL_PRIMES.len()] = {
+    let mut ret = [Reciprocal::default(); SMALL_PRIMES.len()];
+    let mut i = 0;
+    while i < SMALL_PRIMES.len() {
+        ret[i] = Reciprocal::new(NonZero::<Limb>::new_unwrap(Limb(SMALL_PRIMES[i] as Word)));
+        i += 1;
+    }
+    ret
+};
+
+/// Square of the smallest prime not in [`SMALL_PRIMES`]: odd integers below this bound which have
+/// no factors among the [`SMALL_PRIMES`] are prime.
+const TRIAL_DIVISION_BOUND: Word = 1031 * 1031;
+
+/// Outcome of a primality test.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Primality {
+    /// The candidate is definitely composite.
+    Composite,
+
+    /// The candidate is definitely prime.
+    Prime,
+
+    /// The candidate is a probable prime: it passed the test, but may still be composite.
+    ProbablyPrime,
+}
+
+impl Primality {
+    /// Returns `true` if the candidate was not proven to be composite.
+    pub const fn is_probably_prime(&self) -> bool {
+        !matches!(self, Self::Composite)
+    }
+}
+
+/// Trial division of `candidate` by the [`SMALL_PRIMES`].
+///
+/// Returns `None` if `candidate` has no small prime factors, but is too large to be proven prime
+/// by trial division alone.
+fn trial_division<T: Unsigned>(candidate: &T) -> Option<Primality> {
+    let two = T::from_limb_like(Limb::from(2u8), candidate);
+    if candidate < &two {
+        return Some(Primality::Composite);
+    }
+    if candidate == &two {
+        return Some(Primality::Prime);
+    }
+    if candidate.is_even().to_bool_vartime() {
+        return Some(Primality::Composite);
+    }
+
+    for (prime, reciprocal) in SMALL_PRIMES.iter().zip(&SMALL_PRIME_RECIPROCALS) {
+        if candidate.rem_limb_with_reciprocal(reciprocal) == Limb::ZERO {
+            let prime = T::from_limb_like(Limb(Word::from(*prime)), candidate);
+            return Some(if candidate == &prime {
+                Primality::Prime
+            } else {
+                Primality::Composite
+            });
+        }
+    }
+
+    let bound = T::from_limb_like(Limb(TRIAL_DIVISION_BOUND), candidate);
+    (candidate < &bound).then_some(Primality::Prime)
+}
+
+/// Determine whether `candidate` is prime, using the Baillie-PSW test.
+///
+/// The candidate is first checked by trial division against small primes, then subjected to a
+/// Miller-Rabin test with base 2 and a strong Lucas test. No composite which passes this check is
+/// known, and it has been verified that there are none below `2^64`.
+///
+/// This function is variable-time with respect to `candidate`.
+pub fn is_prime_vartime<T: Unsigned>(candidate: &T) -> bool {
+    match trial_division(candidate) {
+        Some(primality) => primality.is_probably_prime(),
+        None => {
+            let candidate = Odd(candidate.clone());
+            MillerRabin::new(&candidate)
+                .test_base_two()
+                .is_probably_prime()
+                && lucas_test(&candidate).is_probably_prime()
+        }
+    }
+}
+
+/// Determine whether `candidate` is prime, using the Baillie-PSW test followed by `rounds`
+/// Miller-Rabin tests with random bases.
+///
+/// The additional random-base tests follow the recommendations of FIPS 186-5 (Appendix B.3), and
+/// bound the probability of a composite being accepted even for adversarially chosen candidates.
+///
+/// This function is variable-time with respect to `candidate`.
+#[cfg(feature = "rand_core")]
+pub fn is_prime_with_rng<T, R>(rng: &mut R, candidate: &T, rounds: usize) -> bool
+where
+    T: Unsigned + RandomMod,
+    R: RngCore + ?Sized,
+{
+    if !is_prime_vartime(candidate) {
+        return false;
+    }
+    if trial_division(candidate).is_some() {
+        return true;
+    }
+
+    let mr = MillerRabin::new(&Odd(candidate.clone()));
+    (0..rounds).all(|_| mr.test_random_base(rng).is_probably_prime())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{Primality, is_prime_vartime};
+    use crate::{U64, U128, U256};
+
+    #[test]
+    fn small_values() {
+        let primes = (0u64..2000)
+            .filter(|&n| n >= 2 && (2..n).take_while(|d| d * d <= n).all(|d| n % d != 0));
+        let mut expected = primes.peekable();
+
+        for n in 0u64..2000 {
+            let is_prime = expected.next_if_eq(&n).is_some();
+            assert_eq!(is_prime_vartime(&U64::from_u64(n)), is_prime, "n = {n}");
+            assert_eq!(is_prime_vartime(&U256::from_u64(n)), is_prime, "n = {n}");
+        }
+    }
+
+    #[test]
+    fn large_primes() {
+        // 2^127 - 1
+        assert!(is_prime_vartime(&U128::MAX.shr_vartime(1)));
+        // P-256 field modulus
+        assert!(is_prime_vartime(&U256::from_be_hex(
+            "ffffffff00000001000000000000000000000000ffffffffffffffffffffffff"
+        )));
+        // 2^128 + 1 = 59649589127497217 * 5704689200685129054721
+        assert!(!is_prime_vartime(
+            &U256::ONE.shl_vartime(128).wrapping_add(&U256::ONE)
+        ));
+    }
+
+    #[test]
+    fn carmichael_numbers() {
+        for n in [
+            561u64, 1105, 1729, 2465, 2821, 6601, 8911, 41041, 825265, 321197185,
+        ] {
+            assert!(!is_prime_vartime(&U64::from_u64(n)), "n = {n}");
+        }
+    }
+
+    #[test]
+    fn primality_is_probably_prime() {
+        assert!(!Primality::Composite.is_probably_prime());
+        assert!(Primality::Prime.is_probably_prime());
+        assert!(Primality::ProbablyPrime.is_probably_prime());
+    }
+
+    #[cfg(feature = "rand_core")]
+    #[test]
+    fn with_rng() {
+        use super::is_prime_with_rng;
+        use chacha20::ChaCha8Rng;
+        use rand_core::SeedableRng;
+
+        let mut rng = ChaCha8Rng::from_seed([7u8; 32]);
+        let p =
+            U256::from_be_hex("ffffffff00000001000000000000000000000000ffffffffffffffffffffffff");
+        assert!(is_prime_with_rng(&mut rng, &p, 10));
+        assert!(!is_prime_with_rng(
+            &mut rng,
+            &p.wrapping_add(&U256::from_u8(2)),
+            10
+        ));
+    }
+}
@@ -0,0 +1,229 @@
+//! Random prime generation.
+
+use super::{SMALL_PRIME_RECIPROCALS, SMALL_PRIMES, is_prime_vartime};
+use crate::{Limb, RandomBits, Unsigned, Word};
+use rand_core::RngCore;
+
+/// Smallest bit length for which sieving is used: candidates of this size (and, for safe primes,
+/// `(p - 1) / 2`) are larger than all of the [`SMALL_PRIMES`], so a small factor always means the
+/// candidate is composite.
+const MIN_SIEVE_BITS: u32 = 12;
+
+/// Maximum increment applied to a random starting point before a new one is drawn.
+const MAX_OFFSET: u32 = 1 << 20;
+
+/// Generate a random prime of exactly `bit_length` bits.
+///
+/// Random odd candidates with the most significant bit set are sieved against small primes, and
+/// the remaining ones are checked with the Baillie-PSW test ([`is_prime_vartime`]).
+///
+/// For [`Uint`](crate::Uint) the result has the full precision of the type, while for
+/// [`BoxedUint`](crate::BoxedUint) its precision is `bit_length` rounded up to a whole number of
+/// limbs.
+///
+/// This function is variable-time: its running time depends on the number of candidates which
+/// are rejected before a prime is found.
+///
+/// # Panics
+/// - if `bit_length` is less than 2.
+/// - if `bit_length` is larger than the precision of `T`.
+pub fn generate_prime<T, R>(rng: &mut R, bit_length: u32) -> T
+where
+    T: Unsigned + RandomBits,
+    R: RngCore + ?Sized,
+{
+    assert!(bit_length >= 2, "bit length must be at least 2");
+    generate(rng, bit_length, false)
+}
+
+/// Generate a random safe prime `p` of exactly `bit_length` bits, i.e. a prime such that
+/// `(p - 1) / 2` is also prime.
+///
+/// Candidates are sieved so that neither `p` nor `(p - 1) / 2` has a small prime factor, and both
+/// are then checked with the Baillie-PSW test ([`is_prime_vartime`]).
+///
+/// For [`Uint`](crate::Uint) the result has the full precision of the type, while for
+/// [`BoxedUint`](crate::BoxedUint) its precision is `bit_length` rounded up to a whole number of
+/// limbs.
+///
+/// This function is variable-time: its running time depends on the number of candidates which
+/// are rejected before a prime is found.
+///
+/// # Panics
+/// - if `bit_length` is less than 3.
+/// - if `bit_length` is larger than the precision of `T`.
+pub fn generate_safe_prime<T, R>(rng: &mut R, bit_length: u32) -> T
+where
+    T: Unsigned + RandomBits,
+    R: RngCore + ?Sized,
+{
+    assert!(bit_length >= 3, "bit length must be at least 3");
+    generate(rng, bit_length, true)
+}
+
+fn generate<T, R>(rng: &mut R, bit_length: u32, safe: bool) -> T
+where
+    T: Unsigned + RandomBits,
+    R: RngCore + ?Sized,
+{
+    loop {
+        let mut start = T::random_bits(rng, bit_length);
+        start.set_bit_vartime(bit_length - 1, true);
+        start.set_bit_vartime(0, true);
+        if safe {
+            // Safe primes other than 5 and 7 are congruent to 3 modulo 4 (and 11 modulo 12)
+            start.set_bit_vartime(1, true);
+        }
+
+        if bit_length < MIN_SIEVE_BITS {
+            if is_valid(&start, safe) {
+                return start;
+            }
+            continue;
+        }
+
+        let sieve = Sieve::new(start, bit_length, safe);
+        for candidate in sieve {
+            if is_valid(&candidate, safe) {
+                return candidate;
+            }
+        }
+    }
+}
+
+/// Check the candidate, and for safe primes `(candidate - 1) / 2`, with the Baillie-PSW test.
+fn is_valid<T: Unsigned>(candidate: &T, safe: bool) -> bool {
+    if safe {
+        is_prime_vartime(&candidate.wrapping_shr_vartime(1)) && is_prime_vartime(candidate)
+    } else {
+        is_prime_vartime(candidate)
+    }
+}
+
+/// Incremental sieve yielding candidates `start + offset` which have no factors among the
+/// [`SMALL_PRIMES`] (and, for safe primes, whose `(p - 1) / 2` has none either).
+///
+/// The residues of `start` modulo the small primes are computed once, using the precomputed
+/// reciprocals, so that each subsequent candidate only requires word-sized arithmetic.
+struct Sieve<T> {
+    start: T,
+    bit_length: u32,
+    residues: [u16; SMALL_PRIMES.len()],
+    offset: u32,
+    step: u32,
+    safe: bool,
+}
+
+impl<T: Unsigned> Sieve<T> {
+    fn new(start: T, bit_length: u32, safe: bool) -> Self {
+        let mut residues = [0; SMALL_PRIMES.len()];
+        for (residue, reciprocal) in residues.iter_mut().zip(&SMALL_PRIME_RECIPROCALS) {
+            // The remainder is below 1024, so it fits into a `u16`
+            *residue = start.rem_limb_with_reciprocal(reciprocal).0 as u16;
+        }
+
+        Self {
+            start,
+            bit_length,
+            residues,
+            offset: 0,
+            step: if safe { 4 } else { 2 },
+            safe,
+        }
+    }
+
+    /// Check the candidate at the current offset against the small primes.
+    fn passes(&self) -> bool {
+        SMALL_PRIMES
+            .iter()
+            .zip(&self.residues)
+            .all(|(&prime, &residue)| {
+                let prime = u32::from(prime);
+                let r = (u32::from(residue) + self.offset % prime) % prime;
+                // `r == 1` means that `p - 1`, and hence `(p - 1) / 2`, is divisible by `prime`.
+                r != 0 && !(self.safe && r == 1)
+            })
+    }
+}
+
+impl<T: Unsigned> Iterator for Sieve<T> {
+    type Item = T;
+
+    fn next(&mut self) -> Option<T> {
+        while self.offset < MAX_OFFSET {
+            let passes = self.passes();
+            let offset = self.offset;
+            self.offset += self.step;
+
+            if passes {
+                let candidate = self
+                    .start
+                    .wrapping_add(&T::from_limb_like(Limb(Word::from(offset)), &self.start));
+
+                // Stop once the increment carries into the next bit length (or wraps around).
+                if candidate.bits_vartime() != self.bit_length {
+                    return None;
+                }
+                return Some(candidate);
+            }
+        }
+        None
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{generate_prime, generate_safe_prime};
+    use crate::{U64, U128, U256, primality::is_prime_vartime};
+    use chacha20::ChaCha8Rng;
+    use rand_core::SeedableRng;
+
+    #[test]
+    fn prime() {
+        let mut rng = ChaCha8Rng::from_seed([7u8; 32]);
+        for bit_length in [2, 3, 8, 11, 12, 13, 32, 63, 64] {
+            let p: U64 = generate_prime(&mut rng, bit_length);
+            assert_eq!(p.bits(), bit_length);
+            assert!(is_prime_vartime(&p));
+        }
+
+        let p: U256 = generate_prime(&mut rng, 256);
+        assert_eq!(p.bits(), 256);
+        assert!(is_prime_vartime(&p));
+    }
+
+    #[test]
+    fn safe_prime() {
+        let mut rng = ChaCha8Rng::from_seed([7u8; 32]);
+        for bit_length in [3, 4, 8, 11, 12, 13, 64, 128] {
+            let p: U128 = generate_safe_prime(&mut rng, bit_length);
+            assert_eq!(p.bits(), bit_length);
+            assert!(is_prime_vartime(&p));
+            assert!(is_prime_vartime(&p.shr_vartime(1)));
+        }
+    }
+
+    #[test]
+    #[should_panic]
+    fn bit_length_too_small() {
+        let mut rng = ChaCha8Rng::from_seed([7u8; 32]);
+        let _: U64 = generate_prime(&mut rng, 1);
+    }
+
+    #[cfg(feature = "alloc")]
+    #[test]
+    fn boxed() {
+        use crate::BoxedUint;
+
+        let mut rng = ChaCha8Rng::from_seed([7u8; 32]);
+        let p: BoxedUint = generate_prime(&mut rng, 192);
+        assert_eq!(p.bits(), 192);
+        assert_eq!(p.bits_precision(), 192);
+        assert!(is_prime_vartime(&p));
+
+        let p: BoxedUint = generate_safe_prime(&mut rng, 130);
+        assert_eq!(p.bits(), 130);
+        assert!(is_prime_vartime(&p));
+        assert!(is_prime_vartime(&p.wrapping_shr_vartime(1)));
+    }
+}
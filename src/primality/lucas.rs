@@ -0,0 +1,191 @@
+//! Strong Lucas probable prime test.
+
+use super::Primality;
+use crate::{
+    Limb, Monty, Odd, Square, UintRef, Unsigned, Word, jacobi::jacobi_symbol_word_vartime,
+};
+
+/// Perform a strong Lucas probable prime test on `candidate`.
+///
+/// The Lucas sequence parameters are chosen with Selfridge's "Method A": `D` is the first value
+/// in `5, -7, 9, -11, ...` such that the Jacobi symbol `(D|n)` is `-1`, with `P = 1` and
+/// `Q = (1 - D) / 4`. Writing `n + 1 = 2^s * d` with `d` odd, the candidate passes the test if
+/// either `U_d ≡ 0 (mod n)` or `V_(2^r * d) ≡ 0 (mod n)` for some `0 <= r < s`.
+///
+/// Perfect squares are rejected up front, since no suitable `D` exists for them.
+///
+/// This function is variable-time with respect to `candidate`.
+pub fn lucas_test<T: Unsigned>(candidate: &Odd<T>) -> Primality {
+    let n = candidate.as_ref();
+    let one = T::one_like(n);
+    if n == &one {
+        return Primality::Composite;
+    }
+
+    let sqrt = n.sqrt_vartime();
+    if &(sqrt.clone() * &sqrt) == n {
+        return Primality::Composite;
+    }
+
+    let (abs_d, d_is_negative) = match selfridge_parameter(n) {
+        Ok(d) => d,
+        Err(primality) => return primality,
+    };
+
+    // Q = (1 - D) / 4
+    let abs_q = if d_is_negative {
+        (abs_d + 1) / 4
+    } else {
+        (abs_d - 1) / 4
+    };
+    let q_is_negative = !d_is_negative;
+
+    // Reject candidates sharing a factor with `Q`, which would invalidate the test.
+    if abs_q > 1 && n.rem_limb(Limb(abs_q).to_nz().expect("Q is non-zero")) == Limb::ZERO {
+        return if n == &T::from_limb_like(Limb(abs_q), n) {
+            Primality::Prime
+        } else {
+            Primality::Composite
+        };
+    }
+
+    let params = T::Monty::new_params_vartime(candidate.clone());
+    let to_monty = |abs: Word, is_negative: bool| {
+        let value = T::from_limb_like(Limb(abs), n) % candidate.as_nz_ref();
+        let value = T::Monty::new(value, params.clone());
+        if is_negative { -value } else { value }
+    };
+    let d = to_monty(abs_d, d_is_negative);
+    let q = to_monty(abs_q, q_is_negative);
+    let zero = T::Monty::zero(params.clone());
+
+    // n + 1 = 2^s * e with e odd; computed as 2 * (n / 2 + 1) to avoid overflow
+    let half_plus_one = n.wrapping_shr_vartime(1).wrapping_add(&one);
+    let tz = half_plus_one.trailing_zeros_vartime();
+    let s = tz + 1;
+    let e = half_plus_one.wrapping_shr_vartime(tz);
+
+    // Left-to-right binary Lucas chain, starting from U_1 = 1, V_1 = P = 1 and Q^1.
+    let mut u = T::Monty::one(params.clone());
+    let mut v = u.clone();
+    let mut qk = q.clone();
+
+    for i in (0..e.bits_vartime() - 1).rev() {
+        // U_2k = U_k * V_k, V_2k = V_k^2 - 2Q^k
+        u *= &v;
+        v = v.square() - qk.double();
+        qk = qk.square();
+
+        if e.bit_vartime(i) {
+            // U_(k+1) = (P * U_k + V_k) / 2, V_(k+1) = (D * U_k + P * V_k) / 2
+            let next_u = (u.clone() + &v).div_by_2();
+            v = (d.clone() * &u + &v).div_by_2();
+            u = next_u;
+            qk *= &q;
+        }
+    }
+
+    if u == zero || v == zero {
+        return Primality::ProbablyPrime;
+    }
+
+    for _ in 1..s {
+        // V_2k = V_k^2 - 2Q^k
+        v = v.square() - qk.double();
+        if v == zero {
+            return Primality::ProbablyPrime;
+        }
+        qk = qk.square();
+    }
+
+    Primality::Composite
+}
+
+/// Find the Selfridge parameter `D` for `n`, returned as its absolute value and sign.
+///
+/// If some candidate `D` shares a factor with `n`, the primality of `n` is already decided and
+/// returned as an error.
+fn selfridge_parameter<T: Unsigned>(n: &T) -> Result<(Word, bool), Primality> {
+    let n_ref = UintRef::new(n.as_limbs());
+    // (-1|n) = -1 if and only if n ≡ 3 (mod 4)
+    let n_is_3_mod_4 = n.as_limbs()[0].0 & 3 == 3;
+
+    let mut abs_d: Word = 5;
+    let mut is_negative = false;
+
+    loop {
+        let mut symbol = jacobi_symbol_word_vartime(abs_d, n_ref);
+        if is_negative && n_is_3_mod_4 {
+            symbol = -symbol;
+        }
+
+        match symbol {
+            -1 => return Ok((abs_d, is_negative)),
+            0 => {
+                // Every smaller odd number above 3 is coprime to `n`, so if `n == |D|` it is prime.
+                return Err(if n == &T::from_limb_like(Limb(abs_d), n) {
+                    Primality::Prime
+                } else {
+                    Primality::Composite
+                });
+            }
+            _ => {
+                abs_d += 2;
+                is_negative = !is_negative;
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::lucas_test;
+    use crate::{Odd, U64, U128, primality::Primality};
+
+    fn lucas(n: u64) -> Primality {
+        lucas_test(&Odd::new(U64::from_u64(n)).unwrap())
+    }
+
+    #[test]
+    fn small_primes() {
+        for p in [3u64, 5, 7, 11, 13, 17, 19, 23, 101, 65537] {
+            assert!(lucas(p).is_probably_prime(), "p = {p}");
+        }
+    }
+
+    #[test]
+    fn composites() {
+        for n in [1u64, 9, 15, 21, 25, 27, 35, 49, 561, 2047, 3277, 1_000_001] {
+            assert_eq!(lucas(n), Primality::Composite, "n = {n}");
+        }
+    }
+
+    #[test]
+    fn strong_lucas_pseudoprimes() {
+        // OEIS A217255: composites which pass the strong Lucas test with Selfridge parameters
+        for n in [
+            5459u64, 5777, 10877, 16109, 18971, 22499, 24569, 25199, 40309, 58519,
+        ] {
+            assert_eq!(lucas(n), Primality::ProbablyPrime, "n = {n}");
+        }
+    }
+
+    #[test]
+    fn large_prime() {
+        // 2^127 - 1
+        let p = U128::MAX.shr_vartime(1);
+        assert_eq!(lucas_test(&Odd::new(p).unwrap()), Primality::ProbablyPrime);
+    }
+
+    #[cfg(feature = "alloc")]
+    #[test]
+    fn boxed() {
+        use crate::BoxedUint;
+
+        let p = BoxedUint::max(128).wrapping_shr_vartime(1);
+        assert_eq!(lucas_test(&p.to_odd().unwrap()), Primality::ProbablyPrime);
+
+        let n = BoxedUint::from(5777u64);
+        assert_eq!(lucas_test(&n.to_odd().unwrap()), Primality::ProbablyPrime);
+    }
+}
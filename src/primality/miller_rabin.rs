@@ -0,0 +1,200 @@
+//! Miller-Rabin probable prime test.
+
+use super::Primality;
+use crate::{Limb, Monty, Odd, PowBoundedExp, Square, Unsigned};
+
+#[cfg(feature = "rand_core")]
+use {
+    crate::{NonZero, RandomMod},
+    rand_core::RngCore,
+};
+
+/// Precomputed state for running Miller-Rabin tests with several bases against the same
+/// candidate.
+///
+/// The candidate `n` is written as `n - 1 = 2^s * d` with `d` odd, and a base `a` passes the test
+/// if either `a^d ≡ 1 (mod n)` or `a^(2^r * d) ≡ -1 (mod n)` for some `0 <= r < s`. A prime
+/// passes the test for every base, while an odd composite passes it for at most a quarter of the
+/// bases.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct MillerRabin<T: Unsigned> {
+    /// The candidate being tested.
+    candidate: Odd<T>,
+    /// Odd part `d` of `candidate - 1`.
+    d: T,
+    /// The power of two `s` dividing `candidate - 1`.
+    s: u32,
+    /// One in Montgomery form.
+    one: T::Monty,
+    /// `candidate - 1` in Montgomery form.
+    minus_one: T::Monty,
+}
+
+impl<T: Unsigned> MillerRabin<T> {
+    /// Precompute the Montgomery parameters and the decomposition of `candidate - 1` needed to
+    /// test `candidate`.
+    pub fn new(candidate: &Odd<T>) -> Self {
+        let params = T::Monty::new_params_vartime(candidate.clone());
+        let one = T::Monty::one(params);
+        let minus_one = -one.clone();
+
+        let candidate_minus_one = candidate.wrapping_sub(&T::one_like(candidate));
+        // For `candidate == 1` this is the full precision, and `d` is zero.
+        let s = candidate_minus_one.trailing_zeros_vartime();
+        let d = candidate_minus_one.wrapping_shr_vartime(s);
+
+        Self {
+            candidate: candidate.clone(),
+            d,
+            s,
+            one,
+            minus_one,
+        }
+    }
+
+    /// Perform a Miller-Rabin test with the given `base`, which is reduced modulo the candidate.
+    ///
+    /// `base` must have the same precision as the candidate.
+    ///
+    /// Bases congruent to `0` or `±1` modulo the candidate carry no information, and the test
+    /// reports [`Primality::ProbablyPrime`] for them (unless the candidate is `1`).
+    ///
+    /// The modular exponentiation is constant-time with respect to `base`, but the test returns
+    /// as soon as its outcome is known.
+    pub fn test(&self, base: &T) -> Primality {
+        if self.d.is_zero().to_bool_vartime() {
+            // The candidate is 1
+            return Primality::Composite;
+        }
+
+        let base = base.clone() % self.candidate.as_nz_ref();
+        if base.is_zero().to_bool_vartime() {
+            return Primality::ProbablyPrime;
+        }
+
+        let params = self.one.params().clone();
+        let mut x = T::Monty::new(base, params).pow_bounded_exp(&self.d, self.d.bits_vartime());
+        if x == self.one || x == self.minus_one {
+            return Primality::ProbablyPrime;
+        }
+
+        for _ in 1..self.s {
+            x = x.square();
+            if x == self.minus_one {
+                return Primality::ProbablyPrime;
+            }
+            if x == self.one {
+                // A non-trivial square root of one has been found.
+                return Primality::Composite;
+            }
+        }
+
+        Primality::Composite
+    }
+
+    /// Perform a Miller-Rabin test with base 2.
+    pub fn test_base_two(&self) -> Primality {
+        self.test(&T::from_limb_like(Limb::from(2u8), &self.candidate))
+    }
+
+    /// Returns the candidate being tested.
+    pub fn candidate(&self) -> &Odd<T> {
+        &self.candidate
+    }
+}
+
+#[cfg(feature = "rand_core")]
+impl<T: Unsigned + RandomMod> MillerRabin<T> {
+    /// Perform a Miller-Rabin test with a base chosen uniformly at random from
+    /// `[2, candidate - 2]`.
+    ///
+    /// Candidates below 5 have no such bases; [`Self::test_base_two`] is used for them instead.
+    pub fn test_random_base<R: RngCore + ?Sized>(&self, rng: &mut R) -> Primality {
+        let three = T::from_limb_like(Limb::from(3u8), &self.candidate);
+        match NonZero::new(self.candidate.wrapping_sub(&three)).into_option() {
+            Some(range) if self.candidate.as_ref() > &three => {
+                let base = T::random_mod_vartime(rng, &range)
+                    .wrapping_add(&T::from_limb_like(Limb::from(2u8), &self.candidate));
+                self.test(&base)
+            }
+            _ => self.test_base_two(),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::MillerRabin;
+    use crate::{Odd, U64, U128, primality::Primality};
+
+    fn test_base_two(n: u64) -> Primality {
+        MillerRabin::new(&Odd::new(U64::from_u64(n)).unwrap()).test_base_two()
+    }
+
+    #[test]
+    fn small_primes() {
+        for p in [3u64, 5, 7, 11, 13, 101, 65537] {
+            assert!(test_base_two(p).is_probably_prime(), "p = {p}");
+        }
+        assert_eq!(test_base_two(1), Primality::Composite);
+    }
+
+    #[test]
+    fn composites() {
+        // Carmichael numbers are caught by the strong test
+        for n in [9u64, 15, 561, 1105, 1729, 2465] {
+            assert_eq!(test_base_two(n), Primality::Composite, "n = {n}");
+        }
+    }
+
+    #[test]
+    fn strong_pseudoprimes_base_two() {
+        // Composites which pass the test with base 2, but not with base 3
+        for n in [2047u64, 3277, 4033, 4681, 8321] {
+            let mr = MillerRabin::new(&Odd::new(U64::from_u64(n)).unwrap());
+            assert_eq!(mr.test_base_two(), Primality::ProbablyPrime, "n = {n}");
+            assert_eq!(mr.test(&U64::from_u8(3)), Primality::Composite, "n = {n}");
+        }
+    }
+
+    #[test]
+    fn trivial_bases() {
+        let mr = MillerRabin::new(&Odd::new(U128::from_u64(561)).unwrap());
+        assert_eq!(mr.test(&U128::ZERO), Primality::ProbablyPrime);
+        assert_eq!(mr.test(&U128::ONE), Primality::ProbablyPrime);
+        assert_eq!(mr.test(&U128::from_u64(560)), Primality::ProbablyPrime);
+        assert_eq!(mr.test(&U128::from_u64(562)), Primality::ProbablyPrime);
+        assert_eq!(mr.test(&U128::from_u64(563)), Primality::Composite);
+    }
+
+    #[cfg(feature = "alloc")]
+    #[test]
+    fn boxed() {
+        use crate::{BoxedUint, Resize};
+
+        // 2^127 - 1
+        let p = BoxedUint::max(128).wrapping_shr_vartime(1);
+        let mr = MillerRabin::new(&p.to_odd().unwrap());
+        assert_eq!(mr.test_base_two(), Primality::ProbablyPrime);
+        assert_eq!(
+            mr.test(&BoxedUint::from(12345u64).resize(128)),
+            Primality::ProbablyPrime
+        );
+    }
+
+    #[cfg(feature = "rand_core")]
+    #[test]
+    fn random_bases() {
+        use chacha20::ChaCha8Rng;
+        use rand_core::SeedableRng;
+
+        let mut rng = ChaCha8Rng::from_seed([7u8; 32]);
+        for n in [3u64, 5, 7, 65537] {
+            let mr = MillerRabin::new(&Odd::new(U64::from_u64(n)).unwrap());
+            assert!(mr.test_random_base(&mut rng).is_probably_prime(), "n = {n}");
+        }
+
+        let mr = MillerRabin::new(&Odd::new(U64::from_u64(2047)).unwrap());
+        assert!((0..16).any(|_| mr.test_random_base(&mut rng) == Primality::Composite));
+    }
+}
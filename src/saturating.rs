@@ -0,0 +1,392 @@
+//! Saturating arithmetic.
+
+use crate::{
+    Choice, CtEq, CtSelect, One, SaturatingAdd, SaturatingMul, SaturatingNeg, SaturatingShl,
+    SaturatingSub, Zero,
+};
+use core::{
+    fmt,
+    ops::{Add, Mul, Neg, Shl, Sub},
+};
+
+#[cfg(feature = "rand_core")]
+use {crate::Random, rand_core::TryRngCore};
+
+#[cfg(feature = "serde")]
+use serdect::serde::{Deserialize, Deserializer, Serialize, Serializer};
+
+/// Provides intentionally-saturating arithmetic on `T`.
+///
+/// This is analogous to [`core::num::Saturating`] but allows this crate to
+/// define trait impls for this type.
+#[derive(Copy, Clone, Debug, Default, Eq, PartialEq, PartialOrd, Ord)]
+pub struct Saturating<T>(pub T);
+
+impl<T: SaturatingAdd> Add<Self> for Saturating<T> {
+    type Output = Saturating<T>;
+
+    #[inline]
+    fn add(self, rhs: Self) -> Self::Output {
+        Saturating(self.0.saturating_add(&rhs.0))
+    }
+}
+
+impl<T: SaturatingAdd> Add<&Self> for Saturating<T> {
+    type Output = Saturating<T>;
+
+    #[inline]
+    fn add(self, rhs: &Self) -> Self::Output {
+        Saturating(self.0.saturating_add(&rhs.0))
+    }
+}
+
+impl<T: SaturatingAdd> Add<Saturating<T>> for &Saturating<T> {
+    type Output = Saturating<T>;
+
+    #[inline]
+    fn add(self, rhs: Saturating<T>) -> Self::Output {
+        Saturating(self.0.saturating_add(&rhs.0))
+    }
+}
+
+impl<T: SaturatingAdd> Add<&Saturating<T>> for &Saturating<T> {
+    type Output = Saturating<T>;
+
+    #[inline]
+    fn add(self, rhs: &Saturating<T>) -> Self::Output {
+        Saturating(self.0.saturating_add(&rhs.0))
+    }
+}
+
+impl<T: SaturatingSub> Sub<Self> for Saturating<T> {
+    type Output = Saturating<T>;
+
+    #[inline]
+    fn sub(self, rhs: Self) -> Self::Output {
+        Saturating(self.0.saturating_sub(&rhs.0))
+    }
+}
+
+impl<T: SaturatingSub> Sub<&Self> for Saturating<T> {
+    type Output = Saturating<T>;
+
+    #[inline]
+    fn sub(self, rhs: &Self) -> Self::Output {
+        Saturating(self.0.saturating_sub(&rhs.0))
+    }
+}
+
+impl<T: SaturatingSub> Sub<Saturating<T>> for &Saturating<T> {
+    type Output = Saturating<T>;
+
+    #[inline]
+    fn sub(self, rhs: Saturating<T>) -> Self::Output {
+        Saturating(self.0.saturating_sub(&rhs.0))
+    }
+}
+
+impl<T: SaturatingSub> Sub<&Saturating<T>> for &Saturating<T> {
+    type Output = Saturating<T>;
+
+    #[inline]
+    fn sub(self, rhs: &Saturating<T>) -> Self::Output {
+        Saturating(self.0.saturating_sub(&rhs.0))
+    }
+}
+
+impl<T: SaturatingMul> Mul<Self> for Saturating<T> {
+    type Output = Saturating<T>;
+
+    #[inline]
+    fn mul(self, rhs: Self) -> Self::Output {
+        Saturating(self.0.saturating_mul(&rhs.0))
+    }
+}
+
+impl<T: SaturatingMul> Mul<&Self> for Saturating<T> {
+    type Output = Saturating<T>;
+
+    #[inline]
+    fn mul(self, rhs: &Self) -> Self::Output {
+        Saturating(self.0.saturating_mul(&rhs.0))
+    }
+}
+
+impl<T: SaturatingMul> Mul<Saturating<T>> for &Saturating<T> {
+    type Output = Saturating<T>;
+
+    #[inline]
+    fn mul(self, rhs: Saturating<T>) -> Self::Output {
+        Saturating(self.0.saturating_mul(&rhs.0))
+    }
+}
+
+impl<T: SaturatingMul> Mul<&Saturating<T>> for &Saturating<T> {
+    type Output = Saturating<T>;
+
+    #[inline]
+    fn mul(self, rhs: &Saturating<T>) -> Self::Output {
+        Saturating(self.0.saturating_mul(&rhs.0))
+    }
+}
+
+impl<T: SaturatingNeg> Neg for Saturating<T> {
+    type Output = Saturating<T>;
+
+    #[inline]
+    fn neg(self) -> Self::Output {
+        Saturating(self.0.saturating_neg())
+    }
+}
+
+impl<T: SaturatingNeg> Neg for &Saturating<T> {
+    type Output = Saturating<T>;
+
+    #[inline]
+    fn neg(self) -> Self::Output {
+        Saturating(self.0.saturating_neg())
+    }
+}
+
+impl<T: SaturatingShl> Shl<u32> for Saturating<T> {
+    type Output = Saturating<T>;
+
+    #[inline]
+    fn shl(self, rhs: u32) -> Self::Output {
+        Saturating(self.0.saturating_shl(rhs))
+    }
+}
+
+impl<T: SaturatingShl> Shl<u32> for &Saturating<T> {
+    type Output = Saturating<T>;
+
+    #[inline]
+    fn shl(self, rhs: u32) -> Self::Output {
+        Saturating(self.0.saturating_shl(rhs))
+    }
+}
+
+impl<T> CtEq for Saturating<T>
+where
+    T: CtEq,
+{
+    #[inline]
+    fn ct_eq(&self, other: &Self) -> Choice {
+        CtEq::ct_eq(&self.0, &other.0)
+    }
+}
+
+impl<T> CtSelect for Saturating<T>
+where
+    T: CtSelect,
+{
+    #[inline]
+    fn ct_select(&self, other: &Self, choice: Choice) -> Self {
+        Self(self.0.ct_select(&other.0, choice))
+    }
+}
+
+impl<T: Zero> Zero for Saturating<T> {
+    #[inline]
+    fn zero() -> Self {
+        Saturating(T::zero())
+    }
+}
+
+impl<T: One> One for Saturating<T> {
+    #[inline]
+    fn one() -> Self {
+        Saturating(T::one())
+    }
+}
+
+impl<T: num_traits::Zero + SaturatingAdd> num_traits::Zero for Saturating<T> {
+    #[inline]
+    fn zero() -> Self {
+        Saturating(T::zero())
+    }
+
+    #[inline]
+    fn is_zero(&self) -> bool {
+        self.0.is_zero()
+    }
+}
+
+impl<T: num_traits::One + SaturatingMul + PartialEq> num_traits::One for Saturating<T> {
+    #[inline]
+    fn one() -> Self {
+        Saturating(T::one())
+    }
+
+    #[inline]
+    fn is_one(&self) -> bool {
+        self.0.is_one()
+    }
+}
+
+impl<T: fmt::Display> fmt::Display for Saturating<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        self.0.fmt(f)
+    }
+}
+
+impl<T: fmt::Binary> fmt::Binary for Saturating<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        self.0.fmt(f)
+    }
+}
+
+impl<T: fmt::Octal> fmt::Octal for Saturating<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        self.0.fmt(f)
+    }
+}
+
+impl<T: fmt::LowerHex> fmt::LowerHex for Saturating<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        self.0.fmt(f)
+    }
+}
+
+impl<T: fmt::UpperHex> fmt::UpperHex for Saturating<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        self.0.fmt(f)
+    }
+}
+
+#[cfg(feature = "rand_core")]
+impl<T: Random> Random for Saturating<T> {
+    fn try_random_from_rng<R: TryRngCore + ?Sized>(rng: &mut R) -> Result<Self, R::Error> {
+        Ok(Saturating(Random::try_random_from_rng(rng)?))
+    }
+}
+
+#[cfg(feature = "serde")]
+impl<'de, T: Deserialize<'de>> Deserialize<'de> for Saturating<T> {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        Ok(Self(T::deserialize(deserializer)?))
+    }
+}
+
+#[cfg(feature = "serde")]
+impl<T: Serialize> Serialize for Saturating<T> {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        self.0.serialize(serializer)
+    }
+}
+
+#[cfg(feature = "zeroize")]
+impl<T: zeroize::Zeroize> zeroize::Zeroize for Saturating<T> {
+    fn zeroize(&mut self) {
+        self.0.zeroize();
+    }
+}
+
+#[cfg(feature = "subtle")]
+impl<T> subtle::ConditionallySelectable for Saturating<T>
+where
+    T: Copy,
+    Self: CtSelect,
+{
+    #[inline]
+    fn conditional_select(a: &Self, b: &Self, choice: subtle::Choice) -> Self {
+        a.ct_select(b, choice.into())
+    }
+}
+
+#[cfg(feature = "subtle")]
+impl<T> subtle::ConstantTimeEq for Saturating<T>
+where
+    Self: CtEq,
+{
+    #[inline]
+    fn ct_eq(&self, other: &Self) -> subtle::Choice {
+        CtEq::ct_eq(self, other).into()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::Saturating;
+    use crate::{I128, U128};
+
+    #[test]
+    fn uint_ops() {
+        let max = Saturating(U128::MAX);
+        let one = Saturating(U128::ONE);
+        let two = Saturating(U128::from_u8(2));
+
+        assert_eq!(max + one, max);
+        assert_eq!(one + one, two);
+        assert_eq!(one - max, Saturating(U128::ZERO));
+        assert_eq!(two - one, one);
+        assert_eq!(max * two, max);
+        assert_eq!(two * two, Saturating(U128::from_u8(4)));
+        assert_eq!(-one, Saturating(U128::ZERO));
+        assert_eq!(one << 127, Saturating(U128::ONE.shl(127)));
+        assert_eq!(two << 127, max);
+
+        let mut x = one;
+        x += &two;
+        x -= one;
+        x *= max;
+        assert_eq!(x, max);
+    }
+
+    #[test]
+    fn int_ops() {
+        let max = Saturating(I128::MAX);
+        let min = Saturating(I128::MIN);
+        let one = Saturating(I128::ONE);
+        let minus_one = Saturating(I128::MINUS_ONE);
+
+        assert_eq!(max + one, max);
+        assert_eq!(min + minus_one, min);
+        assert_eq!(min - one, min);
+        assert_eq!(max - minus_one, max);
+        assert_eq!(
+            max * minus_one,
+            Saturating(I128::MIN.wrapping_add(&I128::ONE))
+        );
+        assert_eq!(min * minus_one, max);
+        assert_eq!(min * max, min);
+        assert_eq!(-min, max);
+        assert_eq!(-&one, minus_one);
+        assert_eq!(one << 127, max);
+        assert_eq!(minus_one << 127, min);
+
+        let mut x = min;
+        x -= one;
+        x += &max;
+        x *= minus_one;
+        assert_eq!(x, one);
+    }
+
+    #[cfg(feature = "alloc")]
+    #[test]
+    fn boxed_uint_ops() {
+        use crate::BoxedUint;
+
+        let max = Saturating(BoxedUint::max(128));
+        let zero = Saturating(BoxedUint::zero_with_precision(128));
+        let one = Saturating(BoxedUint::one_with_precision(128));
+
+        assert_eq!(&max + &one, max);
+        assert_eq!(&one - &max, zero);
+        assert_eq!(&max * &max, max);
+        assert_eq!(-&one, zero);
+        assert_eq!(&one << 128, max);
+
+        let mut x = one.clone();
+        x += &max;
+        x -= one;
+        x *= &max;
+        assert_eq!(x, max);
+    }
+}
@@ -586,6 +586,34 @@ pub trait CheckedMul<Rhs = Self>: Sized {
     fn checked_mul(&self, rhs: &Rhs) -> CtOption<Self>;
 }
 
+/// Checked negation.
+pub trait CheckedNeg: Sized {
+    /// Perform checked negation, returning a [`CtOption`] which `is_some` only if the result is
+    /// representable.
+    fn checked_neg(&self) -> CtOption<Self>;
+}
+
+/// Checked remainder.
+pub trait CheckedRem<Rhs = Self>: Sized {
+    /// Perform checked reduction, returning a [`CtOption`] which `is_some` only if the divisor is
+    /// non-zero.
+    fn checked_rem(&self, rhs: &Rhs) -> CtOption<Self>;
+}
+
+/// Checked left shift.
+pub trait CheckedShl: Sized {
+    /// Perform checked left shift, returning a [`CtOption`] which `is_some` only if
+    /// `shift` is less than the precision of `self`.
+    fn checked_shl(&self, shift: u32) -> CtOption<Self>;
+}
+
+/// Checked right shift.
+pub trait CheckedShr: Sized {
+    /// Perform checked right shift, returning a [`CtOption`] which `is_some` only if
+    /// `shift` is less than the precision of `self`.
+    fn checked_shr(&self, shift: u32) -> CtOption<Self>;
+}
+
 /// Checked subtraction.
 pub trait CheckedSub<Rhs = Self>: Sized {
     /// Perform checked subtraction, returning a [`CtOption`] which `is_some`
@@ -593,6 +621,37 @@ pub trait CheckedSub<Rhs = Self>: Sized {
     fn checked_sub(&self, rhs: &Rhs) -> CtOption<Self>;
 }
 
+/// Saturating addition.
+pub trait SaturatingAdd<Rhs = Self>: Sized {
+    /// Perform saturating addition, returning the nearest bound of the type on overflow.
+    fn saturating_add(&self, rhs: &Rhs) -> Self;
+}
+
+/// Saturating multiplication.
+pub trait SaturatingMul<Rhs = Self>: Sized {
+    /// Perform saturating multiplication, returning the nearest bound of the type on overflow.
+    fn saturating_mul(&self, rhs: &Rhs) -> Self;
+}
+
+/// Saturating negation.
+pub trait SaturatingNeg: Sized {
+    /// Perform saturating negation, returning the nearest bound of the type on overflow.
+    fn saturating_neg(&self) -> Self;
+}
+
+/// Saturating left shifts.
+pub trait SaturatingShl: Sized {
+    /// Computes `self << shift`, returning the nearest bound of the type if any non-zero bits
+    /// would be shifted out (or, for signed integers, if the sign would change).
+    fn saturating_shl(&self, shift: u32) -> Self;
+}
+
+/// Saturating subtraction.
+pub trait SaturatingSub<Rhs = Self>: Sized {
+    /// Perform saturating subtraction, returning the nearest bound of the type on overflow.
+    fn saturating_sub(&self, rhs: &Rhs) -> Self;
+}
+
 /// Concatenate two numbers into a "wide" double-width value, using the `hi` value as the most
 /// significant portion of the resulting value.
 pub trait Concat: ConcatMixed<Self, MixedOutput = Self::Output> {
@@ -852,6 +911,16 @@ where
 {
     /// Calculates `x1 ^ k1 * ... * xn ^ kn`.
     fn multi_exponentiate(bases_and_exponents: &BasesAndExponents) -> Self;
+
+    /// Calculates `x1 ^ k1 * ... * xn ^ kn` in variable time.
+    ///
+    /// Implementations may use an algorithm better suited to large numbers of bases, such as
+    /// Pippenger's, since they need not be constant-time.
+    ///
+    /// NOTE: the exponents are leaked in the time pattern.
+    fn multi_exponentiate_vartime(bases_and_exponents: &BasesAndExponents) -> Self {
+        Self::multi_exponentiate(bases_and_exponents)
+    }
 }
 
 impl<T, Exponent, BasesAndExponents> MultiExponentiate<Exponent, BasesAndExponents> for T
@@ -863,6 +932,10 @@ where
     fn multi_exponentiate(bases_and_exponents: &BasesAndExponents) -> Self {
         Self::multi_exponentiate_bounded_exp(bases_and_exponents, Exponent::BITS)
     }
+
+    fn multi_exponentiate_vartime(bases_and_exponents: &BasesAndExponents) -> Self {
+        Self::multi_exponentiate_bounded_exp_vartime(bases_and_exponents, Exponent::BITS)
+    }
 }
 
 /// Performs modular multi-exponentiation using Montgomery's ladder.
@@ -880,6 +953,16 @@ where
         bases_and_exponents: &BasesAndExponents,
         exponent_bits: u32,
     ) -> Self;
+
+    /// Calculates `x1 ^ k1 * ... * xn ^ kn` in variable time.
+    ///
+    /// NOTE: the exponents are leaked in the time pattern.
+    fn multi_exponentiate_bounded_exp_vartime(
+        bases_and_exponents: &BasesAndExponents,
+        exponent_bits: u32,
+    ) -> Self {
+        Self::multi_exponentiate_bounded_exp(bases_and_exponents, exponent_bits)
+    }
 }
 
 /// Constant-time inversion.
@@ -979,6 +1062,62 @@ pub trait Resize: Sized {
     }
 }
 
+/// An element of the ring of integers modulo some modulus, independent of its representation.
+///
+/// This allows writing generic algorithms over modular integers, such as polynomial evaluation or
+/// interpolation, without depending on representation details like those exposed by [`Monty`].
+/// All operands of a binary operation must share the same modulus.
+pub trait ModularRing:
+    Clone
+    + CtEq
+    + CtSelect
+    + Debug
+    + Eq
+    + Sized
+    + Add<Output = Self>
+    + for<'a> Add<&'a Self, Output = Self>
+    + AddAssign
+    + for<'a> AddAssign<&'a Self>
+    + Sub<Output = Self>
+    + for<'a> Sub<&'a Self, Output = Self>
+    + SubAssign
+    + for<'a> SubAssign<&'a Self>
+    + Mul<Output = Self>
+    + for<'a> Mul<&'a Self, Output = Self>
+    + MulAssign
+    + for<'a> MulAssign<&'a Self>
+    + Neg<Output = Self>
+    + Invert<Output = CtOption<Self>>
+    + Pow<Self::Integer>
+    + Retrieve<Output = Self::Integer>
+    + Square
+{
+    /// The integer type of the modulus and of canonical representatives of ring elements.
+    type Integer: Unsigned;
+
+    /// Returns `0` with the same modulus as `self`.
+    fn zero_like(&self) -> Self;
+
+    /// Returns `1` with the same modulus as `self`.
+    fn one_like(&self) -> Self;
+
+    /// Returns `value` reduced by the modulus of `self`.
+    fn new_like(&self, value: &Self::Integer) -> Self;
+
+    /// Compute a square root of `self`, i.e. a value `r` such that `r * r == self`.
+    ///
+    /// Returns `None` if `self` is not a quadratic residue. The modulus is expected to be prime:
+    /// otherwise `None` may be returned even if a square root exists.
+    fn sqrt(&self) -> CtOption<Self>;
+
+    /// Determine if `self` is a quadratic residue, i.e. if it has a square root.
+    ///
+    /// The modulus is expected to be prime.
+    fn is_square(&self) -> Choice {
+        self.sqrt().is_some()
+    }
+}
+
 /// A representation of an integer optimized for the performance of modular operations.
 pub trait Monty:
     'static
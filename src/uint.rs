@@ -37,16 +37,19 @@ pub(crate) mod div_limb;
 pub(crate) mod encoding;
 mod from;
 pub(crate) mod gcd;
+mod ilog;
 mod invert_mod;
 pub(crate) mod lcm;
 mod mod_symbol;
 pub(crate) mod mul;
 mod mul_int;
-mod mul_mod;
+pub(crate) mod mul_mod;
 mod neg;
 mod neg_mod;
+pub(crate) mod reciprocal;
 mod ref_type;
 mod resize;
+pub(crate) mod root;
 mod select;
 mod shl;
 mod shr;
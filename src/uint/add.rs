@@ -1,7 +1,8 @@
 //! [`Uint`] addition operations.
 
 use crate::{
-    Add, AddAssign, Checked, CheckedAdd, CtOption, Limb, Uint, Wrapping, WrappingAdd, word,
+    Add, AddAssign, Checked, CheckedAdd, CtOption, Limb, Saturating, SaturatingAdd, Uint, Wrapping,
+    WrappingAdd, word,
 };
 
 impl<const LIMBS: usize> Uint<LIMBS> {
@@ -105,6 +106,18 @@ impl<const LIMBS: usize> AddAssign<&Checked<Uint<LIMBS>>> for Checked<Uint<LIMBS
     }
 }
 
+impl<const LIMBS: usize> AddAssign for Saturating<Uint<LIMBS>> {
+    fn add_assign(&mut self, other: Self) {
+        *self = *self + other;
+    }
+}
+
+impl<const LIMBS: usize> AddAssign<&Saturating<Uint<LIMBS>>> for Saturating<Uint<LIMBS>> {
+    fn add_assign(&mut self, other: &Self) {
+        *self = *self + other;
+    }
+}
+
 impl<const LIMBS: usize> CheckedAdd for Uint<LIMBS> {
     fn checked_add(&self, rhs: &Self) -> CtOption<Self> {
         let (result, carry) = self.carrying_add(rhs, Limb::ZERO);
@@ -112,6 +125,12 @@ impl<const LIMBS: usize> CheckedAdd for Uint<LIMBS> {
     }
 }
 
+impl<const LIMBS: usize> SaturatingAdd for Uint<LIMBS> {
+    fn saturating_add(&self, rhs: &Self) -> Self {
+        self.saturating_add(rhs)
+    }
+}
+
 impl<const LIMBS: usize> WrappingAdd for Uint<LIMBS> {
     fn wrapping_add(&self, v: &Self) -> Self {
         self.wrapping_add(v)
@@ -12,11 +12,14 @@ pub(crate) mod div;
 pub(crate) mod encoding;
 mod from;
 mod gcd;
+mod ilog;
 mod invert_mod;
 mod mul;
 mod mul_mod;
 mod neg;
 mod neg_mod;
+pub(crate) mod reciprocal;
+mod root;
 mod select;
 mod shl;
 mod shr;
@@ -1,8 +1,8 @@
 //! [`BoxedUint`] addition operations.
 
 use crate::{
-    Add, AddAssign, BoxedUint, CheckedAdd, Choice, CtOption, CtSelect, Limb, U64, U128, Uint,
-    Wrapping, WrappingAdd,
+    Add, AddAssign, BoxedUint, Checked, CheckedAdd, Choice, CtOption, CtSelect, Limb, Saturating,
+    SaturatingAdd, U64, U128, Uint, Wrapping, WrappingAdd,
 };
 
 impl BoxedUint {
@@ -43,6 +43,14 @@ impl BoxedUint {
         carry
     }
 
+    /// Perform saturating addition, returning `MAX` on overflow.
+    pub fn saturating_add(&self, rhs: &Self) -> Self {
+        let (mut res, carry) = self.carrying_add(rhs, Limb::ZERO);
+        res.as_mut_uint_ref()
+            .conditional_set_max(carry.is_nonzero());
+        res
+    }
+
     /// Perform wrapping addition, discarding overflow.
     pub fn wrapping_add(&self, rhs: &Self) -> Self {
         self.carrying_add(rhs, Limb::ZERO).0
@@ -170,6 +178,30 @@ impl AddAssign<&Wrapping<BoxedUint>> for Wrapping<BoxedUint> {
     }
 }
 
+impl AddAssign<Checked<BoxedUint>> for Checked<BoxedUint> {
+    fn add_assign(&mut self, other: Checked<BoxedUint>) {
+        *self = &*self + other;
+    }
+}
+
+impl AddAssign<&Checked<BoxedUint>> for Checked<BoxedUint> {
+    fn add_assign(&mut self, other: &Checked<BoxedUint>) {
+        *self = &*self + other;
+    }
+}
+
+impl AddAssign<Saturating<BoxedUint>> for Saturating<BoxedUint> {
+    fn add_assign(&mut self, other: Saturating<BoxedUint>) {
+        *self = Saturating(self.0.saturating_add(&other.0));
+    }
+}
+
+impl AddAssign<&Saturating<BoxedUint>> for Saturating<BoxedUint> {
+    fn add_assign(&mut self, other: &Saturating<BoxedUint>) {
+        *self = Saturating(self.0.saturating_add(&other.0));
+    }
+}
+
 impl CheckedAdd for BoxedUint {
     fn checked_add(&self, rhs: &Self) -> CtOption<Self> {
         let (result, carry) = self.carrying_add(rhs, Limb::ZERO);
@@ -177,6 +209,12 @@ impl CheckedAdd for BoxedUint {
     }
 }
 
+impl SaturatingAdd for BoxedUint {
+    fn saturating_add(&self, rhs: &Self) -> Self {
+        self.saturating_add(rhs)
+    }
+}
+
 impl WrappingAdd for BoxedUint {
     fn wrapping_add(&self, v: &Self) -> Self {
         self.wrapping_add(v)
@@ -284,4 +322,15 @@ mod tests {
 
         h += BoxedUint::one();
     }
+
+    #[test]
+    fn saturating_add() {
+        let max = BoxedUint::max(128);
+        assert_eq!(
+            BoxedUint::one().saturating_add(&BoxedUint::one()),
+            BoxedUint::from(2u8)
+        );
+        assert_eq!(max.saturating_add(&BoxedUint::one()), max);
+        assert_eq!(max.saturating_add(&max), max);
+    }
 }
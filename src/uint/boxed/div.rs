@@ -1,8 +1,9 @@
 //! [`BoxedUint`] division operations.
 
 use crate::{
-    BoxedUint, CheckedDiv, CtOption, CtSelect, Div, DivAssign, DivRemLimb, DivVartime, Limb,
-    NonZero, Reciprocal, Rem, RemAssign, RemLimb, RemMixed, UintRef, Wrapping,
+    BoxedUint, BoxedUintReciprocal, CheckedDiv, CheckedRem, CtOption, CtSelect, Div, DivAssign,
+    DivRemLimb, DivVartime, Limb, NonZero, Reciprocal, Rem, RemAssign, RemLimb, RemMixed, UintRef,
+    Wrapping,
 };
 
 impl BoxedUint {
@@ -47,12 +48,48 @@ impl BoxedUint {
     pub fn rem(&self, rhs: &NonZero<Self>) -> Self {
         let xc = self.limbs.len();
         let yc = rhs.0.limbs.len();
+        if xc < yc {
+            return self.div_rem(rhs).1;
+        }
+
         let (mut quo, mut rem) = (self.clone(), rhs.as_ref().clone());
-        let x = quo.as_mut_uint_ref().split_at_mut(xc.saturating_sub(yc));
+        let x = quo.as_mut_uint_ref().split_at_mut(xc - yc);
         UintRef::rem_wide(x, rem.as_mut_uint_ref());
         rem
     }
 
+    /// Computes self / rhs using a pre-made reciprocal, returns the quotient, remainder.
+    pub fn div_rem_with_reciprocal(&self, reciprocal: &BoxedUintReciprocal) -> (Self, Self) {
+        let (mut quo, mut rem) = (self.clone(), reciprocal.divisor_normalized.clone());
+        quo.as_mut_uint_ref().div_rem_normalized(
+            rem.as_mut_uint_ref(),
+            reciprocal.shift,
+            reciprocal.words,
+            &reciprocal.reciprocal,
+        );
+        (quo, rem)
+    }
+
+    /// Computes self % rhs using a pre-made reciprocal, returns the remainder.
+    pub fn rem_with_reciprocal(&self, reciprocal: &BoxedUintReciprocal) -> Self {
+        let xc = self.limbs.len();
+        let yc = reciprocal.divisor_normalized.limbs.len();
+        if xc < yc {
+            return self.div_rem_with_reciprocal(reciprocal).1;
+        }
+
+        let (mut quo, mut rem) = (self.clone(), reciprocal.divisor_normalized.clone());
+        let x = quo.as_mut_uint_ref().split_at_mut(xc - yc);
+        UintRef::rem_wide_normalized(
+            x,
+            rem.as_mut_uint_ref(),
+            reciprocal.shift,
+            reciprocal.words,
+            &reciprocal.reciprocal,
+        );
+        rem
+    }
+
     /// Computes self / rhs, returns the quotient and remainder.
     ///
     /// Variable-time with respect to `rhs`
@@ -118,11 +155,22 @@ impl BoxedUint {
     pub fn checked_div(&self, rhs: &Self) -> CtOption<Self> {
         let mut quo = self.clone();
         let is_nz = rhs.is_nonzero();
-        let mut rem = Self::one_with_precision(self.bits_precision());
+        let mut rem = Self::one_with_precision(rhs.bits_precision());
         rem.ct_assign(rhs, is_nz);
         quo.as_mut_uint_ref().div_rem(rem.as_mut_uint_ref());
         CtOption::new(quo, is_nz)
     }
+
+    /// Perform checked reduction, returning a [`CtOption`] which `is_some`
+    /// only if the rhs != 0
+    ///
+    /// The remainder has the precision of `rhs`.
+    pub fn checked_rem(&self, rhs: &Self) -> CtOption<Self> {
+        let is_nz = rhs.is_nonzero();
+        let mut divisor = Self::one_with_precision(rhs.bits_precision());
+        divisor.ct_assign(rhs, is_nz);
+        CtOption::new(self.rem(&NonZero(divisor)), is_nz)
+    }
 }
 
 impl CheckedDiv for BoxedUint {
@@ -131,6 +179,12 @@ impl CheckedDiv for BoxedUint {
     }
 }
 
+impl CheckedRem for BoxedUint {
+    fn checked_rem(&self, rhs: &BoxedUint) -> CtOption<Self> {
+        self.checked_rem(rhs)
+    }
+}
+
 impl Div<&NonZero<BoxedUint>> for &BoxedUint {
     type Output = BoxedUint;
 
@@ -396,4 +450,36 @@ mod tests {
         };
         assert_eq!(a.divide_x_by_y(), BoxedUint::from(2707385u64));
     }
+
+    #[test]
+    fn checked_div_rem_mixed_precision() {
+        let x = BoxedUint::from(1000u64).resize(128);
+        let y = BoxedUint::from(7u64).resize(256);
+        assert_eq!(
+            x.checked_div(&y).unwrap(),
+            BoxedUint::from(142u64).resize(128)
+        );
+        assert_eq!(
+            y.checked_div(&x).unwrap(),
+            BoxedUint::zero_with_precision(256)
+        );
+        assert_eq!(
+            x.checked_rem(&y).unwrap(),
+            BoxedUint::from(6u64).resize(256)
+        );
+        assert_eq!(
+            y.checked_rem(&x).unwrap(),
+            BoxedUint::from(7u64).resize(128)
+        );
+        assert!(
+            x.checked_div(&BoxedUint::zero_with_precision(256))
+                .is_none()
+                .to_bool()
+        );
+        assert!(
+            x.checked_rem(&BoxedUint::zero_with_precision(256))
+                .is_none()
+                .to_bool()
+        );
+    }
 }
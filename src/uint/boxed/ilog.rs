@@ -0,0 +1,247 @@
+//! [`BoxedUint`] integer logarithm operations.
+
+use crate::{BoxedUint, Choice, CtGt, CtOption, Limb, Resize};
+
+impl BoxedUint {
+    /// Computes `floor(log2(self))` in constant time.
+    ///
+    /// # Panics
+    /// - if `self` is zero.
+    pub fn ilog2(&self) -> u32 {
+        self.checked_ilog2()
+            .into_option()
+            .expect("logarithm of zero is undefined")
+    }
+
+    /// Computes `floor(log2(self))`.
+    ///
+    /// This method is variable-time with respect to `self`.
+    ///
+    /// # Panics
+    /// - if `self` is zero.
+    pub fn ilog2_vartime(&self) -> u32 {
+        self.checked_ilog2_vartime()
+            .into_option()
+            .expect("logarithm of zero is undefined")
+    }
+
+    /// Computes `floor(log2(self))` in constant time, returning a [`CtOption`] which `is_some`
+    /// only if `self` is non-zero.
+    pub fn checked_ilog2(&self) -> CtOption<u32> {
+        CtOption::new(self.bits().saturating_sub(1), self.is_nonzero())
+    }
+
+    /// Computes `floor(log2(self))`, returning a [`CtOption`] which `is_some`
+    /// only if `self` is non-zero.
+    ///
+    /// This method is variable-time with respect to `self`.
+    pub fn checked_ilog2_vartime(&self) -> CtOption<u32> {
+        CtOption::new(self.bits_vartime().saturating_sub(1), self.is_nonzero())
+    }
+
+    /// Computes `floor(log10(self))` in constant time.
+    ///
+    /// # Panics
+    /// - if `self` is zero.
+    pub fn ilog10(&self) -> u32 {
+        self.ilog(&Self::from(10u8))
+    }
+
+    /// Computes `floor(log10(self))`.
+    ///
+    /// This method is variable-time with respect to `self`.
+    ///
+    /// # Panics
+    /// - if `self` is zero.
+    pub fn ilog10_vartime(&self) -> u32 {
+        self.ilog_vartime(&Self::from(10u8))
+    }
+
+    /// Computes `floor(log10(self))` in constant time, returning a [`CtOption`] which `is_some`
+    /// only if `self` is non-zero.
+    pub fn checked_ilog10(&self) -> CtOption<u32> {
+        self.checked_ilog(&Self::from(10u8))
+    }
+
+    /// Computes `floor(log10(self))`, returning a [`CtOption`] which `is_some`
+    /// only if `self` is non-zero.
+    ///
+    /// This method is variable-time with respect to `self`.
+    pub fn checked_ilog10_vartime(&self) -> CtOption<u32> {
+        self.checked_ilog_vartime(&Self::from(10u8))
+    }
+
+    /// Computes `floor(log_base(self))`.
+    ///
+    /// This method is constant-time with respect to `self`, but not `base`.
+    ///
+    /// # Panics
+    /// - if `self` is zero or `base` is less than 2.
+    pub fn ilog(&self, base: &Self) -> u32 {
+        self.checked_ilog(base)
+            .into_option()
+            .expect("logarithm of zero or to a base less than 2 is undefined")
+    }
+
+    /// Computes `floor(log_base(self))`.
+    ///
+    /// This method is variable-time with respect to `self` and `base`.
+    ///
+    /// # Panics
+    /// - if `self` is zero or `base` is less than 2.
+    pub fn ilog_vartime(&self, base: &Self) -> u32 {
+        self.checked_ilog_vartime(base)
+            .into_option()
+            .expect("logarithm of zero or to a base less than 2 is undefined")
+    }
+
+    /// Computes `floor(log_base(self))`, returning a [`CtOption`] which `is_some`
+    /// only if `self` is non-zero and `base` is at least 2.
+    ///
+    /// `base` may have a different precision than `self`.
+    ///
+    /// This method is constant-time with respect to `self`, but not `base`.
+    pub fn checked_ilog(&self, base: &Self) -> CtOption<u32> {
+        let base_bits = base.bits_vartime();
+        let log = if base_bits < 2 || base_bits > self.bits_precision() {
+            // Either the result is none, or `self < base`
+            0
+        } else if base.limbs[0].0.count_ones() == 1 && base_bits <= Limb::BITS {
+            // `base = 2^m`, so `floor(log_base(self)) = floor(floor(log2(self)) / m)`
+            self.bits().saturating_sub(1) / base.limbs[0].0.trailing_zeros()
+        } else {
+            // The powers of `base` which fit in `self` only depend on `base`
+            let base = base.resize_unchecked(self.bits_precision());
+            let mut power = base.clone();
+            let mut ret = 0;
+            loop {
+                ret += power.ct_gt(self).not().select_u32(0, 1);
+
+                let next = power.checked_mul(&base);
+                if next.is_none().to_bool_vartime() {
+                    break;
+                }
+                power = next.into_option().expect("checked above");
+            }
+            ret
+        };
+
+        CtOption::new(log, self.is_nonzero().and(is_valid_base(base_bits)))
+    }
+
+    /// Computes `floor(log_base(self))`, returning a [`CtOption`] which `is_some`
+    /// only if `self` is non-zero and `base` is at least 2.
+    ///
+    /// `base` may have a different precision than `self`.
+    ///
+    /// This method is variable-time with respect to `self` and `base`.
+    pub fn checked_ilog_vartime(&self, base: &Self) -> CtOption<u32> {
+        let base_bits = base.bits_vartime();
+        let mut log = 0;
+        if base_bits >= 2 && base_bits <= self.bits_vartime() {
+            let base = base.resize_unchecked(self.bits_precision());
+            let mut power = base.clone();
+            loop {
+                if power.cmp_vartime(self).is_gt() {
+                    break;
+                }
+                log += 1;
+
+                match power.checked_mul(&base).into_option() {
+                    Some(next) => power = next,
+                    None => break,
+                }
+            }
+        }
+
+        CtOption::new(log, self.is_nonzero().and(is_valid_base(base_bits)))
+    }
+}
+
+/// Is a base with the given bit length at least 2?
+fn is_valid_base(bits: u32) -> Choice {
+    Choice::from_u8_lsb((bits >= 2) as u8)
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{BoxedUint, Resize};
+
+    #[test]
+    fn ilog2() {
+        let zero = BoxedUint::zero_with_precision(256);
+        let max = !zero.clone();
+        assert!(zero.checked_ilog2().is_none().to_bool());
+        assert!(zero.checked_ilog2_vartime().is_none().to_bool());
+        assert_eq!(BoxedUint::one().ilog2(), 0);
+        assert_eq!(BoxedUint::from(3u8).ilog2(), 1);
+        assert_eq!(BoxedUint::from(3u8).ilog2_vartime(), 1);
+        assert_eq!(max.ilog2(), 255);
+        assert_eq!(max.ilog2_vartime(), 255);
+    }
+
+    #[test]
+    #[should_panic]
+    fn ilog2_zero() {
+        BoxedUint::zero().ilog2();
+    }
+
+    #[test]
+    fn ilog10() {
+        let zero = BoxedUint::zero_with_precision(128);
+        assert!(zero.checked_ilog10().is_none().to_bool());
+        assert!(zero.checked_ilog10_vartime().is_none().to_bool());
+
+        let ten = BoxedUint::from(10u8).resize(128);
+        let mut power = BoxedUint::one_with_precision(128);
+        for k in 0..=38 {
+            assert_eq!(power.ilog10(), k);
+            assert_eq!(power.ilog10_vartime(), k);
+            if k > 0 {
+                let below = power.wrapping_sub(&BoxedUint::one());
+                assert_eq!(below.ilog10(), k - 1);
+                assert_eq!(below.ilog10_vartime(), k - 1);
+            }
+            power = power.wrapping_mul(&ten);
+        }
+
+        let max = !zero;
+        assert_eq!(max.ilog10(), 38);
+        assert_eq!(max.ilog10_vartime(), 38);
+    }
+
+    #[test]
+    fn ilog() {
+        let x = BoxedUint::from(u64::MAX);
+        for base in [
+            2u64,
+            3,
+            4,
+            7,
+            16,
+            255,
+            256,
+            1 << 32,
+            (1 << 32) + 1,
+            u64::MAX,
+        ] {
+            let expected = u64::MAX.ilog(base);
+            let base = BoxedUint::from(base);
+            assert_eq!(x.ilog(&base), expected);
+            assert_eq!(x.ilog_vartime(&base), expected);
+        }
+
+        // Base wider than `self`
+        let base = BoxedUint::one_with_precision(256).shl_vartime(100).unwrap();
+        assert_eq!(x.ilog(&base), 0);
+        assert_eq!(x.ilog_vartime(&base), 0);
+        let x = base.square();
+        assert_eq!(x.ilog(&base), 2);
+        assert_eq!(x.ilog_vartime(&base), 2);
+
+        for base in [BoxedUint::zero(), BoxedUint::one()] {
+            assert!(x.checked_ilog(&base).is_none().to_bool());
+            assert!(x.checked_ilog_vartime(&base).is_none().to_bool());
+        }
+    }
+}
@@ -1,8 +1,8 @@
 //! [`BoxedUint`] multiplication operations.
 
 use crate::{
-    BoxedUint, CheckedMul, ConcatenatingMul, CtOption, Limb, Mul, MulAssign, Uint, UintRef,
-    Wrapping, WrappingMul,
+    BoxedUint, Checked, CheckedMul, ConcatenatingMul, CtOption, Limb, Mul, MulAssign, Saturating,
+    SaturatingMul, Uint, UintRef, Wrapping, WrappingMul,
     uint::mul::{karatsuba, wrapping_mul_overflow},
 };
 
@@ -33,7 +33,7 @@ impl BoxedUint {
     pub fn checked_mul(&self, rhs: &Self) -> CtOption<Self> {
         let (res, carry) = self.wrapping_mul_carry(rhs.as_limbs(), self.nlimbs());
         let overflow =
-            wrapping_mul_overflow(self.as_uint_ref(), self.as_uint_ref(), carry.is_nonzero());
+            wrapping_mul_overflow(self.as_uint_ref(), rhs.as_uint_ref(), carry.is_nonzero());
         CtOption::new(res, overflow.not())
     }
 
@@ -160,6 +160,30 @@ impl MulAssign<&Wrapping<BoxedUint>> for Wrapping<BoxedUint> {
     }
 }
 
+impl MulAssign<Checked<BoxedUint>> for Checked<BoxedUint> {
+    fn mul_assign(&mut self, other: Checked<BoxedUint>) {
+        *self = &*self * other;
+    }
+}
+
+impl MulAssign<&Checked<BoxedUint>> for Checked<BoxedUint> {
+    fn mul_assign(&mut self, other: &Checked<BoxedUint>) {
+        *self = &*self * other;
+    }
+}
+
+impl MulAssign<Saturating<BoxedUint>> for Saturating<BoxedUint> {
+    fn mul_assign(&mut self, other: Saturating<BoxedUint>) {
+        *self = Saturating(self.0.saturating_mul(&other.0));
+    }
+}
+
+impl MulAssign<&Saturating<BoxedUint>> for Saturating<BoxedUint> {
+    fn mul_assign(&mut self, other: &Saturating<BoxedUint>) {
+        *self = Saturating(self.0.saturating_mul(&other.0));
+    }
+}
+
 impl ConcatenatingMul for BoxedUint {
     type Output = Self;
 
@@ -178,6 +202,12 @@ impl ConcatenatingMul<&BoxedUint> for BoxedUint {
     }
 }
 
+impl SaturatingMul for BoxedUint {
+    fn saturating_mul(&self, rhs: &Self) -> Self {
+        self.saturating_mul(rhs)
+    }
+}
+
 impl WrappingMul for BoxedUint {
     fn wrapping_mul(&self, v: &Self) -> Self {
         self.wrapping_mul(v)
@@ -247,6 +277,20 @@ mod tests {
         }
     }
 
+    #[test]
+    fn checked_mul() {
+        let a = BoxedUint::one_with_precision(256).shl(64);
+        let b = BoxedUint::one_with_precision(256).shl(192);
+        assert!(a.checked_mul(&a).is_some().to_bool());
+        assert!(a.checked_mul(&b).is_none().to_bool());
+        assert!(b.checked_mul(&a).is_none().to_bool());
+
+        // Overflow is determined by both operands, not just `self`
+        let one = BoxedUint::one_with_precision(256);
+        assert!(b.checked_mul(&one).is_some().to_bool());
+        assert!(one.checked_mul(&b).is_some().to_bool());
+    }
+
     #[test]
     fn checked_square() {
         let n = BoxedUint::max(64)
@@ -4,6 +4,10 @@ use crate::{BoxedUint, Limb, MulMod, NonZero, SquareMod, WideWord, Word, div_lim
 
 impl BoxedUint {
     /// Computes `self * rhs mod p` for non-zero `p`.
+    ///
+    /// When performing many multiplications modulo the same `p`, consider precomputing
+    /// [`BoxedBarrettParams`](crate::modular::BoxedBarrettParams) and using
+    /// [`BoxedBarrettParams::mul_mod`](crate::modular::BoxedBarrettParams::mul_mod) instead.
     pub fn mul_mod(&self, rhs: &BoxedUint, p: &NonZero<BoxedUint>) -> BoxedUint {
         self.mul(rhs).rem(p)
     }
@@ -1,6 +1,9 @@
 //! [`BoxedUint`] negation operations.
 
-use crate::{BoxedUint, Choice, CtNeg, CtSelect, Limb, WideWord, Word, WrappingNeg};
+use crate::{
+    BoxedUint, CheckedNeg, Choice, CtNeg, CtOption, CtSelect, Limb, SaturatingNeg, WideWord, Word,
+    WrappingNeg,
+};
 
 impl BoxedUint {
     /// Perform wrapping negation.
@@ -17,6 +20,21 @@ impl BoxedUint {
         ret.into()
     }
 
+    /// Perform checked negation, returning a [`CtOption`] which `is_some` only if `self` is
+    /// zero, since the negation of any non-zero value is below the lower bound of the type.
+    pub fn checked_neg(&self) -> CtOption<Self> {
+        CtOption::new(
+            Self::zero_with_precision(self.bits_precision()),
+            self.is_zero(),
+        )
+    }
+
+    /// Perform saturating negation, returning `ZERO`, since the negation of any non-zero value
+    /// is below the lower bound of the type.
+    pub fn saturating_neg(&self) -> Self {
+        Self::zero_with_precision(self.bits_precision())
+    }
+
     /// Perform in-place wrapping subtraction, returning the truthy value as the second element of
     /// the tuple if an underflow has occurred.
     pub(crate) fn conditional_wrapping_neg_assign(&mut self, choice: Choice) {
@@ -42,6 +60,19 @@ impl CtNeg for BoxedUint {
     }
 }
 
+impl SaturatingNeg for BoxedUint {
+    fn saturating_neg(&self) -> Self {
+        self.saturating_neg()
+    }
+}
+
+impl CheckedNeg for BoxedUint {
+    #[inline]
+    fn checked_neg(&self) -> CtOption<Self> {
+        self.checked_neg()
+    }
+}
+
 impl WrappingNeg for BoxedUint {
     fn wrapping_neg(&self) -> Self {
         self.wrapping_neg()
@@ -0,0 +1,82 @@
+//! Pre-calculated reciprocals for division by a multi-limb [`BoxedUint`].
+
+use crate::{BoxedUint, Limb, NonZero, Reciprocal};
+
+/// A pre-calculated reciprocal for division by a multi-limb [`BoxedUint`] divisor.
+///
+/// This is the heap-allocated counterpart to [`UintReciprocal`](crate::UintReciprocal).
+/// See [`BoxedUint::div_rem_with_reciprocal`] and [`BoxedUint::rem_with_reciprocal`].
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct BoxedUintReciprocal {
+    /// The divisor left-shifted by `shift` bits.
+    pub(crate) divisor_normalized: BoxedUint,
+    /// The number of leading zeros of the divisor.
+    pub(crate) shift: u32,
+    /// The number of non-zero limbs of the divisor.
+    pub(crate) words: u32,
+    /// The reciprocal of the highest limb of the normalized divisor, or of the divisor itself
+    /// when it has a single limb.
+    pub(crate) reciprocal: Reciprocal,
+}
+
+impl BoxedUintReciprocal {
+    /// Pre-calculates a reciprocal for a known divisor,
+    /// to be used in the multi-limb division later.
+    pub fn new(divisor: &NonZero<BoxedUint>) -> Self {
+        let divisor = divisor.as_ref();
+        let bits = divisor.bits();
+        let shift = divisor.bits_precision() - bits;
+        let divisor_normalized = divisor.wrapping_shl(shift);
+
+        let nlimbs = divisor.nlimbs();
+        let reciprocal = if nlimbs == 1 {
+            Reciprocal::new(NonZero(divisor.limbs[0]))
+        } else {
+            Reciprocal::new(NonZero(divisor_normalized.limbs[nlimbs - 1]))
+        };
+
+        Self {
+            divisor_normalized,
+            shift,
+            words: bits.div_ceil(Limb::BITS),
+            reciprocal,
+        }
+    }
+
+    /// Get the divisor.
+    pub fn divisor(&self) -> NonZero<BoxedUint> {
+        NonZero(self.divisor_normalized.wrapping_shr(self.shift))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::BoxedUintReciprocal;
+    use crate::{BoxedUint, NonZero, Resize};
+
+    #[test]
+    fn div_rem() {
+        let x = BoxedUint::from_be_hex(
+            "a8d3c0f0d4a36a0b7a1b1d1c7c5e42b9e3b0c44298fc1c149afbf4c8996fb924",
+            256,
+        )
+        .unwrap();
+        let divisors = [
+            BoxedUint::one_with_precision(128),
+            BoxedUint::from(7u8).resize(128),
+            BoxedUint::from(u64::MAX),
+            BoxedUint::from(u128::MAX),
+            BoxedUint::from(u128::MAX).resize(512),
+            BoxedUint::max(256),
+        ];
+        for d in divisors {
+            let d = NonZero::new(d).unwrap();
+            let reciprocal = BoxedUintReciprocal::new(&d);
+            assert_eq!(reciprocal.divisor(), d);
+
+            let expected = x.div_rem(&d);
+            assert_eq!(x.div_rem_with_reciprocal(&reciprocal), expected);
+            assert_eq!(x.rem_with_reciprocal(&reciprocal), expected.1);
+        }
+    }
+}
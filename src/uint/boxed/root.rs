@@ -0,0 +1,265 @@
+//! [`BoxedUint`] integer `k`-th root operations.
+
+use crate::{
+    BoxedUint, Choice, CtEq, CtGt, CtOption, CtSelect, Limb, NonZero, NonZeroBoxedUint, Word,
+    uint::root::next_root_exponent,
+};
+use core::{iter, num::NonZeroU32};
+
+impl BoxedUint {
+    /// Computes `floor(self^(1/k))`, the integer `k`-th root of `self`, in constant time.
+    ///
+    /// This method is constant-time with respect to `self`, but not `k`.
+    ///
+    /// Callers can check if `self` is a perfect `k`-th power with [`Self::checked_root`].
+    pub fn root(&self, k: NonZeroU32) -> Self {
+        let k = k.get();
+
+        // Determine the bits of the root from the top down, keeping each one if the `k`-th power
+        // of the partial root doesn't exceed `self`. The root has at most `ceil(BITS / k)` bits.
+        let mut ret = Self::zero_with_precision(self.bits_precision());
+        let mut i = self.bits_precision().div_ceil(k);
+        while i > 0 {
+            i -= 1;
+            let mut candidate = ret.clone();
+            candidate.set_bit_vartime(i, true);
+            let (pow, overflow) = candidate.overflowing_pow(k);
+            let is_le = overflow.not().and(pow.ct_gt(self).not());
+            ret.ct_assign(&candidate, is_le);
+        }
+
+        ret
+    }
+
+    /// Computes `floor(self^(1/k))`, the integer `k`-th root of `self`.
+    ///
+    /// Callers can check if `self` is a perfect `k`-th power with [`Self::checked_root_vartime`].
+    pub fn root_vartime(&self, k: NonZeroU32) -> Self {
+        // Uses Newton's method, generalizing Brent & Zimmermann, Modern Computer Arithmetic,
+        // v0.5.9, Algorithm 1.13
+        let k = k.get();
+        if k == 1 || self.is_zero_vartime() {
+            return self.clone();
+        }
+
+        // The initial guess: `x_0 = 2^ceil(b/k)`, where `2^(b-1) <= self < 2^b`.
+        // Will not overflow since `b <= BITS` and `k >= 2`.
+        let mut x = Self::one_with_precision(self.bits_precision());
+        x.overflowing_shl_assign_vartime(self.bits_vartime().div_ceil(k)); // > self^(1/k)
+        let k_limb = NonZero(Limb(k as Word));
+        let k_minus_one = Self::from(k - 1);
+
+        loop {
+            // Calculate `x_{i+1} = floor(((k - 1) * x_i + self / x_i^(k-1)) / k)`
+            let (pow, overflow) = x.overflowing_pow(k - 1);
+            let q = if overflow.to_bool_vartime() {
+                Self::zero_with_precision(self.bits_precision())
+            } else {
+                self.wrapping_div_vartime(&NonZero(pow))
+            };
+
+            // `(k - 1) * x_i` can't overflow since `x_i <= x_0`, but the sum can once `x_i` has
+            // reached the root, in which case `x_{i+1} > x_i`
+            let (sum, carry) = x.wrapping_mul(&k_minus_one).carrying_add(&q, Limb::ZERO);
+            if carry.is_nonzero().to_bool_vartime() {
+                break;
+            }
+            let next_x = sum.div_rem_limb(k_limb).0;
+
+            // The iterates decrease until they reach the root, after which they go back up
+            if !x.cmp_vartime(&next_x).is_gt() {
+                break;
+            }
+
+            x = next_x;
+        }
+
+        x
+    }
+
+    /// Perform checked `k`-th root, returning a [`CtOption`] which `is_some`
+    /// only if `self` is a perfect `k`-th power.
+    pub fn checked_root(&self, k: NonZeroU32) -> CtOption<Self> {
+        let r = self.root(k);
+        let (s, _) = r.overflowing_pow(k.get());
+        let is_some = self.ct_eq(&s);
+        CtOption::new(r, is_some)
+    }
+
+    /// Perform checked `k`-th root, returning a [`CtOption`] which `is_some`
+    /// only if `self` is a perfect `k`-th power.
+    pub fn checked_root_vartime(&self, k: NonZeroU32) -> CtOption<Self> {
+        let r = self.root_vartime(k);
+        let (s, _) = r.overflowing_pow(k.get());
+        let is_some = self.ct_eq(&s);
+        CtOption::new(r, is_some)
+    }
+
+    /// Is `self` a perfect power, i.e. is `self = x^k` for some integers `x` and `k >= 2`?
+    ///
+    /// Zero and one are considered to be perfect powers.
+    pub fn is_perfect_power_vartime(&self) -> bool {
+        let bits = self.bits_vartime();
+        if bits <= 1 {
+            return true;
+        }
+
+        // If `self = x^k` with `x >= 2`, then `k < b` where `2^(b-1) <= self < 2^b`.
+        // It suffices to check prime `k`, since `x^(pk') = (x^k')^p`.
+        iter::successors(Some(2), |&k| Some(next_root_exponent(k)))
+            .take_while(|&k| k < bits)
+            .any(|k| {
+                let r = self.root_vartime(NonZeroU32::new(k).expect("k is non-zero"));
+                let (s, overflow) = r.overflowing_pow(k);
+                !overflow.to_bool_vartime() && s.cmp_vartime(self).is_eq()
+            })
+    }
+
+    /// Computes `self^exp`, along with a [`Choice`] which is truthy if the result overflowed.
+    ///
+    /// This method is constant-time with respect to `self`, but not `exp`.
+    fn overflowing_pow(&self, exp: u32) -> (Self, Choice) {
+        if exp == 0 {
+            return (
+                Self::one_with_precision(self.bits_precision()),
+                Choice::FALSE,
+            );
+        }
+
+        let mut ret = self.clone();
+        let mut overflow = Choice::FALSE;
+        for i in (0..u32::BITS - exp.leading_zeros() - 1).rev() {
+            let square = ret.checked_square();
+            overflow = overflow.or(square.is_none());
+            ret = square.as_inner_unchecked().clone();
+
+            if (exp >> i) & 1 == 1 {
+                let product = ret.checked_mul(self);
+                overflow = overflow.or(product.is_none());
+                ret = product.as_inner_unchecked().clone();
+            }
+        }
+
+        (ret, overflow)
+    }
+}
+
+impl NonZeroBoxedUint {
+    /// Computes `floor(self^(1/k))`, the integer `k`-th root of `self`, in constant time.
+    ///
+    /// This method is constant-time with respect to `self`, but not `k`.
+    pub fn root(&self, k: NonZeroU32) -> Self {
+        // The root of a non-zero value is at least one
+        NonZero(self.0.root(k))
+    }
+
+    /// Computes `floor(self^(1/k))`, the integer `k`-th root of `self`.
+    pub fn root_vartime(&self, k: NonZeroU32) -> Self {
+        NonZero(self.0.root_vartime(k))
+    }
+
+    /// Perform checked `k`-th root, returning a [`CtOption`] which `is_some`
+    /// only if `self` is a perfect `k`-th power.
+    pub fn checked_root(&self, k: NonZeroU32) -> CtOption<Self> {
+        self.0.checked_root(k).map(NonZero)
+    }
+
+    /// Perform checked `k`-th root, returning a [`CtOption`] which `is_some`
+    /// only if `self` is a perfect `k`-th power.
+    pub fn checked_root_vartime(&self, k: NonZeroU32) -> CtOption<Self> {
+        self.0.checked_root_vartime(k).map(NonZero)
+    }
+
+    /// Is `self` a perfect power, i.e. is `self = x^k` for some integers `x` and `k >= 2`?
+    ///
+    /// One is considered to be a perfect power.
+    pub fn is_perfect_power_vartime(&self) -> bool {
+        self.0.is_perfect_power_vartime()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{BoxedUint, NonZero, Resize};
+    use core::num::NonZeroU32;
+
+    const fn k(k: u32) -> NonZeroU32 {
+        NonZeroU32::new(k).unwrap()
+    }
+
+    #[test]
+    fn edge() {
+        let zero = BoxedUint::zero_with_precision(256);
+        let one = BoxedUint::one_with_precision(256);
+        let max = !zero.clone();
+        for i in 1..10 {
+            assert_eq!(zero.root(k(i)), zero);
+            assert_eq!(zero.root_vartime(k(i)), zero);
+            assert_eq!(one.root(k(i)), one);
+            assert_eq!(one.root_vartime(k(i)), one);
+        }
+
+        assert_eq!(max.root(k(1)), max);
+        assert_eq!(max.root_vartime(k(1)), max);
+        assert_eq!(max.root(k(2)), max.sqrt());
+        assert_eq!(max.root_vartime(k(2)), max.sqrt());
+        assert_eq!(max.root(k(256)), one);
+        assert_eq!(max.root_vartime(k(256)), one);
+        assert_eq!(max.root(k(1000)), one);
+        assert_eq!(max.root_vartime(k(1000)), one);
+
+        // `floor((2^128 - 1)^(1/3)) = 6981463658331`
+        let max = !BoxedUint::zero_with_precision(128);
+        let expected = BoxedUint::from(6981463658331u64).resize(128);
+        assert_eq!(max.root(k(3)), expected);
+        assert_eq!(max.root_vartime(k(3)), expected);
+    }
+
+    #[test]
+    fn cubes() {
+        for i in 0..200u64 {
+            let r = BoxedUint::from(i);
+            let cube = BoxedUint::from(i * i * i);
+            assert_eq!(cube.root(k(3)), r);
+            assert_eq!(cube.root_vartime(k(3)), r);
+            assert!(cube.checked_root(k(3)).is_some().to_bool());
+            assert!(cube.checked_root_vartime(k(3)).is_some().to_bool());
+
+            if i > 1 {
+                let below = BoxedUint::from(i * i * i - 1);
+                let r = BoxedUint::from(i - 1);
+                assert_eq!(below.root(k(3)), r);
+                assert_eq!(below.root_vartime(k(3)), r);
+                assert!(!below.checked_root(k(3)).is_some().to_bool());
+                assert!(!below.checked_root_vartime(k(3)).is_some().to_bool());
+            }
+        }
+    }
+
+    #[test]
+    fn perfect_powers() {
+        assert!(BoxedUint::zero().is_perfect_power_vartime());
+        assert!(BoxedUint::one().is_perfect_power_vartime());
+        assert!(!BoxedUint::from(2u8).is_perfect_power_vartime());
+        assert!(BoxedUint::from(4u8).is_perfect_power_vartime());
+        assert!(BoxedUint::from(8u8).is_perfect_power_vartime());
+        assert!(!BoxedUint::from(12u8).is_perfect_power_vartime());
+        assert!(BoxedUint::from(243u8).is_perfect_power_vartime());
+
+        // Product of two 64-bit primes
+        let n = BoxedUint::from_be_hex("ffffffffffffff720000000000001321", 128).unwrap();
+        assert!(!n.is_perfect_power_vartime());
+        assert!(n.square().is_perfect_power_vartime());
+        assert!(n.square().mul(&n).is_perfect_power_vartime());
+    }
+
+    #[test]
+    fn non_zero() {
+        let x = NonZero::new(BoxedUint::from(1u64 << 30)).unwrap();
+        assert_eq!(x.root(k(3)).get(), BoxedUint::from(1u64 << 10));
+        assert_eq!(x.root_vartime(k(5)).get(), BoxedUint::from(1u64 << 6));
+        assert!(x.checked_root(k(10)).is_some().to_bool());
+        assert!(!x.checked_root_vartime(k(7)).is_some().to_bool());
+        assert!(x.is_perfect_power_vartime());
+    }
+}
@@ -1,6 +1,9 @@
 //! [`BoxedUint`] bitwise left shift operations.
 
-use crate::{BoxedUint, Choice, CtOption, Limb, Shl, ShlAssign, ShlVartime, WrappingShl};
+use crate::{
+    BoxedUint, CheckedShl, Choice, CtEq, CtOption, Limb, SaturatingShl, Shl, ShlAssign, ShlVartime,
+    WrappingShl,
+};
 
 impl BoxedUint {
     /// Computes `self << shift`.
@@ -69,6 +72,15 @@ impl BoxedUint {
         self.as_mut_uint_ref().overflowing_shl_assign(shift);
     }
 
+    /// Computes `self << shift` in a panic-free manner, returning `MAX` if any non-zero bits
+    /// would be shifted out.
+    pub fn saturating_shl(&self, shift: u32) -> Self {
+        let mut res = self.wrapping_shl(shift);
+        let overflow = res.wrapping_shr(shift).ct_ne(self);
+        res.as_mut_uint_ref().conditional_set_max(overflow);
+        res
+    }
+
     /// Computes `self << shift` in variable-time in a panic-free manner, masking off bits of `shift` which would cause
     /// the shift to exceed the type's width.
     pub fn wrapping_shl_vartime(&self, shift: u32) -> Self {
@@ -160,6 +172,19 @@ macro_rules! impl_shl {
 
 impl_shl!(i32, u32, usize);
 
+impl SaturatingShl for BoxedUint {
+    fn saturating_shl(&self, shift: u32) -> BoxedUint {
+        self.saturating_shl(shift)
+    }
+}
+
+impl CheckedShl for BoxedUint {
+    fn checked_shl(&self, shift: u32) -> CtOption<Self> {
+        let (result, overflow) = self.overflowing_shl(shift);
+        CtOption::new(result, !overflow)
+    }
+}
+
 impl WrappingShl for BoxedUint {
     fn wrapping_shl(&self, shift: u32) -> BoxedUint {
         self.wrapping_shl(shift)
@@ -211,4 +236,18 @@ mod tests {
             one.shl_vartime(67).unwrap()
         );
     }
+
+    #[test]
+    fn saturating_shl() {
+        let one = BoxedUint::one_with_precision(128);
+        let max = BoxedUint::max(128);
+        assert_eq!(one.saturating_shl(127), one.shl(127));
+        assert_eq!(one.saturating_shl(128), max);
+        assert_eq!(max.saturating_shl(1), max);
+        assert_eq!(max.shr(1).saturating_shl(1), max.wrapping_sub(&one));
+        assert_eq!(
+            BoxedUint::zero_with_precision(128).saturating_shl(200),
+            BoxedUint::zero_with_precision(128)
+        );
+    }
 }
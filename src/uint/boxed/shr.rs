@@ -1,6 +1,8 @@
 //! [`BoxedUint`] bitwise right shift operations.
 
-use crate::{BoxedUint, Choice, CtOption, Limb, Shr, ShrAssign, ShrVartime, WrappingShr};
+use crate::{
+    BoxedUint, CheckedShr, Choice, CtOption, Limb, Shr, ShrAssign, ShrVartime, WrappingShr,
+};
 
 impl BoxedUint {
     /// Computes `self >> shift`.
@@ -157,6 +159,13 @@ macro_rules! impl_shr {
 
 impl_shr!(i32, u32, usize);
 
+impl CheckedShr for BoxedUint {
+    fn checked_shr(&self, shift: u32) -> CtOption<Self> {
+        let (result, overflow) = self.overflowing_shr(shift);
+        CtOption::new(result, !overflow)
+    }
+}
+
 impl WrappingShr for BoxedUint {
     fn wrapping_shr(&self, shift: u32) -> BoxedUint {
         self.wrapping_shr(shift)
@@ -1,8 +1,8 @@
 //! [`BoxedUint`] subtraction operations.
 
 use crate::{
-    BoxedUint, CheckedSub, Choice, CtOption, CtSelect, Limb, Sub, SubAssign, U64, U128, Uint,
-    Wrapping, WrappingSub,
+    BoxedUint, Checked, CheckedSub, Choice, CtOption, CtSelect, Limb, Saturating, SaturatingSub,
+    Sub, SubAssign, U64, U128, Uint, Wrapping, WrappingSub,
 };
 
 impl BoxedUint {
@@ -43,6 +43,14 @@ impl BoxedUint {
         borrow
     }
 
+    /// Perform saturating subtraction, returning `ZERO` on underflow.
+    pub fn saturating_sub(&self, rhs: &Self) -> Self {
+        let (mut res, borrow) = self.borrowing_sub(rhs, Limb::ZERO);
+        res.as_mut_uint_ref()
+            .conditional_set_zero(borrow.is_nonzero());
+        res
+    }
+
     /// Perform wrapping subtraction, discarding overflow.
     pub fn wrapping_sub(&self, rhs: &Self) -> Self {
         self.borrowing_sub(rhs, Limb::ZERO).0
@@ -77,6 +85,12 @@ impl CheckedSub for BoxedUint {
     }
 }
 
+impl SaturatingSub for BoxedUint {
+    fn saturating_sub(&self, rhs: &Self) -> Self {
+        self.saturating_sub(rhs)
+    }
+}
+
 impl Sub for BoxedUint {
     type Output = Self;
 
@@ -184,6 +198,30 @@ impl SubAssign<&Wrapping<BoxedUint>> for Wrapping<BoxedUint> {
     }
 }
 
+impl SubAssign<Checked<BoxedUint>> for Checked<BoxedUint> {
+    fn sub_assign(&mut self, other: Checked<BoxedUint>) {
+        *self = &*self - other;
+    }
+}
+
+impl SubAssign<&Checked<BoxedUint>> for Checked<BoxedUint> {
+    fn sub_assign(&mut self, other: &Checked<BoxedUint>) {
+        *self = &*self - other;
+    }
+}
+
+impl SubAssign<Saturating<BoxedUint>> for Saturating<BoxedUint> {
+    fn sub_assign(&mut self, other: Saturating<BoxedUint>) {
+        *self = Saturating(self.0.saturating_sub(&other.0));
+    }
+}
+
+impl SubAssign<&Saturating<BoxedUint>> for Saturating<BoxedUint> {
+    fn sub_assign(&mut self, other: &Saturating<BoxedUint>) {
+        *self = Saturating(self.0.saturating_sub(&other.0));
+    }
+}
+
 impl WrappingSub for BoxedUint {
     fn wrapping_sub(&self, v: &Self) -> Self {
         self.wrapping_sub(v)
@@ -294,4 +332,21 @@ mod tests {
         let mut h = BoxedUint::one().resize(1024);
         h -= BoxedUint::one();
     }
+
+    #[test]
+    fn saturating_sub() {
+        let max = BoxedUint::max(128);
+        assert_eq!(
+            max.saturating_sub(&max),
+            BoxedUint::zero_with_precision(128)
+        );
+        assert_eq!(
+            BoxedUint::one().saturating_sub(&BoxedUint::one()),
+            BoxedUint::zero()
+        );
+        assert_eq!(
+            BoxedUint::one().saturating_sub(&max),
+            BoxedUint::zero_with_precision(128)
+        );
+    }
 }
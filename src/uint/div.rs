@@ -2,8 +2,8 @@
 
 use super::div_limb::Reciprocal;
 use crate::{
-    CheckedDiv, CtOption, Div, DivAssign, DivRemLimb, DivVartime, Limb, NonZero, Rem, RemAssign,
-    RemLimb, Uint, UintRef, Wrapping,
+    CheckedDiv, CheckedRem, CtOption, Div, DivAssign, DivRemLimb, DivVartime, Limb, NonZero, Rem,
+    RemAssign, RemLimb, Uint, UintReciprocal, UintRef, Wrapping,
 };
 
 impl<const LIMBS: usize> Uint<LIMBS> {
@@ -47,6 +47,24 @@ impl<const LIMBS: usize> Uint<LIMBS> {
         (x, y)
     }
 
+    /// Computes `self` / `rhs` using a pre-made reciprocal, returning the quotient
+    /// and the remainder.
+    ///
+    /// This function is constant-time with respect to both `self` and `rhs`.
+    pub const fn div_rem_with_reciprocal<const RHS_LIMBS: usize>(
+        &self,
+        reciprocal: &UintReciprocal<RHS_LIMBS>,
+    ) -> (Self, Uint<RHS_LIMBS>) {
+        let (mut x, mut y) = (*self, reciprocal.divisor_normalized);
+        x.as_mut_uint_ref().div_rem_normalized(
+            y.as_mut_uint_ref(),
+            reciprocal.shift,
+            reciprocal.words,
+            &reciprocal.reciprocal,
+        );
+        (x, y)
+    }
+
     /// Computes `self` / `rhs`, returning the quotient and the remainder.
     ///
     /// This is variable-time only with respect to `rhs`.
@@ -73,6 +91,27 @@ impl<const LIMBS: usize> Uint<LIMBS> {
         y
     }
 
+    /// Computes `self` % `rhs` using a pre-made reciprocal.
+    pub const fn rem_with_reciprocal<const RHS_LIMBS: usize>(
+        &self,
+        reciprocal: &UintReciprocal<RHS_LIMBS>,
+    ) -> Uint<RHS_LIMBS> {
+        if LIMBS < RHS_LIMBS {
+            return self.div_rem_with_reciprocal(reciprocal).1;
+        }
+
+        let (mut x, mut y) = (*self, reciprocal.divisor_normalized);
+        let x = x.as_mut_uint_ref().split_at_mut(LIMBS - RHS_LIMBS);
+        UintRef::rem_wide_normalized(
+            x,
+            y.as_mut_uint_ref(),
+            reciprocal.shift,
+            reciprocal.words,
+            &reciprocal.reciprocal,
+        );
+        y
+    }
+
     /// Computes `self` % `rhs` in variable-time with respect to `rhs`.
     ///
     /// When used with a fixed `rhs`, this function is constant-time with respect
@@ -545,6 +584,12 @@ impl<const LIMBS: usize> DivVartime for Uint<LIMBS> {
     }
 }
 
+impl<const LIMBS: usize> CheckedRem for Uint<LIMBS> {
+    fn checked_rem(&self, rhs: &Uint<LIMBS>) -> CtOption<Self> {
+        self.checked_rem(rhs)
+    }
+}
+
 impl<const LIMBS: usize, const RHS_LIMBS: usize> Rem<&NonZero<Uint<RHS_LIMBS>>> for &Uint<LIMBS> {
     type Output = Uint<RHS_LIMBS>;
 
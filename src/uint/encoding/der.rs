@@ -25,7 +25,10 @@ where
 
     fn try_from(bytes: Asn1UintRef<'a>) -> der::Result<Uint<LIMBS>> {
         let mut array = Array::default();
-        let offset = array.len().saturating_sub(bytes.len().try_into()?);
+        let offset = array
+            .len()
+            .checked_sub(bytes.len().try_into()?)
+            .ok_or_else(|| Asn1UintRef::TAG.length_error())?;
         array[offset..].copy_from_slice(bytes.as_bytes());
         Ok(Uint::from_be_byte_array(array))
     }
@@ -119,6 +122,10 @@ pub mod allocating {
     impl<'a> DecodeValue<'a> for BoxedUint {
         type Error = der::Error;
 
+        /// Decodes a non-negative DER `INTEGER`.
+        ///
+        /// Negative values are rejected with a value error, and non-minimal encodings with a
+        /// non-canonical error, just as for [`Uint`](crate::Uint).
         fn decode_value<R: der::Reader<'a>>(
             reader: &mut R,
             header: der::Header,
@@ -139,8 +146,8 @@ pub mod allocating {
 
 #[cfg(test)]
 pub mod test {
-    use crate::{ArrayEncoding, BoxedUint, U128, Uint};
-    use der::{DecodeValue, EncodeValue, Header, Tag};
+    use crate::{ArrayEncoding, BoxedUint, U64, U128, Uint};
+    use der::{Decode, DecodeValue, EncodeValue, ErrorKind, Header, Tag};
 
     fn assert_valid_uint_value_len<const LIMBS: usize>(n: &Uint<LIMBS>) -> der::Result<()>
     where
@@ -220,4 +227,35 @@ pub mod test {
         assert_valid_value_len_hex("7fdcba9876543210fedcba9876543210");
         assert_valid_value_len_hex("fedcba9876543210fedcba9876543210");
     }
+
+    #[test]
+    fn reject_negative() {
+        for der in [&[0x02, 0x01, 0x80][..], &[0x02, 0x02, 0xFF, 0x7F]] {
+            let err = U128::from_der(der).unwrap_err();
+            assert_eq!(err.kind(), ErrorKind::Value { tag: Tag::Integer });
+
+            #[cfg(feature = "alloc")]
+            assert_eq!(
+                BoxedUint::from_der(der).unwrap_err().kind(),
+                ErrorKind::Value { tag: Tag::Integer }
+            );
+        }
+    }
+
+    #[test]
+    fn reject_non_canonical() {
+        for der in [&[0x02, 0x02, 0x00, 0x7F][..], &[0x02, 0x02, 0x00, 0x00]] {
+            let err = U128::from_der(der).unwrap_err();
+            assert_eq!(err.kind(), ErrorKind::Noncanonical { tag: Tag::Integer });
+
+            #[cfg(feature = "alloc")]
+            assert!(BoxedUint::from_der(der).is_err());
+        }
+    }
+
+    #[test]
+    fn reject_oversized() {
+        let der = [0x02, 0x09, 0x01, 0, 0, 0, 0, 0, 0, 0, 0];
+        assert!(U64::from_der(&der).is_err());
+    }
 }
@@ -0,0 +1,242 @@
+//! [`Uint`] integer logarithm operations.
+
+use crate::{CtOption, Limb, Uint};
+
+impl<const LIMBS: usize> Uint<LIMBS> {
+    /// Computes `floor(log2(self))` in constant time.
+    ///
+    /// # Panics
+    /// - if `self` is zero.
+    pub const fn ilog2(&self) -> u32 {
+        self.checked_ilog2()
+            .expect_copied("logarithm of zero is undefined")
+    }
+
+    /// Computes `floor(log2(self))`.
+    ///
+    /// This method is variable-time with respect to `self`.
+    ///
+    /// # Panics
+    /// - if `self` is zero.
+    pub const fn ilog2_vartime(&self) -> u32 {
+        self.checked_ilog2_vartime()
+            .expect_copied("logarithm of zero is undefined")
+    }
+
+    /// Computes `floor(log2(self))` in constant time, returning a [`CtOption`] which `is_some`
+    /// only if `self` is non-zero.
+    pub const fn checked_ilog2(&self) -> CtOption<u32> {
+        CtOption::new(self.bits().saturating_sub(1), self.is_nonzero())
+    }
+
+    /// Computes `floor(log2(self))`, returning a [`CtOption`] which `is_some`
+    /// only if `self` is non-zero.
+    ///
+    /// This method is variable-time with respect to `self`.
+    pub const fn checked_ilog2_vartime(&self) -> CtOption<u32> {
+        CtOption::new(self.bits_vartime().saturating_sub(1), self.is_nonzero())
+    }
+
+    /// Computes `floor(log10(self))` in constant time.
+    ///
+    /// # Panics
+    /// - if `self` is zero.
+    pub const fn ilog10(&self) -> u32 {
+        self.ilog(&Self::from_u8(10))
+    }
+
+    /// Computes `floor(log10(self))`.
+    ///
+    /// This method is variable-time with respect to `self`.
+    ///
+    /// # Panics
+    /// - if `self` is zero.
+    pub const fn ilog10_vartime(&self) -> u32 {
+        self.ilog_vartime(&Self::from_u8(10))
+    }
+
+    /// Computes `floor(log10(self))` in constant time, returning a [`CtOption`] which `is_some`
+    /// only if `self` is non-zero.
+    pub const fn checked_ilog10(&self) -> CtOption<u32> {
+        self.checked_ilog(&Self::from_u8(10))
+    }
+
+    /// Computes `floor(log10(self))`, returning a [`CtOption`] which `is_some`
+    /// only if `self` is non-zero.
+    ///
+    /// This method is variable-time with respect to `self`.
+    pub const fn checked_ilog10_vartime(&self) -> CtOption<u32> {
+        self.checked_ilog_vartime(&Self::from_u8(10))
+    }
+
+    /// Computes `floor(log_base(self))`.
+    ///
+    /// This method is constant-time with respect to `self`, but not `base`.
+    ///
+    /// # Panics
+    /// - if `self` is zero or `base` is less than 2.
+    pub const fn ilog(&self, base: &Self) -> u32 {
+        self.checked_ilog(base)
+            .expect_copied("logarithm of zero or to a base less than 2 is undefined")
+    }
+
+    /// Computes `floor(log_base(self))`.
+    ///
+    /// This method is variable-time with respect to `self` and `base`.
+    ///
+    /// # Panics
+    /// - if `self` is zero or `base` is less than 2.
+    pub const fn ilog_vartime(&self, base: &Self) -> u32 {
+        self.checked_ilog_vartime(base)
+            .expect_copied("logarithm of zero or to a base less than 2 is undefined")
+    }
+
+    /// Computes `floor(log_base(self))`, returning a [`CtOption`] which `is_some`
+    /// only if `self` is non-zero and `base` is at least 2.
+    ///
+    /// This method is constant-time with respect to `self`, but not `base`.
+    pub const fn checked_ilog(&self, base: &Self) -> CtOption<u32> {
+        let log = if base.cmp_vartime(&Self::ONE).is_le() {
+            0
+        } else if base.limbs[0].0.count_ones() == 1 && base.bits_vartime() <= Limb::BITS {
+            // `base = 2^m`, so `floor(log_base(self)) = floor(floor(log2(self)) / m)`
+            self.bits().saturating_sub(1) / base.limbs[0].0.trailing_zeros()
+        } else if base.bits_vartime() <= Limb::BITS {
+            // Avoid full-size multiplications for the common case of a single limb base
+            self.count_powers(&Uint::<1>::from_word(base.limbs[0].0))
+        } else {
+            self.count_powers(base)
+        };
+
+        let is_some = self.is_nonzero().and(Self::lt(&Self::ONE, base));
+        CtOption::new(log, is_some)
+    }
+
+    /// Computes `floor(log_base(self))`, returning a [`CtOption`] which `is_some`
+    /// only if `self` is non-zero and `base` is at least 2.
+    ///
+    /// This method is variable-time with respect to `self` and `base`.
+    pub const fn checked_ilog_vartime(&self, base: &Self) -> CtOption<u32> {
+        let mut log = 0;
+        if self.cmp_vartime(base).is_ge() && base.cmp_vartime(&Self::ONE).is_gt() {
+            let mut power = *base;
+            loop {
+                log += 1;
+
+                let next = power.checked_mul(base);
+                if next.is_none().to_bool_vartime() {
+                    break;
+                }
+                power = next.to_inner_unchecked();
+                if power.cmp_vartime(self).is_gt() {
+                    break;
+                }
+            }
+        }
+
+        let is_some = self.is_nonzero().and(Self::lt(&Self::ONE, base));
+        CtOption::new(log, is_some)
+    }
+
+    /// Counts the powers `base^k` with `k >= 1` which are less than or equal to `self`.
+    ///
+    /// This method is constant-time with respect to `self`, but not `base`.
+    const fn count_powers<const RHS_LIMBS: usize>(&self, base: &Uint<RHS_LIMBS>) -> u32 {
+        let mut ret = 0;
+
+        // The powers of `base` which fit in `Self` only depend on `base`
+        let mut power = base.resize::<LIMBS>();
+        loop {
+            ret += Self::lte(&power, self).select_u32(0, 1);
+
+            let next = power.checked_mul(base);
+            if next.is_none().to_bool_vartime() {
+                break;
+            }
+            power = next.to_inner_unchecked();
+        }
+
+        ret
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{U64, U128, U256, Uint};
+
+    #[test]
+    fn ilog2() {
+        assert!(U256::ZERO.checked_ilog2().is_none().to_bool());
+        assert!(U256::ZERO.checked_ilog2_vartime().is_none().to_bool());
+        assert_eq!(U256::ONE.ilog2(), 0);
+        assert_eq!(U256::from_u8(2).ilog2(), 1);
+        assert_eq!(U256::from_u8(3).ilog2_vartime(), 1);
+        assert_eq!(U256::MAX.ilog2(), 255);
+        assert_eq!(U256::MAX.ilog2_vartime(), 255);
+    }
+
+    #[test]
+    #[should_panic]
+    fn ilog2_zero() {
+        U256::ZERO.ilog2();
+    }
+
+    #[test]
+    fn ilog10() {
+        assert!(U256::ZERO.checked_ilog10().is_none().to_bool());
+        assert!(U256::ZERO.checked_ilog10_vartime().is_none().to_bool());
+
+        let mut power = U128::ONE;
+        for k in 0..=38 {
+            assert_eq!(power.ilog10(), k);
+            assert_eq!(power.ilog10_vartime(), k);
+            if k > 0 {
+                let below = power.wrapping_sub(&U128::ONE);
+                assert_eq!(below.ilog10(), k - 1);
+                assert_eq!(below.ilog10_vartime(), k - 1);
+            }
+            power = power.wrapping_mul(&U128::from_u8(10));
+        }
+
+        // `u128::MAX` has 39 decimal digits
+        assert_eq!(U128::MAX.ilog10(), 38);
+        assert_eq!(U128::MAX.ilog10_vartime(), 38);
+    }
+
+    #[test]
+    fn ilog() {
+        let x = U64::from_u64(u64::MAX);
+        for base in [
+            2u64,
+            3,
+            4,
+            7,
+            16,
+            255,
+            256,
+            1 << 32,
+            (1 << 32) + 1,
+            u64::MAX,
+        ] {
+            let expected = u64::MAX.ilog(base);
+            let base = U64::from_u64(base);
+            assert_eq!(x.ilog(&base), expected);
+            assert_eq!(x.ilog_vartime(&base), expected);
+        }
+
+        // Multi-limb base
+        let base = U256::ONE.shl_vartime(100).wrapping_add(&U256::ONE);
+        let x = base.wrapping_mul(&base);
+        assert_eq!(x.ilog(&base), 2);
+        assert_eq!(x.ilog_vartime(&base), 2);
+        assert_eq!(x.wrapping_sub(&U256::ONE).ilog(&base), 1);
+        assert_eq!(x.wrapping_sub(&U256::ONE).ilog_vartime(&base), 1);
+        assert_eq!(U256::ONE.ilog(&base), 0);
+        assert_eq!(U256::ONE.ilog_vartime(&base), 0);
+
+        for base in [Uint::ZERO, Uint::ONE] {
+            assert!(U256::MAX.checked_ilog(&base).is_none().to_bool());
+            assert!(U256::MAX.checked_ilog_vartime(&base).is_none().to_bool());
+        }
+    }
+}
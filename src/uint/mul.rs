@@ -2,7 +2,7 @@
 
 use crate::{
     Checked, CheckedMul, Choice, Concat, ConcatMixed, ConcatenatingMul, CtOption, Limb, Mul,
-    MulAssign, Uint, UintRef, Wrapping, WrappingMul,
+    MulAssign, Saturating, SaturatingMul, Uint, UintRef, Wrapping, WrappingMul,
 };
 
 pub(crate) mod karatsuba;
@@ -185,6 +185,24 @@ impl<const LIMBS: usize> MulAssign<&Checked<Uint<LIMBS>>> for Checked<Uint<LIMBS
     }
 }
 
+impl<const LIMBS: usize> MulAssign<Saturating<Uint<LIMBS>>> for Saturating<Uint<LIMBS>> {
+    fn mul_assign(&mut self, other: Saturating<Uint<LIMBS>>) {
+        *self = *self * other;
+    }
+}
+
+impl<const LIMBS: usize> MulAssign<&Saturating<Uint<LIMBS>>> for Saturating<Uint<LIMBS>> {
+    fn mul_assign(&mut self, other: &Saturating<Uint<LIMBS>>) {
+        *self = *self * other;
+    }
+}
+
+impl<const LIMBS: usize> SaturatingMul for Uint<LIMBS> {
+    fn saturating_mul(&self, rhs: &Self) -> Self {
+        self.saturating_mul(rhs)
+    }
+}
+
 impl<const LIMBS: usize, const RHS_LIMBS: usize, const WIDE_LIMBS: usize>
     ConcatenatingMul<Uint<RHS_LIMBS>> for Uint<LIMBS>
 where
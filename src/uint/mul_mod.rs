@@ -4,6 +4,10 @@ use crate::{Limb, MulMod, NonZero, SquareMod, Uint, WideWord, Word, div_limb::mu
 
 impl<const LIMBS: usize> Uint<LIMBS> {
     /// Computes `self * rhs mod p`.
+    ///
+    /// When performing many multiplications modulo the same `p`, consider precomputing
+    /// [`BarrettParams`](crate::modular::BarrettParams) and using
+    /// [`BarrettParams::mul_mod`](crate::modular::BarrettParams::mul_mod) instead.
     pub fn mul_mod(&self, rhs: &Uint<LIMBS>, p: &NonZero<Uint<LIMBS>>) -> Uint<LIMBS> {
         let lo_hi = self.widening_mul(rhs);
         Self::rem_wide(lo_hi, p)
@@ -81,7 +85,7 @@ impl<const LIMBS: usize> SquareMod for Uint<LIMBS> {
 }
 
 /// Computes `a + (b * c) + carry`, returning the result along with the new carry.
-const fn mac_by_limb<const LIMBS: usize>(
+pub(crate) const fn mac_by_limb<const LIMBS: usize>(
     a: &Uint<LIMBS>,
     b: &Uint<LIMBS>,
     c: Limb,
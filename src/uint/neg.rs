@@ -1,4 +1,6 @@
-use crate::{Choice, Limb, Uint, WideWord, Word, WrappingNeg, word};
+use crate::{
+    CheckedNeg, Choice, CtOption, Limb, SaturatingNeg, Uint, WideWord, Word, WrappingNeg, word,
+};
 
 impl<const LIMBS: usize> Uint<LIMBS> {
     /// Perform wrapping negation.
@@ -27,6 +29,32 @@ impl<const LIMBS: usize> Uint<LIMBS> {
     pub const fn wrapping_neg_if(&self, negate: Choice) -> Self {
         Uint::select(self, &self.wrapping_neg(), negate)
     }
+
+    /// Perform checked negation, returning a [`CtOption`] which `is_some` only if `self` is
+    /// `ZERO`, since the negation of any non-zero value is below the lower bound of the type.
+    pub const fn checked_neg(&self) -> CtOption<Self> {
+        CtOption::new(Self::ZERO, self.is_zero())
+    }
+
+    /// Perform saturating negation, returning `ZERO`, since the negation of any non-zero value
+    /// is below the lower bound of the type.
+    pub const fn saturating_neg(&self) -> Self {
+        Self::ZERO
+    }
+}
+
+impl<const LIMBS: usize> CheckedNeg for Uint<LIMBS> {
+    #[inline]
+    fn checked_neg(&self) -> CtOption<Self> {
+        self.checked_neg()
+    }
+}
+
+impl<const LIMBS: usize> SaturatingNeg for Uint<LIMBS> {
+    #[inline]
+    fn saturating_neg(&self) -> Self {
+        self.saturating_neg()
+    }
 }
 
 impl<const LIMBS: usize> WrappingNeg for Uint<LIMBS> {
@@ -0,0 +1,111 @@
+//! Pre-calculated reciprocals for division by a multi-limb [`Uint`].
+
+use crate::{Limb, NonZero, Reciprocal, Uint};
+
+/// A pre-calculated reciprocal for division by a multi-limb divisor.
+///
+/// This holds the divisor normalized such that its high bit is set, along with the reciprocal
+/// of its highest limb, so that repeated divisions by the same value can skip these steps.
+/// See [`Uint::div_rem_with_reciprocal`] and [`Uint::rem_with_reciprocal`].
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub struct UintReciprocal<const LIMBS: usize> {
+    /// The divisor left-shifted by `shift` bits.
+    pub(crate) divisor_normalized: Uint<LIMBS>,
+    /// The number of leading zeros of the divisor.
+    pub(crate) shift: u32,
+    /// The number of non-zero limbs of the divisor.
+    pub(crate) words: u32,
+    /// The reciprocal of the highest limb of the normalized divisor, or of the divisor itself
+    /// when `LIMBS` is 1.
+    pub(crate) reciprocal: Reciprocal,
+}
+
+impl<const LIMBS: usize> UintReciprocal<LIMBS> {
+    /// Pre-calculates a reciprocal for a known divisor,
+    /// to be used in the multi-limb division later.
+    pub const fn new(divisor: &NonZero<Uint<LIMBS>>) -> Self {
+        let divisor = divisor.as_ref();
+        let bits = divisor.bits();
+        let shift = Uint::<LIMBS>::BITS - bits;
+        let divisor_normalized = divisor.wrapping_shl(shift);
+
+        let reciprocal = if LIMBS == 1 {
+            Reciprocal::new(NonZero(divisor.limbs[0]))
+        } else {
+            Reciprocal::new(NonZero(divisor_normalized.limbs[LIMBS - 1]))
+        };
+
+        Self {
+            divisor_normalized,
+            shift,
+            words: bits.div_ceil(Limb::BITS),
+            reciprocal,
+        }
+    }
+
+    /// Get the divisor.
+    pub const fn divisor(&self) -> NonZero<Uint<LIMBS>> {
+        NonZero(self.divisor_normalized.wrapping_shr(self.shift))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::UintReciprocal;
+    use crate::{NonZero, U64, U128, U256, U512};
+
+    #[test]
+    fn divisor() {
+        let d = NonZero::new(U256::from_be_hex(
+            "000000000000000000000000000000000123456789abcdef0123456789abcdef",
+        ))
+        .unwrap();
+        assert_eq!(UintReciprocal::new(&d).divisor(), d);
+    }
+
+    #[test]
+    fn div_rem() {
+        let x = U512::from_be_hex(concat![
+            "a8d3c0f0d4a36a0b7a1b1d1c7c5e42b9e3b0c44298fc1c149afbf4c8996fb924",
+            "27ae41e4649b934ca495991b7852b855ffffffffffffffff0000000000000001"
+        ]);
+        let divisors = [
+            U256::ONE,
+            U256::from_u8(7),
+            U256::from_u64(u64::MAX),
+            U128::MAX.resize(),
+            U256::from_be_hex("00000000000000000000000000000001ffffffffffffffffffffffffffffffff"),
+            U256::from_be_hex("8000000000000000000000000000000000000000000000000000000000000001"),
+            U256::MAX,
+        ];
+        for d in divisors {
+            let d = NonZero::new(d).unwrap();
+            let reciprocal = UintReciprocal::new(&d);
+            let expected = x.div_rem(&d);
+            assert_eq!(x.div_rem_with_reciprocal(&reciprocal), expected);
+            assert_eq!(x.rem_with_reciprocal(&reciprocal), expected.1);
+        }
+    }
+
+    #[test]
+    fn single_limb() {
+        let x = U256::MAX;
+        for d in [1u64, 3, 10, 1 << 63, u64::MAX] {
+            let d = NonZero::new(U64::from_u64(d)).unwrap();
+            let reciprocal = UintReciprocal::new(&d);
+            assert_eq!(x.div_rem_with_reciprocal(&reciprocal), x.div_rem(&d));
+            assert_eq!(x.rem_with_reciprocal(&reciprocal), x.rem(&d));
+        }
+    }
+
+    #[test]
+    fn narrow_dividend() {
+        let x = U128::MAX;
+        for d in [U256::from_u8(3), U128::MAX.resize(), U256::MAX] {
+            let d = NonZero::new(d).unwrap();
+            let reciprocal = UintReciprocal::new(&d);
+            assert_eq!(x.div_rem_with_reciprocal(&reciprocal), x.div_rem(&d));
+            assert_eq!(x.rem_with_reciprocal(&reciprocal), x.rem(&d));
+        }
+    }
+}
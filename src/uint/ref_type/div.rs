@@ -34,11 +34,40 @@ impl UintRef {
         let yz = y.bits_precision() - ybits;
         y.wrapping_shl_assign(yz);
 
+        // Calculate a reciprocal from the highest word of the divisor
+        let reciprocal = Reciprocal::new(y.0[y.nlimbs() - 1].to_nz().expect_copied("zero divisor"));
+
+        x.div_rem_normalized(y, yz, ywords, &reciprocal);
+    }
+
+    /// Computes `self` / `rhs` for a normalized divisor, returning the quotient in `self` and
+    /// the remainder in `rhs`.
+    ///
+    /// `rhs` must hold the divisor left-shifted by `yz` bits such that its high bit is set, where
+    /// `ywords` is the number of non-zero limbs of the unshifted divisor. `reciprocal` must be
+    /// calculated from the highest limb of `rhs`, or from the unshifted divisor if `rhs` is a
+    /// single limb.
+    #[inline(always)]
+    pub(crate) const fn div_rem_normalized(
+        &mut self,
+        rhs: &mut Self,
+        yz: u32,
+        ywords: u32,
+        reciprocal: &Reciprocal,
+    ) {
+        let (x, y) = (self, rhs);
+
+        // Short circuit for single-word divisor
+        if y.nlimbs() == 1 {
+            y.0[0] = x.div_rem_limb_with_reciprocal(reciprocal);
+            return;
+        }
+
         // Shift the dividend to align the words
         let lshift = yz % Limb::BITS;
         let x_hi = x.shl_assign_limb(lshift);
 
-        Self::div_rem_shifted(x, x_hi, y, ywords);
+        Self::div_rem_shifted(x, x_hi, y, ywords, reciprocal);
 
         x.wrapping_shr_assign_by_limbs(ywords - 1);
         y.shr_assign_limb(lshift);
@@ -112,6 +141,35 @@ impl UintRef {
         let yz = y.bits_precision() - ybits;
         y.wrapping_shl_assign(yz);
 
+        // Calculate a reciprocal from the highest word of the divisor
+        let reciprocal = Reciprocal::new(y.0[y.nlimbs() - 1].to_nz().expect_copied("zero divisor"));
+
+        Self::rem_wide_normalized((x_lo, x), y, yz, ywords, &reciprocal);
+    }
+
+    /// Computes `x_lower_upper` % `rhs` for a normalized divisor, returning the remainder
+    /// in `rhs`.
+    ///
+    /// The requirements on `rhs`, `yz`, `ywords` and `reciprocal` are the same as for
+    /// [`UintRef::div_rem_normalized`]. `x_lower_upper` is left in an indeterminate state.
+    #[inline(always)]
+    pub(crate) const fn rem_wide_normalized(
+        x_lower_upper: (&mut Self, &mut Self),
+        rhs: &mut Self,
+        yz: u32,
+        ywords: u32,
+        reciprocal: &Reciprocal,
+    ) {
+        let (x_lo, x) = x_lower_upper;
+        let y = rhs;
+
+        // Short circuit for single-word divisor
+        if y.nlimbs() == 1 {
+            let carry = x.rem_limb_with_reciprocal(reciprocal, Limb::ZERO);
+            y.0[0] = x_lo.rem_limb_with_reciprocal(reciprocal, carry);
+            return;
+        }
+
         // Shift the dividend to align the words
         let lshift = yz % Limb::BITS;
         let x_lo_carry = x_lo.shl_assign_limb(lshift);
@@ -119,7 +177,7 @@ impl UintRef {
         x.0[0] = x.0[0].bitor(x_lo_carry);
 
         // Perform the core division algorithm
-        Self::rem_wide_shifted((x_lo, x), x_hi, y, ywords);
+        Self::rem_wide_shifted((x_lo, x), x_hi, y, ywords, reciprocal);
 
         // Unshift the remainder from the earlier adjustment
         y.shr_assign_limb(lshift);
@@ -195,20 +253,24 @@ impl UintRef {
     /// Perform in-place division (`self` / `y`) for a pre-shifted dividend and divisor.
     ///
     /// The dividend and divisor must be left-shifted such that the high bit of the divisor
-    /// is set, and `x_hi` holds the top bits of the dividend.
+    /// is set, `x_hi` holds the top bits of the dividend, and `reciprocal` must be calculated
+    /// from the highest word of the divisor.
     ///
     /// The quotient is returned in `self` and the remainder in `y`, but these values require
     /// additional correction. This is left to the caller for performance reasons.
     #[inline(always)]
-    pub const fn div_rem_shifted(&mut self, mut x_hi: Limb, y: &mut Self, ywords: u32) {
+    pub const fn div_rem_shifted(
+        &mut self,
+        mut x_hi: Limb,
+        y: &mut Self,
+        ywords: u32,
+        reciprocal: &Reciprocal,
+    ) {
         let x = self;
-
-        // Calculate a reciprocal from the highest word of the divisor
-        let reciprocal = Reciprocal::new(y.0[y.nlimbs() - 1].to_nz().expect_copied("zero divisor"));
         debug_assert!(reciprocal.shift() == 0);
 
         // Perform the core division algorithm
-        x_hi = Self::div_rem_large_shifted(x, x_hi, y, ywords, reciprocal, Choice::FALSE);
+        x_hi = Self::div_rem_large_shifted(x, x_hi, y, ywords, *reciprocal, Choice::FALSE);
 
         // Calculate quotient and remainder for the case where the divisor is a single word.
         let limb_div = Choice::from_u32_eq(1, ywords);
@@ -216,7 +278,7 @@ impl UintRef {
         // but this can only be the case if `limb_div` is falsy, in which case we discard
         // the result anyway, so we conditionally set `x_hi` to zero for this branch.
         let x_hi_adjusted = Limb::select(Limb::ZERO, x_hi, limb_div);
-        let (quo2, rem2) = div2by1(x_hi_adjusted.0, x.0[0].0, &reciprocal);
+        let (quo2, rem2) = div2by1(x_hi_adjusted.0, x.0[0].0, reciprocal);
 
         // Adjust the quotient for single limb division
         x.0[0] = Limb::select(x.0[0], Limb(quo2), limb_div);
@@ -357,7 +419,8 @@ impl UintRef {
     /// tracking only the remainder.
     ///
     /// The dividend and divisor must be left-shifted such that the high bit of the divisor
-    /// is set, and `x_hi` holds the top bits of the dividend.
+    /// is set, `x_hi` holds the top bits of the dividend, and `reciprocal` must be calculated
+    /// from the highest word of the divisor.
     ///
     /// The shifted remainder is returned in `y`, and must be unshifted by the caller.
     /// `x` is left in an indeterminate state.
@@ -367,16 +430,14 @@ impl UintRef {
         mut x_hi: Limb,
         y: &mut Self,
         ywords: u32,
+        reciprocal: &Reciprocal,
     ) {
         let (x_lo, x) = x;
         let ysize = y.nlimbs();
-
-        // Calculate a reciprocal from the highest word of the divisor
-        let reciprocal = Reciprocal::new(y.0[ysize - 1].to_nz().expect_copied("zero divisor"));
         debug_assert!(reciprocal.shift() == 0);
 
         // Perform the core division algorithm
-        x_hi = Self::rem_wide_large_shifted((x_lo, x), x_hi, y, ywords, reciprocal, Choice::FALSE);
+        x_hi = Self::rem_wide_large_shifted((x_lo, x), x_hi, y, ywords, *reciprocal, Choice::FALSE);
 
         // Calculate remainder for the case where the divisor is a single word.
         let limb_div = Choice::from_u32_eq(1, ywords);
@@ -384,7 +445,7 @@ impl UintRef {
         // but this can only be the case if `limb_div` is falsy, in which case we discard
         // the result anyway, so we conditionally set `x_hi` to zero for this branch.
         let x_hi_adjusted = Limb::select(Limb::ZERO, x_hi, limb_div);
-        let (_, rem2) = div2by1(x_hi_adjusted.0, x.0[0].0, &reciprocal);
+        let (_, rem2) = div2by1(x_hi_adjusted.0, x.0[0].0, reciprocal);
 
         // Copy out the low limb of the remainder
         y.0[0] = Limb::select(x.0[0], Limb(rem2), limb_div);
@@ -0,0 +1,301 @@
+//! [`Uint`] integer `k`-th root operations.
+
+use crate::{
+    Choice, CtEq, CtOption, Limb, NonZero, NonZeroUint, Uint, Word, primality::SMALL_PRIMES,
+};
+use core::num::NonZeroU32;
+
+impl<const LIMBS: usize> Uint<LIMBS> {
+    /// Computes `floor(self^(1/k))`, the integer `k`-th root of `self`, in constant time.
+    ///
+    /// This method is constant-time with respect to `self`, but not `k`.
+    ///
+    /// Callers can check if `self` is a perfect `k`-th power with [`Self::checked_root`].
+    pub const fn root(&self, k: NonZeroU32) -> Self {
+        let k = k.get();
+
+        // Determine the bits of the root from the top down, keeping each one if the `k`-th power
+        // of the partial root doesn't exceed `self`. The root has at most `ceil(BITS / k)` bits.
+        let mut ret = Self::ZERO;
+        let mut i = Self::BITS.div_ceil(k);
+        while i > 0 {
+            i -= 1;
+            let candidate = ret.set_bit_vartime(i, true);
+            let (pow, overflow) = candidate.overflowing_pow(k);
+            let is_le = overflow.not().and(Self::lte(&pow, self));
+            ret = Self::select(&ret, &candidate, is_le);
+        }
+
+        ret
+    }
+
+    /// Computes `floor(self^(1/k))`, the integer `k`-th root of `self`.
+    ///
+    /// Callers can check if `self` is a perfect `k`-th power with [`Self::checked_root_vartime`].
+    pub const fn root_vartime(&self, k: NonZeroU32) -> Self {
+        // Uses Newton's method, generalizing Brent & Zimmermann, Modern Computer Arithmetic,
+        // v0.5.9, Algorithm 1.13
+        let k = k.get();
+        if k == 1 || self.is_zero_vartime() {
+            return *self;
+        }
+
+        // The initial guess: `x_0 = 2^ceil(b/k)`, where `2^(b-1) <= self < 2^b`.
+        // Will not overflow since `b <= BITS` and `k >= 2`.
+        let mut x = Self::ONE.shl_vartime(self.bits_vartime().div_ceil(k)); // > self^(1/k)
+        let k_limb = NonZero(Limb(k as Word));
+        let k_minus_one = Self::from_u32(k - 1);
+
+        loop {
+            // Calculate `x_{i+1} = floor(((k - 1) * x_i + self / x_i^(k-1)) / k)`
+            let (pow, overflow) = x.overflowing_pow(k - 1);
+            let q = if overflow.to_bool_vartime() {
+                Self::ZERO
+            } else {
+                self.wrapping_div_vartime(&NonZero(pow))
+            };
+
+            // `(k - 1) * x_i` can't overflow since `x_i <= x_0`, but the sum can once `x_i` has
+            // reached the root, in which case `x_{i+1} > x_i`
+            let (sum, carry) = x.wrapping_mul(&k_minus_one).carrying_add(&q, Limb::ZERO);
+            if carry.is_nonzero().to_bool_vartime() {
+                break;
+            }
+            let next_x = sum.div_rem_limb(k_limb).0;
+
+            // The iterates decrease until they reach the root, after which they go back up
+            if !x.cmp_vartime(&next_x).is_gt() {
+                break;
+            }
+
+            x = next_x;
+        }
+
+        x
+    }
+
+    /// Perform checked `k`-th root, returning a [`CtOption`] which `is_some`
+    /// only if `self` is a perfect `k`-th power.
+    pub fn checked_root(&self, k: NonZeroU32) -> CtOption<Self> {
+        let r = self.root(k);
+        let (s, _) = r.overflowing_pow(k.get());
+        CtOption::new(r, self.ct_eq(&s))
+    }
+
+    /// Perform checked `k`-th root, returning a [`CtOption`] which `is_some`
+    /// only if `self` is a perfect `k`-th power.
+    pub fn checked_root_vartime(&self, k: NonZeroU32) -> CtOption<Self> {
+        let r = self.root_vartime(k);
+        let (s, _) = r.overflowing_pow(k.get());
+        CtOption::new(r, self.ct_eq(&s))
+    }
+
+    /// Is `self` a perfect power, i.e. is `self = x^k` for some integers `x` and `k >= 2`?
+    ///
+    /// Zero and one are considered to be perfect powers.
+    pub const fn is_perfect_power_vartime(&self) -> bool {
+        if self.cmp_vartime(&Self::ONE).is_le() {
+            return true;
+        }
+
+        // If `self = x^k` with `x >= 2`, then `k < b` where `2^(b-1) <= self < 2^b`.
+        // It suffices to check prime `k`, since `x^(pk') = (x^k')^p`.
+        let bits = self.bits_vartime();
+        let mut k = 2;
+        while k < bits {
+            let r = self.root_vartime(NonZeroU32::new(k).expect("k is non-zero"));
+            let (s, overflow) = r.overflowing_pow(k);
+            if !overflow.to_bool_vartime() && Self::eq(&s, self).to_bool_vartime() {
+                return true;
+            }
+            k = next_root_exponent(k);
+        }
+
+        false
+    }
+
+    /// Computes `self^exp`, along with a [`Choice`] which is truthy if the result overflowed.
+    ///
+    /// This method is constant-time with respect to `self`, but not `exp`.
+    const fn overflowing_pow(&self, exp: u32) -> (Self, Choice) {
+        if exp == 0 {
+            return (Self::ONE, Choice::FALSE);
+        }
+
+        let mut ret = *self;
+        let mut overflow = Choice::FALSE;
+        let mut i = u32::BITS - exp.leading_zeros() - 1;
+        while i > 0 {
+            i -= 1;
+            let square = ret.checked_square();
+            overflow = overflow.or(square.is_none());
+            ret = square.to_inner_unchecked();
+
+            if (exp >> i) & 1 == 1 {
+                let product = ret.checked_mul(self);
+                overflow = overflow.or(product.is_none());
+                ret = product.to_inner_unchecked();
+            }
+        }
+
+        (ret, overflow)
+    }
+}
+
+impl<const LIMBS: usize> NonZeroUint<LIMBS> {
+    /// Computes `floor(self^(1/k))`, the integer `k`-th root of `self`, in constant time.
+    ///
+    /// This method is constant-time with respect to `self`, but not `k`.
+    pub const fn root(&self, k: NonZeroU32) -> Self {
+        // The root of a non-zero value is at least one
+        NonZero(self.0.root(k))
+    }
+
+    /// Computes `floor(self^(1/k))`, the integer `k`-th root of `self`.
+    pub const fn root_vartime(&self, k: NonZeroU32) -> Self {
+        NonZero(self.0.root_vartime(k))
+    }
+
+    /// Perform checked `k`-th root, returning a [`CtOption`] which `is_some`
+    /// only if `self` is a perfect `k`-th power.
+    pub fn checked_root(&self, k: NonZeroU32) -> CtOption<Self> {
+        self.0.checked_root(k).map(NonZero)
+    }
+
+    /// Perform checked `k`-th root, returning a [`CtOption`] which `is_some`
+    /// only if `self` is a perfect `k`-th power.
+    pub fn checked_root_vartime(&self, k: NonZeroU32) -> CtOption<Self> {
+        self.0.checked_root_vartime(k).map(NonZero)
+    }
+
+    /// Is `self` a perfect power, i.e. is `self = x^k` for some integers `x` and `k >= 2`?
+    ///
+    /// One is considered to be a perfect power.
+    pub const fn is_perfect_power_vartime(&self) -> bool {
+        self.0.is_perfect_power_vartime()
+    }
+}
+
+/// Returns the smallest exponent greater than `k` which perfect power tests need to check.
+///
+/// These are the odd primes from [`SMALL_PRIMES`], followed by every odd number beyond them:
+/// checking a composite exponent is redundant but harmless.
+pub(crate) const fn next_root_exponent(k: u32) -> u32 {
+    let mut i = 0;
+    while i < SMALL_PRIMES.len() {
+        if SMALL_PRIMES[i] as u32 > k {
+            return SMALL_PRIMES[i] as u32;
+        }
+        i += 1;
+    }
+    (k + 1) | 1
+}
+
+#[cfg(test)]
+mod tests {
+    use super::next_root_exponent;
+    use crate::{NonZero, U64, U128, U256, U2048};
+    use core::num::NonZeroU32;
+
+    const fn k(k: u32) -> NonZeroU32 {
+        NonZeroU32::new(k).unwrap()
+    }
+
+    #[test]
+    fn edge() {
+        for i in 1..10 {
+            assert_eq!(U256::ZERO.root(k(i)), U256::ZERO);
+            assert_eq!(U256::ZERO.root_vartime(k(i)), U256::ZERO);
+            assert_eq!(U256::ONE.root(k(i)), U256::ONE);
+            assert_eq!(U256::ONE.root_vartime(k(i)), U256::ONE);
+        }
+
+        assert_eq!(U256::MAX.root(k(1)), U256::MAX);
+        assert_eq!(U256::MAX.root_vartime(k(1)), U256::MAX);
+        assert_eq!(U256::MAX.root(k(2)), U256::MAX.sqrt());
+        assert_eq!(U256::MAX.root_vartime(k(2)), U256::MAX.sqrt());
+        assert_eq!(U256::MAX.root(k(256)), U256::ONE);
+        assert_eq!(U256::MAX.root_vartime(k(256)), U256::ONE);
+        assert_eq!(U256::MAX.root(k(1000)), U256::ONE);
+        assert_eq!(U256::MAX.root_vartime(k(1000)), U256::ONE);
+
+        // `floor((2^128 - 1)^(1/3)) = 6981463658331`
+        assert_eq!(U128::MAX.root(k(3)), U128::from_u64(6981463658331));
+        assert_eq!(U128::MAX.root_vartime(k(3)), U128::from_u64(6981463658331));
+    }
+
+    #[test]
+    fn cubes() {
+        for i in 0..200u64 {
+            let r = U64::from_u64(i);
+            let cube = U64::from_u64(i * i * i);
+            assert_eq!(cube.root(k(3)), r);
+            assert_eq!(cube.root_vartime(k(3)), r);
+            assert!(cube.checked_root(k(3)).is_some().to_bool());
+            assert!(cube.checked_root_vartime(k(3)).is_some().to_bool());
+
+            if i > 1 {
+                let below = cube.wrapping_sub(&U64::ONE);
+                assert_eq!(below.root(k(3)), r.wrapping_sub(&U64::ONE));
+                assert_eq!(below.root_vartime(k(3)), r.wrapping_sub(&U64::ONE));
+                assert!(!below.checked_root(k(3)).is_some().to_bool());
+                assert!(!below.checked_root_vartime(k(3)).is_some().to_bool());
+            }
+        }
+    }
+
+    #[test]
+    fn matches_sqrt() {
+        let x =
+            U256::from_be_hex("4bb750738e25a8f82940737d94a48a91f8cd918a3679ff90c1a631f2bd6c3597");
+        assert_eq!(x.root(k(2)), x.sqrt());
+        assert_eq!(x.root_vartime(k(2)), x.sqrt());
+    }
+
+    #[test]
+    fn perfect_powers() {
+        assert!(U256::ZERO.is_perfect_power_vartime());
+        assert!(U256::ONE.is_perfect_power_vartime());
+        assert!(!U256::from_u8(2).is_perfect_power_vartime());
+        assert!(U256::from_u8(4).is_perfect_power_vartime());
+        assert!(U256::from_u8(8).is_perfect_power_vartime());
+        assert!(!U256::from_u8(12).is_perfect_power_vartime());
+        assert!(U256::from_u8(243).is_perfect_power_vartime());
+        assert!(!U256::MAX.is_perfect_power_vartime());
+
+        // `3^101`
+        let (x, _) = U256::from_u8(3).overflowing_pow(101);
+        assert!(x.is_perfect_power_vartime());
+        assert!(!x.wrapping_add(&U256::ONE).is_perfect_power_vartime());
+
+        // Product of two 64-bit primes
+        let n = U128::from_be_hex("ffffffffffffff720000000000001321");
+        assert!(!n.is_perfect_power_vartime());
+        assert!(n.square().is_perfect_power_vartime());
+
+        // `3^1031`, with an exponent beyond the small primes
+        let (x, _) = U2048::from_u8(3).overflowing_pow(1031);
+        assert!(x.is_perfect_power_vartime());
+        assert!(!x.wrapping_add(&U2048::ONE).is_perfect_power_vartime());
+    }
+
+    #[test]
+    fn root_exponents() {
+        assert_eq!(next_root_exponent(2), 3);
+        assert_eq!(next_root_exponent(3), 5);
+        assert_eq!(next_root_exponent(24), 29);
+        assert_eq!(next_root_exponent(1019), 1021);
+        assert_eq!(next_root_exponent(1021), 1023);
+        assert_eq!(next_root_exponent(1023), 1025);
+    }
+
+    #[test]
+    fn non_zero() {
+        let x = NonZero::new(U256::from_u64(1 << 30)).unwrap();
+        assert_eq!(x.root(k(3)).get(), U256::from_u64(1 << 10));
+        assert_eq!(x.root_vartime(k(5)).get(), U256::from_u64(1 << 6));
+        assert!(x.checked_root(k(10)).is_some().to_bool());
+        assert!(!x.checked_root_vartime(k(7)).is_some().to_bool());
+        assert!(x.is_perfect_power_vartime());
+    }
+}
@@ -1,6 +1,9 @@
 //! [`Uint`] bitwise left shift operations.
 
-use crate::{Choice, CtOption, Limb, NonZero, Shl, ShlAssign, ShlVartime, Uint, WrappingShl};
+use crate::{
+    CheckedShl, Choice, CtOption, Limb, NonZero, SaturatingShl, Shl, ShlAssign, ShlVartime, Uint,
+    WrappingShl,
+};
 
 impl<const LIMBS: usize> Uint<LIMBS> {
     /// Computes `self << shift`.
@@ -150,6 +153,14 @@ impl<const LIMBS: usize> Uint<LIMBS> {
         ctutils::unwrap_or!(self.overflowing_shl(shift), Self::ZERO, Self::select)
     }
 
+    /// Computes `self << shift` in a panic-free manner, returning `MAX` if any non-zero bits
+    /// would be shifted out.
+    pub const fn saturating_shl(&self, shift: u32) -> Self {
+        let res = self.wrapping_shl(shift);
+        let overflow = Self::eq(&res.wrapping_shr(shift), self).not();
+        Self::select(&res, &Self::MAX, overflow)
+    }
+
     /// Computes `self << shift` in variable-time in a panic-free manner, returning zero if the
     /// shift exceeds the precision.
     pub const fn wrapping_shl_vartime(&self, shift: u32) -> Self {
@@ -255,6 +266,18 @@ macro_rules! impl_shl {
 
 impl_shl!(i32, u32, usize);
 
+impl<const LIMBS: usize> SaturatingShl for Uint<LIMBS> {
+    fn saturating_shl(&self, shift: u32) -> Uint<LIMBS> {
+        self.saturating_shl(shift)
+    }
+}
+
+impl<const LIMBS: usize> CheckedShl for Uint<LIMBS> {
+    fn checked_shl(&self, shift: u32) -> CtOption<Self> {
+        self.overflowing_shl(shift)
+    }
+}
+
 impl<const LIMBS: usize> WrappingShl for Uint<LIMBS> {
     fn wrapping_shl(&self, shift: u32) -> Uint<LIMBS> {
         self.wrapping_shl(shift)
@@ -376,4 +399,14 @@ mod tests {
         assert_eq!(val.wrapping_shl_by_limbs_vartime(1).as_words(), &[0, 1]);
         assert_eq!(val.wrapping_shl_by_limbs_vartime(2).as_words(), &[0, 0]);
     }
+
+    #[test]
+    fn saturating_shl() {
+        assert_eq!(U256::ONE.saturating_shl(1), U256::from_u8(2));
+        assert_eq!(U256::ONE.saturating_shl(255), U256::ONE.shl(255));
+        assert_eq!(U256::ONE.saturating_shl(256), U256::MAX);
+        assert_eq!(N.saturating_shl(1), U256::MAX);
+        assert_eq!(N.shr(1).saturating_shl(1), N.wrapping_sub(&U256::ONE));
+        assert_eq!(U256::ZERO.saturating_shl(300), U256::ZERO);
+    }
 }
@@ -1,6 +1,9 @@
 //! [`Uint`] bitwise right shift operations.
 
-use crate::{Choice, CtOption, Limb, NonZero, Shr, ShrAssign, ShrVartime, Uint, WrappingShr, word};
+use crate::{
+    CheckedShr, Choice, CtOption, Limb, NonZero, Shr, ShrAssign, ShrVartime, Uint, WrappingShr,
+    word,
+};
 
 impl<const LIMBS: usize> Uint<LIMBS> {
     /// Computes `self >> shift`.
@@ -275,6 +278,12 @@ macro_rules! impl_shr {
 
 impl_shr!(i32, u32, usize);
 
+impl<const LIMBS: usize> CheckedShr for Uint<LIMBS> {
+    fn checked_shr(&self, shift: u32) -> CtOption<Self> {
+        self.overflowing_shr(shift)
+    }
+}
+
 impl<const LIMBS: usize> WrappingShr for Uint<LIMBS> {
     fn wrapping_shr(&self, shift: u32) -> Uint<LIMBS> {
         self.wrapping_shr(shift)
@@ -1,7 +1,10 @@
 //! [`Uint`] subtraction operations.
 
 use super::Uint;
-use crate::{Checked, CheckedSub, CtOption, Limb, Sub, SubAssign, Wrapping, WrappingSub, word};
+use crate::{
+    Checked, CheckedSub, CtOption, Limb, Saturating, SaturatingSub, Sub, SubAssign, Wrapping,
+    WrappingSub, word,
+};
 
 impl<const LIMBS: usize> Uint<LIMBS> {
     /// Computes `self - (rhs + borrow)`, returning the result along with the new borrow.
@@ -46,6 +49,12 @@ impl<const LIMBS: usize> CheckedSub for Uint<LIMBS> {
     }
 }
 
+impl<const LIMBS: usize> SaturatingSub for Uint<LIMBS> {
+    fn saturating_sub(&self, rhs: &Self) -> Self {
+        self.saturating_sub(rhs)
+    }
+}
+
 impl<const LIMBS: usize> Sub for Uint<LIMBS> {
     type Output = Self;
 
@@ -99,6 +108,18 @@ impl<const LIMBS: usize> SubAssign<&Checked<Uint<LIMBS>>> for Checked<Uint<LIMBS
     }
 }
 
+impl<const LIMBS: usize> SubAssign for Saturating<Uint<LIMBS>> {
+    fn sub_assign(&mut self, other: Self) {
+        *self = *self - other;
+    }
+}
+
+impl<const LIMBS: usize> SubAssign<&Saturating<Uint<LIMBS>>> for Saturating<Uint<LIMBS>> {
+    fn sub_assign(&mut self, other: &Self) {
+        *self = *self - other;
+    }
+}
+
 impl<const LIMBS: usize> WrappingSub for Uint<LIMBS> {
     fn wrapping_sub(&self, v: &Self) -> Self {
         self.wrapping_sub(v)
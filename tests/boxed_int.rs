@@ -0,0 +1,192 @@
+//! Equivalence tests between `crypto_bigint::BoxedInt` and `num_bigint::BigInt`.
+
+#![cfg(feature = "alloc")]
+
+mod common;
+
+use common::to_bigint;
+use crypto_bigint::{BoxedInt, Limb, Resize};
+use num_bigint::BigInt;
+use num_integer::Integer as _;
+use proptest::prelude::*;
+
+/// Smallest and largest values representable by a [`BoxedInt`] with the given precision.
+fn bounds(bits_precision: u32) -> (BigInt, BigInt) {
+    (
+        to_bigint(&BoxedInt::min(bits_precision)),
+        to_bigint(&BoxedInt::max(bits_precision)),
+    )
+}
+
+prop_compose! {
+    /// Generate a random `BoxedInt`.
+    fn int()(mut bytes in any::<Vec<u8>>()) -> BoxedInt {
+        let extra = bytes.len() % Limb::BYTES;
+        let bytes_precision = bytes.len() - extra;
+        bytes.truncate(bytes_precision);
+        BoxedInt::from_be_slice(&bytes, bytes_precision as u32 * 8).unwrap()
+    }
+}
+prop_compose! {
+    /// Generate a pair of random `BoxedInt`s with the same precision.
+    fn int_pair()(a in int(), b in int()) -> (BoxedInt, BoxedInt) {
+        let bits_precision = core::cmp::max(a.bits_precision(), b.bits_precision());
+        (a.resize(bits_precision), b.resize(bits_precision))
+    }
+}
+
+proptest! {
+    #[test]
+    fn resize(a in int(), extra_limbs in 0u32..4) {
+        let bits_precision = a.bits_precision() + extra_limbs * Limb::BITS;
+        let resized = (&a).resize(bits_precision);
+        prop_assert_eq!(to_bigint(&a), to_bigint(&resized));
+        prop_assert_eq!(resized.resize(a.bits_precision()), a);
+    }
+
+    #[test]
+    fn checked_add(a in int(), b in int()) {
+        let expected = to_bigint(&a) + to_bigint(&b);
+        let bits_precision = core::cmp::max(a.bits_precision(), b.bits_precision());
+        let (min, max) = bounds(bits_precision);
+
+        match Option::<BoxedInt>::from(a.checked_add(&b)) {
+            Some(actual) => prop_assert_eq!(expected, to_bigint(&actual)),
+            None => prop_assert!(expected < min || expected > max),
+        }
+    }
+
+    #[test]
+    fn checked_sub(a in int(), b in int()) {
+        let expected = to_bigint(&a) - to_bigint(&b);
+        let bits_precision = core::cmp::max(a.bits_precision(), b.bits_precision());
+        let (min, max) = bounds(bits_precision);
+
+        match Option::<BoxedInt>::from(a.checked_sub(&b)) {
+            Some(actual) => prop_assert_eq!(expected, to_bigint(&actual)),
+            None => prop_assert!(expected < min || expected > max),
+        }
+    }
+
+    #[test]
+    fn checked_mul((a, b) in int_pair()) {
+        let expected = to_bigint(&a) * to_bigint(&b);
+        let (min, max) = bounds(a.bits_precision());
+
+        match Option::<BoxedInt>::from(a.checked_mul(&b)) {
+            Some(actual) => prop_assert_eq!(expected, to_bigint(&actual)),
+            None => prop_assert!(expected < min || expected > max),
+        }
+    }
+
+    #[test]
+    fn concatenating_mul(a in int(), b in int()) {
+        let expected = to_bigint(&a) * to_bigint(&b);
+        prop_assert_eq!(expected, to_bigint(&a.concatenating_mul(&b)));
+    }
+
+    #[test]
+    fn checked_div_rem(a in int(), b in int()) {
+        let a_bi = to_bigint(&a);
+        let b_bi = to_bigint(&b);
+
+        if let Some(b) = Option::from(b.to_nz()) {
+            // `BigInt` division truncates towards zero, as does `BoxedInt`.
+            let expected_quo = &a_bi / &b_bi;
+            let expected_rem = &a_bi % &b_bi;
+            let (min, max) = bounds(a.bits_precision());
+
+            let (quo, rem) = a.checked_div_rem(&b);
+            match Option::<BoxedInt>::from(quo) {
+                Some(quo) => prop_assert_eq!(&expected_quo, &to_bigint(&quo)),
+                None => prop_assert!(expected_quo < min || expected_quo > max),
+            }
+            prop_assert_eq!(&expected_rem, &to_bigint(&rem));
+
+            let (quo, rem) = a.checked_div_rem_vartime(&b);
+            if let Some(quo) = Option::<BoxedInt>::from(quo) {
+                prop_assert_eq!(&expected_quo, &to_bigint(&quo));
+            }
+            prop_assert_eq!(&expected_rem, &to_bigint(&rem));
+        }
+    }
+
+    #[test]
+    fn checked_div_rem_floor(a in int(), b in int()) {
+        let a_bi = to_bigint(&a);
+        let b_bi = to_bigint(&b);
+
+        if let Some(b) = Option::from(b.to_nz()) {
+            let (expected_quo, expected_rem) = a_bi.div_mod_floor(&b_bi);
+            let (min, max) = bounds(a.bits_precision());
+
+            let (quo, rem) = a.checked_div_rem_floor(&b);
+            match Option::<BoxedInt>::from(quo) {
+                Some(quo) => prop_assert_eq!(&expected_quo, &to_bigint(&quo)),
+                None => prop_assert!(expected_quo < min || expected_quo > max),
+            }
+            prop_assert_eq!(expected_rem, to_bigint(&rem));
+        }
+    }
+
+    #[test]
+    fn shl(a in int(), shift in any::<u16>()) {
+        let shift = u32::from(shift) % (a.bits_precision() * 2);
+        let actual = a.wrapping_shl(shift);
+
+        if shift < a.bits_precision() {
+            // Truncate the shifted value to the precision of `a`, then sign-extend again.
+            let modulus = BigInt::from(1) << a.bits_precision();
+            let mut expected = (to_bigint(&a) << shift).mod_floor(&modulus);
+            if expected > bounds(a.bits_precision()).1 {
+                expected -= modulus;
+            }
+            prop_assert_eq!(expected, to_bigint(&actual));
+        } else {
+            prop_assert!(bool::from(actual.is_zero()));
+        }
+    }
+
+    #[test]
+    fn shr(a in int(), shift in any::<u16>()) {
+        let shift = u32::from(shift) % (a.bits_precision() * 2);
+        let expected = to_bigint(&a) >> shift;
+
+        prop_assert_eq!(&expected, &to_bigint(&a.wrapping_shr(shift)));
+        prop_assert_eq!(&expected, &to_bigint(&a.wrapping_shr_vartime(shift)));
+    }
+
+    #[test]
+    fn cmp(a in int(), b in int()) {
+        let expected = to_bigint(&a).cmp(&to_bigint(&b));
+        prop_assert_eq!(expected, a.cmp(&b));
+        prop_assert_eq!(expected, a.cmp_vartime(&b));
+    }
+
+    #[test]
+    fn abs_sign(a in int()) {
+        let a_bi = to_bigint(&a);
+        let (abs, sign) = a.abs_sign();
+        prop_assert_eq!(a_bi.magnitude(), &common::to_biguint(&abs));
+        prop_assert_eq!(bool::from(sign), a_bi.sign() == num_bigint::Sign::Minus);
+    }
+
+    #[test]
+    fn bitops((a, b) in int_pair()) {
+        let a_bi = to_bigint(&a);
+        let b_bi = to_bigint(&b);
+        prop_assert_eq!(&a_bi & &b_bi, to_bigint(&(&a & &b)));
+        prop_assert_eq!(&a_bi | &b_bi, to_bigint(&(&a | &b)));
+        prop_assert_eq!(&a_bi ^ &b_bi, to_bigint(&(&a ^ &b)));
+        prop_assert_eq!(!a_bi, to_bigint(&!a));
+    }
+
+    #[test]
+    fn encoding_roundtrip(a in int()) {
+        let be = a.to_be_bytes();
+        let le = a.to_le_bytes();
+        prop_assert_eq!(&BoxedInt::from_be_slice(&be, a.bits_precision()).unwrap(), &a);
+        prop_assert_eq!(&BoxedInt::from_le_slice(&le, a.bits_precision()).unwrap(), &a);
+        prop_assert_eq!(BigInt::from_signed_bytes_be(&be), to_bigint(&a));
+    }
+}
@@ -6,8 +6,8 @@ mod common;
 
 use common::to_biguint;
 use crypto_bigint::{
-    BoxedUint, Integer, Limb, Odd,
-    modular::{BoxedMontyForm, BoxedMontyParams},
+    BoxedInt, BoxedUint, Choice, Integer, Limb, MultiExponentiate, Odd, Resize,
+    modular::{BoxedMontyForm, BoxedMontyParams, PowerModulus},
 };
 use num_bigint::BigUint;
 use num_integer::Integer as _;
@@ -176,6 +176,110 @@ proptest! {
         prop_assert_eq!(retrieve_biguint(&actual), expected);
     }
 
+    #[test]
+    fn multi_exponentiate_vartime(
+        pairs in prop::collection::vec((uint(), uint()), 1..40),
+        n in modulus(),
+    ) {
+        let bases_and_exponents: Vec<(BoxedMontyForm, BoxedUint)> =
+            pairs.iter().map(|(a, b)| (reduce(a, n.clone()), b.clone())).collect();
+
+        let n_bi = to_biguint(n.modulus());
+        let expected = bases_and_exponents.iter().fold(BigUint::from(1u8), |acc, (a, b)| {
+            acc * retrieve_biguint(a).modpow(&to_biguint(b), &n_bi) % &n_bi
+        });
+        let actual = BoxedMontyForm::multi_exponentiate_vartime(bases_and_exponents.as_slice());
+        prop_assert_eq!(retrieve_biguint(&actual), &expected % &n_bi);
+        prop_assert_eq!(actual, BoxedMontyForm::multi_exponentiate(bases_and_exponents.as_slice()));
+    }
+
+    #[test]
+    fn pow_signed(a in uint(), b in uint(), negate in any::<bool>(), n in modulus()) {
+        let a = reduce(&a, n.clone());
+        let b = BoxedInt::from(b).wrapping_neg_if(Choice::from_u8_lsb(negate as u8));
+        let actual = a.pow_signed(&b);
+
+        let a_bi = retrieve_biguint(&a);
+        let n_bi = to_biguint(n.modulus());
+        let (b_abs, b_is_negative) = b.abs_sign();
+        let base = if b_is_negative.to_bool() { a_bi.invm(&n_bi) } else { Some(a_bi) };
+
+        match (base, actual.into_option()) {
+            (Some(base), Some(actual)) => {
+                let expected = base.modpow(&to_biguint(&b_abs), &n_bi);
+                prop_assert_eq!(retrieve_biguint(&actual), expected);
+            }
+            (None, None) => (),
+            // we disagree on whether the inverse of zero exists modulo 1
+            (Some(_), None) if a.is_zero().into() => (),
+            _ => panic!("disagreement on if the power exists"),
+        }
+    }
+
+    #[test]
+    fn batch_invert((a, b) in monty_form_pair()) {
+        let params = a.params().clone();
+        let zero = BoxedMontyForm::zero(params.clone());
+        let original = [a.clone(), zero, b.clone(), &a * &b];
+        let mut values = original.clone();
+        let mask = BoxedMontyForm::batch_invert(&mut values);
+
+        // Either all of the non-zero values are inverted, or none of them are
+        let is_invertible = original
+            .iter()
+            .all(|x| bool::from(x.is_zero() | x.invert().is_some()));
+        for (i, x) in original.iter().enumerate() {
+            let is_inverted = !bool::from(x.is_zero()) && is_invertible;
+            prop_assert_eq!(bool::from(mask[i]), is_inverted);
+            if is_inverted {
+                prop_assert_eq!(&values[i] * x, BoxedMontyForm::one(params.clone()));
+            } else {
+                prop_assert_eq!(&values[i], x);
+            }
+        }
+    }
+
+    #[test]
+    fn power_modulus(a in uint(), b in uint(), n in modulus()) {
+        let n_bi = to_biguint(n.modulus());
+        let n2_bi = &n_bi * &n_bi;
+        let pm = PowerModulus::new(n.clone(), 2);
+
+        // `x = a + b * n mod n^2`
+        let a = pm.lift(&reduce(&a, n.clone()));
+        let b = pm.lift(&reduce(&b, n.clone()));
+        let n2 = pm.params(2);
+        let n_lifted = BoxedMontyForm::new(
+            n.modulus().resize(n2.bits_precision()).get(),
+            n2.clone(),
+        );
+        let x = &a + &(&b * &n_lifted);
+        let x_bi = retrieve_biguint(&x);
+        prop_assert!(x_bi < n2_bi);
+        prop_assert_eq!(retrieve_biguint(&pm.reduce(&x)), &x_bi % &n_bi);
+
+        let inverse = pm.reduce(&x).invert();
+        if bool::from(inverse.is_some()) {
+            let lifted = pm.lift_inverse(&x, &inverse.unwrap()).unwrap();
+            prop_assert_eq!(Some(retrieve_biguint(&lifted)), x_bi.invm(&n2_bi));
+        }
+    }
+
+    #[test]
+    fn pow_vartime(a in uint(), b in uint(), shift in 0u32..256, n in modulus()) {
+        // Exercise the different window sizes with exponents of any length
+        let b = b.wrapping_shr(shift);
+        let a = reduce(&a, n.clone());
+        let actual = a.pow_vartime(&b);
+
+        let a_bi = retrieve_biguint(&a);
+        let b_bi = to_biguint(&b);
+        let n_bi = to_biguint(n.modulus());
+        let expected = a_bi.modpow(&b_bi, &n_bi);
+
+        prop_assert_eq!(retrieve_biguint(&actual), expected);
+    }
+
     #[test]
     fn div_by_2(a in monty_form()) {
         let actual = a.div_by_2();
@@ -6,7 +6,8 @@ mod common;
 
 use common::to_biguint;
 use crypto_bigint::{
-    BitOps, BoxedUint, CheckedAdd, Choice, Gcd, Integer, Limb, NonZero, Odd, Resize,
+    BitOps, BoxedUint, BoxedUintReciprocal, CheckedAdd, Choice, Gcd, Integer, Limb, NonZero, Odd,
+    Resize, modular::BoxedBarrettParams,
 };
 use num_bigint::BigUint;
 use num_integer::Integer as _;
@@ -111,6 +112,24 @@ proptest! {
         prop_assert_eq!(expected_remainder, to_biguint(&actual_remainder));
     }
 
+    #[test]
+    fn div_rem_with_reciprocal(a in uint(), mut b in uint()) {
+        if b.is_zero().into() {
+            b = b.wrapping_add(&BoxedUint::one());
+        }
+
+        let a_bi = to_biguint(&a);
+        let b_bi = to_biguint(&b);
+        let expected_quotient = &a_bi / &b_bi;
+        let expected_remainder = a_bi % b_bi;
+
+        let reciprocal = BoxedUintReciprocal::new(&NonZero::new(b).unwrap());
+        let (actual_quotient, actual_remainder) = a.div_rem_with_reciprocal(&reciprocal);
+        prop_assert_eq!(expected_quotient, to_biguint(&actual_quotient));
+        prop_assert_eq!(&expected_remainder, &to_biguint(&actual_remainder));
+        prop_assert_eq!(expected_remainder, to_biguint(&a.rem_with_reciprocal(&reciprocal)));
+    }
+
     #[test]
     fn div_rem_vartime((a, mut b) in uint_pair()) {
         if b.is_zero().into() {
@@ -197,6 +216,23 @@ proptest! {
         prop_assert_eq!(expected, actual);
     }
 
+    #[test]
+    fn barrett_mul_mod((a, n) in uint_pair()) {
+        let Some(n) = NonZero::new(n).into_option() else {
+            return Ok(());
+        };
+        let params = BoxedBarrettParams::new(n.clone());
+        let b = a.not();
+
+        let a_bi = to_biguint(&a);
+        let b_bi = to_biguint(&b);
+        let n_bi = to_biguint(n.as_ref());
+
+        prop_assert_eq!(to_biguint(&params.reduce(&a)), &a_bi % &n_bi);
+        prop_assert_eq!(to_biguint(&params.mul_mod(&a, &b)), (&a_bi * &b_bi) % &n_bi);
+        prop_assert_eq!(to_biguint(&params.square_mod(&a)), (&a_bi * &a_bi) % &n_bi);
+    }
+
     #[test]
     fn widening_mul(a in uint(), b in uint()) {
         let a_bi = to_biguint(&a);
@@ -241,6 +277,59 @@ proptest! {
         prop_assert_eq!(expected, to_biguint(&actual));
     }
 
+    #[test]
+    fn checked_mul((a, b) in uint_pair()) {
+        let expected = to_biguint(&a) * to_biguint(&b);
+        let fits = expected.bits() <= u64::from(a.bits_precision());
+        let actual = a.checked_mul(&b);
+
+        prop_assert_eq!(fits, actual.is_some().to_bool());
+        if fits {
+            prop_assert_eq!(expected, to_biguint(&actual.unwrap()));
+        }
+    }
+
+    #[test]
+    fn root(a in uint(), shift in 0u32..256, k in 1u32..300) {
+        // Shift `a` down so that small values, which have non-trivial roots for large `k`, are
+        // tested too
+        let a = a.wrapping_shr_vartime(shift);
+        let a_bi = to_biguint(&a);
+        let k_nz = core::num::NonZeroU32::new(k).unwrap();
+        let expected = a_bi.nth_root(k);
+        let is_exact = expected.pow(k) == a_bi;
+
+        prop_assert_eq!(&expected, &to_biguint(&a.root(k_nz)));
+        prop_assert_eq!(&expected, &to_biguint(&a.root_vartime(k_nz)));
+        prop_assert_eq!(is_exact, a.checked_root(k_nz).is_some().to_bool());
+        prop_assert_eq!(is_exact, a.checked_root_vartime(k_nz).is_some().to_bool());
+    }
+
+    #[test]
+    fn ilog(a in uint(), shift in 0u32..256, b in uint(), base_shift in 0u32..256) {
+        let a = a.wrapping_shr_vartime(shift);
+        let base = b.wrapping_shr_vartime(base_shift);
+        let a_bi = to_biguint(&a);
+        let base_bi = to_biguint(&base);
+
+        if a_bi.bits() == 0 || base_bi < BigUint::from(2u8) {
+            prop_assert!(a.checked_ilog(&base).is_none().to_bool());
+            prop_assert!(a.checked_ilog_vartime(&base).is_none().to_bool());
+        } else {
+            let mut expected = 0;
+            let mut power = base_bi.clone();
+            while power <= a_bi {
+                expected += 1;
+                power *= &base_bi;
+            }
+
+            prop_assert_eq!(expected, a.ilog(&base));
+            prop_assert_eq!(expected, a.ilog_vartime(&base));
+            prop_assert_eq!(a_bi.bits() as u32 - 1, a.ilog2());
+            prop_assert_eq!(a_bi.to_string().len() as u32 - 1, a.ilog10());
+        }
+    }
+
     #[test]
     fn rem((a, b) in uint_pair()) {
         if bool::from(!b.is_zero()) {
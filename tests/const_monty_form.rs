@@ -3,7 +3,10 @@
 mod common;
 
 use common::to_biguint;
-use crypto_bigint::{U256, const_monty_params, modular::ConstMontyParams};
+use crypto_bigint::{
+    U256, const_monty_params, const_special_form_params,
+    modular::{ConstMontyParams, SpecialFormElement},
+};
 use num_bigint::BigUint;
 use num_modular::ModularUnaryOps;
 use proptest::prelude::*;
@@ -16,10 +19,32 @@ const_monty_params!(
 
 type ConstMontyForm = crypto_bigint::modular::ConstMontyForm<Modulus, { U256::LIMBS }>;
 
+// 2^255 - 19, both as a Montgomery modulus and in its special form
+const_monty_params!(
+    P25519MontyModulus,
+    U256,
+    "7fffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffed"
+);
+const_special_form_params!(P25519Modulus, U256, 255, 19);
+
+type P25519Monty = crypto_bigint::modular::ConstMontyForm<P25519MontyModulus, { U256::LIMBS }>;
+type P25519 = SpecialFormElement<P25519Modulus, { U256::LIMBS }>;
+
+// secp256k1 base field modulus, 2^256 - 2^32 - 977
+#[cfg(target_pointer_width = "64")]
+const_special_form_params!(Secp256k1Modulus, U256, 256, 0x1000003d1);
+
+#[cfg(target_pointer_width = "64")]
+type Secp256k1 = SpecialFormElement<Secp256k1Modulus, { U256::LIMBS }>;
+
 fn retrieve_biguint(monty_form: &ConstMontyForm) -> BigUint {
     to_biguint(&monty_form.retrieve())
 }
 
+fn retrieve_p25519(x: &P25519) -> BigUint {
+    to_biguint(&x.retrieve())
+}
+
 fn reduce(n: &U256) -> ConstMontyForm {
     ConstMontyForm::new(n)
 }
@@ -50,4 +75,55 @@ proptest! {
             (_, _) => panic!("disagreement on if modular inverse exists")
         }
     }
+
+    #[test]
+    fn special_form_matches_montgomery(a in uint(), b in uint(), e in uint()) {
+        let (a_mont, b_mont) = (P25519Monty::new(&a), P25519Monty::new(&b));
+        let (a_spec, b_spec) = (P25519::new(&a), P25519::new(&b));
+
+        prop_assert_eq!(a_spec.retrieve(), a_mont.retrieve());
+        prop_assert_eq!((a_spec * b_spec).retrieve(), (a_mont * b_mont).retrieve());
+        prop_assert_eq!(a_spec.square().retrieve(), a_mont.square().retrieve());
+        prop_assert_eq!((a_spec - b_spec).retrieve(), (a_mont - b_mont).retrieve());
+        prop_assert_eq!(a_spec.pow(&e).retrieve(), a_mont.pow(&e).retrieve());
+        prop_assert_eq!(a_spec.pow_vartime(&e).retrieve(), a_mont.pow(&e).retrieve());
+        prop_assert_eq!(a_mont.pow_vartime(&e), a_mont.pow(&e));
+        prop_assert_eq!(a_spec.invert_vartime().into_option(), a_spec.invert().into_option());
+        prop_assert_eq!(
+            a_spec.invert().map(|x| x.retrieve()).into_option(),
+            a_mont.invert().map(|x| x.retrieve()).into_option()
+        );
+        prop_assert_eq!(
+            P25519::lincomb(&[(a_spec, b_spec), (b_spec, b_spec)]).retrieve(),
+            P25519Monty::lincomb(&[(a_mont, b_mont), (b_mont, b_mont)]).retrieve()
+        );
+    }
+
+    #[test]
+    fn special_form_mul(a in uint(), b in uint()) {
+        let p_bi = to_biguint(&P25519::MODULUS);
+        let a_bi = to_biguint(&a);
+        let b_bi = to_biguint(&b);
+
+        let (a, b) = (P25519::new(&a), P25519::new(&b));
+        prop_assert_eq!(retrieve_p25519(&a), &a_bi % &p_bi);
+        prop_assert_eq!(retrieve_p25519(&(a * b)), (&a_bi * &b_bi) % &p_bi);
+        prop_assert_eq!(
+            retrieve_p25519(&(a * b).invert().unwrap()),
+            ((a_bi * b_bi) % &p_bi).invm(&p_bi).unwrap()
+        );
+    }
+
+    #[cfg(target_pointer_width = "64")]
+    #[test]
+    fn special_form_mul_full_width(a in uint(), b in uint()) {
+        let p_bi = to_biguint(&Secp256k1::MODULUS);
+        let a_bi = to_biguint(&a);
+        let b_bi = to_biguint(&b);
+
+        let (a, b) = (Secp256k1::new(&a), Secp256k1::new(&b));
+        prop_assert_eq!(to_biguint(&a.retrieve()), &a_bi % &p_bi);
+        prop_assert_eq!(to_biguint(&(a * b).retrieve()), (&a_bi * &b_bi) % &p_bi);
+        prop_assert_eq!(to_biguint(&a.square().retrieve()), (&a_bi * &a_bi) % &p_bi);
+    }
 }
@@ -41,6 +41,20 @@ prop_compose! {
         MontyParams256::new_vartime(Odd::new(n).expect("modulus ensured odd"))
     }
 }
+prop_compose! {
+    /// Pick a prime modulus covering each of the code paths used by `sqrt`.
+    fn prime_modulus()(i in 0usize..3) -> MontyParams256 {
+        let p = [
+            // P-256: p ≡ 3 (mod 4)
+            "ffffffff00000001000000000000000000000000ffffffffffffffffffffffff",
+            // Curve25519: p ≡ 5 (mod 8)
+            "7fffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffed",
+            // BLS12-381 scalar field: p ≡ 1 (mod 8) with 2-adicity 32
+            "73eda753299d7d483339d80809a1d80553bda402fffe5bfeffffffff00000001",
+        ][i];
+        MontyParams256::new_vartime(Odd::<U256>::from_be_hex(p))
+    }
+}
 // Generates a random `T` and returns it as a tuple in: normal form, montgomery form,
 // inverted montgomery form and the normal form inverse from the num_modular crate.
 fn random_invertible_uint<T>(
@@ -397,6 +411,108 @@ proptest! {
         prop_assert_eq!(retrieve_biguint(&actual), expected);
     }
 
+    #[cfg(feature = "alloc")]
+    #[test]
+    fn multi_exponentiate_vartime(
+        pairs in prop::collection::vec((uint(), uint()), 1..40),
+        exponent_bits in 0u32..=256,
+        n in modulus(),
+    ) {
+        use crypto_bigint::{MultiExponentiate, MultiExponentiateBoundedExp};
+
+        let bases_and_exponents: Vec<(MontyForm256, U256)> =
+            pairs.iter().map(|(x, y)| (reduce(x, n), *y)).collect();
+
+        let n_bi = to_biguint(n.modulus());
+        let expected = bases_and_exponents.iter().fold(BigUint::from(1u8), |acc, (x, y)| {
+            acc * retrieve_biguint(x).modpow(&to_biguint(y), &n_bi) % &n_bi
+        });
+        let actual = MontyForm256::multi_exponentiate_vartime(bases_and_exponents.as_slice());
+        prop_assert_eq!(retrieve_biguint(&actual), expected);
+        prop_assert_eq!(actual, MontyForm256::multi_exponentiate(bases_and_exponents.as_slice()));
+
+        let bounded = MontyForm256::multi_exponentiate_bounded_exp_vartime(
+            bases_and_exponents.as_slice(),
+            exponent_bits,
+        );
+        prop_assert_eq!(
+            bounded,
+            MontyForm256::multi_exponentiate_bounded_exp(bases_and_exponents.as_slice(), exponent_bits)
+        );
+
+        let array = [bases_and_exponents[0], bases_and_exponents[bases_and_exponents.len() - 1]];
+        prop_assert_eq!(
+            MontyForm256::multi_exponentiate_vartime(&array),
+            MontyForm256::multi_exponentiate(&array)
+        );
+    }
+
+    #[test]
+    fn pow_signed(x in uint(), y in uint(), n in modulus()) {
+        let x = reduce(&x, n);
+        let y = y.as_int();
+        let actual = x.pow_signed(y);
+
+        let x_bi = retrieve_biguint(&x);
+        let n_bi = to_biguint(n.modulus());
+        let (y_abs, y_is_negative) = y.abs_sign();
+        let base = if y_is_negative.to_bool() { x_bi.invm(&n_bi) } else { Some(x_bi) };
+
+        match (base, actual.into_option()) {
+            (Some(base), Some(actual)) => {
+                let expected = base.modpow(&to_biguint(&y_abs), &n_bi);
+                prop_assert_eq!(retrieve_biguint(&actual), expected);
+            }
+            (None, None) => (),
+            // we disagree on whether the inverse of zero exists modulo 1
+            (Some(_), None) if x.retrieve() == U256::ZERO => (),
+            _ => panic!("disagreement on if the power exists"),
+        }
+    }
+
+    #[cfg(feature = "alloc")]
+    #[test]
+    fn batch_invert(xs in prop::collection::vec(uint(), 0..8), n in modulus()) {
+        let mut values: Vec<MontyForm256> = xs.iter().map(|x| reduce(x, n)).collect();
+        values.insert(values.len() / 2, MontyForm256::zero(n));
+        let original = values.clone();
+        let mask = MontyForm256::batch_invert(&mut values);
+
+        // Either all of the non-zero values are inverted, or none of them are
+        let n_bi = to_biguint(n.modulus());
+        let inverses: Option<Vec<_>> = original
+            .iter()
+            .filter(|x| !bool::from(x.as_montgomery().is_zero()))
+            .map(|x| retrieve_biguint(x).invm(&n_bi))
+            .collect();
+
+        for (i, x) in original.iter().enumerate() {
+            let is_zero = bool::from(x.as_montgomery().is_zero());
+            let is_inverted = !is_zero && inverses.is_some();
+            prop_assert_eq!(bool::from(mask[i]), is_inverted);
+            if is_inverted {
+                prop_assert_eq!(values[i] * x, MontyForm256::one(n));
+            } else {
+                prop_assert_eq!(&values[i], x);
+            }
+        }
+    }
+
+    #[test]
+    fn pow_vartime(x in uint(), y in uint(), shift in 0u32..256, n in modulus()) {
+        // Exercise the different window sizes with exponents of any length
+        let y = y.wrapping_shr_vartime(shift);
+        let x = reduce(&x, n);
+        let actual = x.pow_vartime(&y);
+
+        let x_bi = retrieve_biguint(&x);
+        let y_bi = to_biguint(&y);
+        let n_bi = to_biguint(n.modulus());
+        let expected = x_bi.modpow(&y_bi, &n_bi);
+
+        prop_assert_eq!(retrieve_biguint(&actual), expected);
+    }
+
     #[test]
     fn div_by_2(x in uint(), n in modulus()) {
         let x = reduce(&x, n);
@@ -413,4 +529,41 @@ proptest! {
 
         prop_assert_eq!(&retrieve_biguint(&actual), &expected);
     }
+
+    #[test]
+    fn sqrt(x in uint(), n in prime_modulus()) {
+        let x = reduce(&x, n);
+        let actual = Option::<MontyForm256>::from(x.sqrt());
+
+        // Euler's criterion: x is a square if and only if x^((p - 1) / 2) ≠ -1
+        let x_bi = retrieve_biguint(&x);
+        let n_bi = to_biguint(n.modulus());
+        let is_square = x_bi.modpow(&(&n_bi >> 1), &n_bi) != &n_bi - 1u8;
+
+        match actual {
+            Some(root) => {
+                prop_assert!(is_square);
+                prop_assert_eq!(root.square(), x);
+            }
+            None => prop_assert!(!is_square),
+        }
+    }
+
+    #[cfg(feature = "alloc")]
+    #[test]
+    fn fixed_base_pow(
+        b in uint(),
+        e in uint(),
+        n in modulus(),
+        window in 1u32..8,
+        tables in 1u32..8
+    ) {
+        use crypto_bigint::modular::FixedBaseTable;
+
+        let b = reduce(&b, n);
+        let table = FixedBaseTable::with_tables(&b, 256, window, tables);
+
+        let expected = retrieve_biguint(&b).modpow(&to_biguint(&e), &to_biguint(n.modulus()));
+        prop_assert_eq!(retrieve_biguint(&table.pow(&e)), expected);
+    }
 }
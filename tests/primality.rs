@@ -0,0 +1,57 @@
+//! Tests for the `crypto_bigint::primality` module.
+
+use crypto_bigint::{U64, U128, U256, primality::is_prime_vartime};
+use proptest::prelude::*;
+
+#[cfg(feature = "rand_core")]
+use {
+    chacha20::ChaCha8Rng,
+    crypto_bigint::primality::{generate_prime, generate_safe_prime},
+    rand_core::SeedableRng,
+};
+
+/// Reference primality check by trial division.
+fn is_prime_reference(n: u32) -> bool {
+    n >= 2
+        && (2..)
+            .take_while(|d: &u64| d * d <= u64::from(n))
+            .all(|d| u64::from(n) % d != 0)
+}
+
+proptest! {
+    #[test]
+    fn matches_trial_division(n in any::<u32>()) {
+        let expected = is_prime_reference(n);
+        prop_assert_eq!(is_prime_vartime(&U64::from_u32(n)), expected);
+        prop_assert_eq!(is_prime_vartime(&U256::from_u32(n)), expected);
+
+        #[cfg(feature = "alloc")]
+        prop_assert_eq!(is_prime_vartime(&crypto_bigint::BoxedUint::from(n)), expected);
+    }
+
+    #[test]
+    fn products_are_composite(a in 2..u64::MAX, b in 2..u64::MAX) {
+        let n = U64::from_u64(a).widening_mul(&U64::from_u64(b));
+        let n: U128 = n.into();
+        prop_assert!(!is_prime_vartime(&n));
+    }
+
+    #[cfg(feature = "rand_core")]
+    #[test]
+    fn generated_primes(seed in any::<[u8; 32]>(), bit_length in 2u32..=128) {
+        let mut rng = ChaCha8Rng::from_seed(seed);
+        let p: U128 = generate_prime(&mut rng, bit_length);
+        prop_assert_eq!(p.bits(), bit_length);
+        prop_assert!(is_prime_vartime(&p));
+    }
+
+    #[cfg(feature = "rand_core")]
+    #[test]
+    fn generated_safe_primes(seed in any::<[u8; 32]>(), bit_length in 3u32..=64) {
+        let mut rng = ChaCha8Rng::from_seed(seed);
+        let p: U64 = generate_safe_prime(&mut rng, bit_length);
+        prop_assert_eq!(p.bits(), bit_length);
+        prop_assert!(is_prime_vartime(&p));
+        prop_assert!(is_prime_vartime(&p.shr_vartime(1)));
+    }
+}
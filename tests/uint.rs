@@ -4,8 +4,8 @@ mod common;
 
 use common::to_biguint;
 use crypto_bigint::{
-    Encoding, Gcd, Limb, NonZero, Odd, U256, U512, U4096, U8192, Uint, Word,
-    modular::{MontyForm, MontyParams},
+    Encoding, Gcd, Limb, NonZero, Odd, U256, U512, U4096, U8192, Uint, UintReciprocal, Word,
+    modular::{BarrettParams, MontyForm, MontyParams},
 };
 use num_bigint::BigUint;
 use num_integer::Integer as _;
@@ -237,6 +237,25 @@ proptest! {
         prop_assert_eq!(expected, actual_vartime);
     }
 
+    #[test]
+    fn barrett_mul_mod(a in uint(), b in uint(), m in uint(), shift in 0u32..256) {
+        let m = m.wrapping_shr_vartime(shift);
+        prop_assume!(m != U256::ZERO);
+        let params = BarrettParams::new(NonZero::new(m).unwrap());
+
+        let a_bi = to_biguint(&a);
+        let b_bi = to_biguint(&b);
+        let m_bi = to_biguint(&m);
+
+        prop_assert_eq!(to_uint(&a_bi % &m_bi), params.reduce(&a));
+        prop_assert_eq!(to_uint((&a_bi * &b_bi) % &m_bi), params.mul_mod(&a, &b));
+        prop_assert_eq!(to_uint((&a_bi * &a_bi) % &m_bi), params.square_mod(&a));
+        prop_assert_eq!(
+            to_uint((&a_bi + (&b_bi << 256)) % &m_bi),
+            params.reduce_wide((a, b))
+        );
+    }
+
     #[test]
     fn wrapping_sub(mut a in uint(), mut b in uint()) {
         if b > a {
@@ -341,6 +360,21 @@ proptest! {
         }
     }
 
+    #[test]
+    fn div_rem_with_reciprocal(a in uint(), b in uint()) {
+        let a_bi = to_biguint(&a);
+        let b_bi = to_biguint(&b);
+
+        if !b_bi.is_zero() {
+            let (q, r) = a_bi.div_rem(&b_bi);
+            let expected = (to_uint(q), to_uint(r));
+            let reciprocal = UintReciprocal::new(&NonZero::new(b).unwrap());
+            let actual = a.div_rem_with_reciprocal(&reciprocal);
+            prop_assert_eq!(expected, actual);
+            prop_assert_eq!(expected.1, a.rem_with_reciprocal(&reciprocal));
+        }
+    }
+
     #[test]
     fn rem_wide(a in uint(), b in uint(), c in uint()) {
         let ab_bi = to_biguint(&a) * to_biguint(&b);
@@ -482,6 +516,64 @@ proptest! {
         prop_assert_eq!(expected, actual_vartime);
     }
 
+    #[test]
+    fn root(a in uint(), shift in 0u32..256, k in 1u32..300) {
+        // Shift `a` down so that small values, which have non-trivial roots for large `k`, are
+        // tested too
+        let a = a.wrapping_shr_vartime(shift);
+        let a_bi = to_biguint(&a);
+        let k_nz = core::num::NonZeroU32::new(k).unwrap();
+        let expected = a_bi.nth_root(k);
+        let is_exact = expected.pow(k) == a_bi;
+        let expected = to_uint(expected);
+
+        prop_assert_eq!(expected, a.root(k_nz));
+        prop_assert_eq!(expected, a.root_vartime(k_nz));
+        prop_assert_eq!(is_exact, a.checked_root(k_nz).is_some().to_bool());
+        prop_assert_eq!(is_exact, a.checked_root_vartime(k_nz).is_some().to_bool());
+    }
+
+    #[test]
+    fn is_perfect_power_vartime(a in uint(), k in 2u32..8) {
+        // `a^k` fits in 256 bits
+        let a = a.wrapping_shr_vartime(256 - 256 / k);
+        let pow = (1..k).fold(a, |acc, _| acc.wrapping_mul(&a));
+        prop_assert!(pow.is_perfect_power_vartime());
+
+        let next = pow.wrapping_add(&U256::ONE);
+        let next_bi = to_biguint(&next);
+        let expected = next_bi <= BigUint::one()
+            || (2..next_bi.bits() as u32).any(|k| next_bi.nth_root(k).pow(k) == next_bi);
+        prop_assert_eq!(expected, next.is_perfect_power_vartime());
+    }
+
+    #[test]
+    fn ilog(a in uint(), shift in 0u32..256, b in uint(), base_shift in 0u32..256) {
+        let a = a.wrapping_shr_vartime(shift);
+        let base = b.wrapping_shr_vartime(base_shift);
+        let a_bi = to_biguint(&a);
+        let base_bi = to_biguint(&base);
+
+        if a_bi.is_zero() || base_bi < BigUint::from(2u8) {
+            prop_assert!(a.checked_ilog(&base).is_none().to_bool());
+            prop_assert!(a.checked_ilog_vartime(&base).is_none().to_bool());
+        } else {
+            let mut expected = 0;
+            let mut power = base_bi.clone();
+            while power <= a_bi {
+                expected += 1;
+                power *= &base_bi;
+            }
+
+            prop_assert_eq!(expected, a.ilog(&base));
+            prop_assert_eq!(expected, a.ilog_vartime(&base));
+            prop_assert_eq!(a_bi.bits() as u32 - 1, a.ilog2());
+            prop_assert_eq!(a_bi.bits() as u32 - 1, a.ilog2_vartime());
+            prop_assert_eq!(a_bi.to_string().len() as u32 - 1, a.ilog10());
+            prop_assert_eq!(a_bi.to_string().len() as u32 - 1, a.ilog10_vartime());
+        }
+    }
+
     #[test]
     fn wrapping_or(a in uint(), b in uint()) {
         let a_bi = to_biguint(&a);